@@ -6,6 +6,7 @@ mkdir -p resources/uv/macos-arm64
 mkdir -p resources/uv/macos-x64
 mkdir -p resources/uv/linux-x64
 mkdir -p resources/uv/windows-x64
+mkdir -p resources/uv/windows-arm64
 
 # 获取最新版本的UV
 UV_VERSION=$(curl -s https://api.github.com/repos/astral-sh/uv/releases/latest | grep '"tag_name":' | sed -E 's/.*"([^"]+)".*/\1/')
@@ -21,6 +22,14 @@ unzip -o resources/uv/windows-x64/uv.zip -d resources/uv/windows-x64/
 # 清理
 rm -f resources/uv/windows-x64/uv.zip
 
+# Windows ARM64 - 使用zip文件
+echo "下载Windows ARM64版本..."
+curl -L -o resources/uv/windows-arm64/uv.zip "https://github.com/astral-sh/uv/releases/download/$UV_VERSION/uv-aarch64-pc-windows-msvc.zip"
+# 解压缩zip文件
+unzip -o resources/uv/windows-arm64/uv.zip -d resources/uv/windows-arm64/
+# 清理
+rm -f resources/uv/windows-arm64/uv.zip
+
 # Linux x64
 echo "下载Linux x64版本..."
 curl -L -o resources/uv/linux-x64/uv.tar.gz "https://github.com/astral-sh/uv/releases/download/$UV_VERSION/uv-x86_64-unknown-linux-gnu.tar.gz"
@@ -65,5 +74,15 @@ chmod +x resources/uv/linux-x64/uv
 chmod +x resources/uv/macos-x64/uv
 chmod +x resources/uv/macos-arm64/uv
 
+# 生成校验和文件，PyWand会在使用内置UV前校验
+for uv_bin in resources/uv/*/uv resources/uv/*/uv.exe; do
+  if [ -f "$uv_bin" ]; then
+    (cd "$(dirname "$uv_bin")" && shasum -a 256 "$(basename "$uv_bin")" > "$(basename "$uv_bin").sha256")
+  fi
+done
+
+# 记录UV版本号，PyWand按版本把内置UV解压到~/.pywand/bin/<版本>/
+echo "${UV_VERSION#v}" > resources/uv/VERSION
+
 echo "下载完成，请检查各平台目录中的UV二进制文件"
 ls -lh resources/uv/*/ 
\ No newline at end of file
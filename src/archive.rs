@@ -0,0 +1,181 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use flate2::read::GzDecoder;
+use globset::GlobSet;
+use zip::ZipArchive;
+
+use pywand::imports::{ScanConfig, SourceContent, build_glob_set, read_source_from};
+
+/// 归档中的Python源文件
+pub struct ArchiveSource {
+    pub path: String, // 相对于项目根目录的路径，使用/分隔
+    pub content: SourceContent,
+}
+
+/// 归档中读取到的所有条目，源文件的内容已读入内存
+#[derive(Default)]
+struct ArchiveEntries {
+    files: Vec<PathBuf>, // 所有普通文件的路径
+    sources: Vec<(PathBuf, SourceContent)>, // 需要分析的源文件
+}
+
+/// 读取.zip或.tar.gz（.tgz）归档中的Python源文件，不解压到磁盘
+///
+/// 所有文件位于同一个顶层目录下时（如GitHub下载的归档），以该目录作为项目根目录。
+/// 与扫描目录一样排除默认的目录、--exclude匹配的路径和虚拟环境，并遵守深度和大小限制
+pub fn read_archive_sources(archive: &Path, config: &ScanConfig) -> Result<Vec<ArchiveSource>> {
+    let name = archive.file_name().map(|name| name.to_string_lossy().to_lowercase()).unwrap_or_default();
+    let file = File::open(archive)
+        .context(format!("无法打开归档文件: {}", archive.display()))?;
+    
+    let entries = if name.ends_with(".zip") {
+        read_zip_entries(file, config)
+    } else if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        read_tar_gz_entries(file, config)
+    } else {
+        bail!("不支持的归档格式: {}（支持.zip、.tar.gz和.tgz）", archive.display());
+    }
+    .context(format!("无法读取归档文件: {}", archive.display()))?;
+    
+    let exclude = build_glob_set(&config.exclude)?;
+    let root = common_root(&entries.files);
+    // 包含pyvenv.cfg的目录是虚拟环境
+    let venv_dirs: Vec<&Path> = entries.files
+        .iter()
+        .filter(|file| file.file_name().is_some_and(|name| name == "pyvenv.cfg"))
+        .filter_map(|file| file.parent())
+        .collect();
+    
+    let mut sources: Vec<ArchiveSource> = entries.sources
+        .into_iter()
+        .filter(|(path, _)| !venv_dirs.iter().any(|venv| path.starts_with(venv)))
+        .filter_map(|(path, content)| {
+            let relative = path.strip_prefix(&root).ok()?;
+            is_scanned_path(relative, config, &exclude).then(|| ArchiveSource {
+                path: relative.to_string_lossy().replace('\\', "/"),
+                content,
+            })
+        })
+        .collect();
+    sources.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(sources)
+}
+
+/// 读取zip归档的条目
+fn read_zip_entries(file: File, config: &ScanConfig) -> Result<ArchiveEntries> {
+    let mut zip = ZipArchive::new(file)?;
+    let mut entries = ArchiveEntries::default();
+    
+    for index in 0..zip.len() {
+        let entry = zip.by_index(index)?;
+        // 跳过目录和包含..或绝对路径的条目
+        let Some(path) = entry.enclosed_name().filter(|_| entry.is_file()) else {
+            continue;
+        };
+        let size = entry.size();
+        add_entry(&mut entries, path, entry, size, config);
+    }
+    
+    Ok(entries)
+}
+
+/// 读取tar.gz归档的条目
+fn read_tar_gz_entries(file: File, config: &ScanConfig) -> Result<ArchiveEntries> {
+    let mut tar = tar::Archive::new(GzDecoder::new(file));
+    let mut entries = ArchiveEntries::default();
+    
+    for entry in tar.entries()? {
+        let entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.into_owned();
+        if path.components().any(|component| !matches!(component, Component::Normal(_) | Component::CurDir)) {
+            continue;
+        }
+        let path: PathBuf = path.components().filter(|component| *component != Component::CurDir).collect();
+        let size = entry.size();
+        add_entry(&mut entries, path, entry, size, config);
+    }
+    
+    Ok(entries)
+}
+
+/// 记录一个文件条目，Python源文件（不在排除的目录中）同时读取内容
+fn add_entry(entries: &mut ArchiveEntries, path: PathBuf, reader: impl Read, size: u64, config: &ScanConfig) {
+    let is_source = path
+        .extension()
+        .is_some_and(|ext| ext == "py" || (config.include_stubs && ext == "pyi"));
+    let in_excluded_dir = path
+        .parent()
+        .is_some_and(|dir| dir.iter().any(|name| config.excluded_dirs.iter().any(|excluded| name == excluded.as_str())));
+    
+    if is_source && !in_excluded_dir {
+        // 无法读取的文件（如非UTF-8内容）按空文件处理，与扫描目录时一致
+        let content = read_source_from(reader, size, config).unwrap_or(SourceContent::Full(String::new()));
+        entries.sources.push((path.clone(), content));
+    }
+    entries.files.push(path);
+}
+
+/// 所有文件都位于同一个顶层目录下时返回该目录，否则返回空路径
+fn common_root(files: &[PathBuf]) -> PathBuf {
+    let mut roots = files.iter().map(|file| {
+        let mut components = file.components();
+        let first = components.next();
+        // 位于归档根目录的文件没有共同的顶层目录
+        first.filter(|_| components.next().is_some())
+    });
+    match roots.next().flatten() {
+        Some(first) if roots.all(|root| root == Some(first)) => PathBuf::from(first.as_os_str()),
+        _ => PathBuf::new(),
+    }
+}
+
+/// 判断项目中的相对路径是否需要扫描：遵守深度限制，排除--exclude匹配的文件及其所在的目录
+fn is_scanned_path(relative: &Path, config: &ScanConfig, exclude: &GlobSet) -> bool {
+    if config.max_depth > 0 && relative.components().count() > config.max_depth {
+        return false;
+    }
+    !relative.ancestors()
+        .filter(|ancestor| !ancestor.as_os_str().is_empty())
+        .any(|ancestor| exclude.is_match(ancestor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    
+    use pywand::imports::extract_imports_from_source;
+    use zip::write::SimpleFileOptions;
+    
+    #[test]
+    fn zip_project_imports_are_read_without_extracting() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = dir.path().join("project.zip");
+        let mut zip = zip::ZipWriter::new(File::create(&archive).unwrap());
+        for (name, content) in [
+            ("project/app.py", "import requests\nfrom pkg import helpers\n"),
+            ("project/pkg/__init__.py", ""),
+            ("project/README.md", "说明\n"),
+            ("project/env/pyvenv.cfg", "home = /usr/bin\n"),
+            ("project/env/lib/site.py", "import numpy\n"),
+        ] {
+            zip.start_file(name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+        
+        let sources = read_archive_sources(&archive, &ScanConfig::default()).unwrap();
+        let paths: Vec<&str> = sources.iter().map(|source| source.path.as_str()).collect();
+        // 以共同的顶层目录作为项目根目录，跳过虚拟环境
+        assert_eq!(paths, vec!["app.py", "pkg/__init__.py"]);
+        assert!(extract_imports_from_source(sources[0].content.text()).contains(&"requests".to_string()));
+        // 归档没有被解压到磁盘
+        assert!(!dir.path().join("project").exists());
+    }
+}
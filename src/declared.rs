@@ -0,0 +1,488 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Result};
+use lazy_static::lazy_static;
+use regex::Regex;
+use toml_edit::{DocumentMut, Item};
+
+lazy_static! {
+    // setup.py中 install_requires=[...] 列表的开头
+    static ref INSTALL_REQUIRES_RE: Regex = Regex::new(r"install_requires\s*=\s*\[").unwrap();
+}
+
+/// 依赖声明及其所在的文件
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeclaredRequirement {
+    pub requirement: String,
+    pub source: &'static str,
+}
+
+/// 读取项目目录中已声明的依赖（requirements.txt、setup.py、pyproject.toml、Pipfile）
+///
+/// 返回所有文件中的依赖声明（按文件顺序，不去重），同一个包可能带有不同的版本约束
+pub fn parse_declared_dependencies(path: &Path) -> Result<Vec<DeclaredRequirement>> {
+    let mut declared = Vec::new();
+    let mut add = |requirements: Vec<String>, source: &'static str| {
+        for requirement in requirements {
+            let entry = DeclaredRequirement { requirement, source };
+            if !declared.contains(&entry) {
+                declared.push(entry);
+            }
+        }
+    };
+
+    // -r引用的文件中的依赖合并到requirements.txt名下
+    let requirements_path = path.join("requirements.txt");
+    if requirements_path.exists() {
+        add(read_requirements_file(&requirements_path)?, "requirements.txt");
+    }
+
+    let setup_path = path.join("setup.py");
+    if setup_path.exists() {
+        let content = fs::read_to_string(&setup_path)
+            .context(format!("无法读取{}文件", setup_path.display()))?;
+        add(parse_setup_py(&content), "setup.py");
+    }
+
+    let pyproject_path = path.join("pyproject.toml");
+    if pyproject_path.exists() {
+        let content = fs::read_to_string(&pyproject_path)
+            .context(format!("无法读取{}文件", pyproject_path.display()))?;
+        add(parse_pyproject_toml(&content)
+            .context(format!("无法解析{}文件", pyproject_path.display()))?, "pyproject.toml");
+    }
+
+    let pipfile_path = path.join("Pipfile");
+    if pipfile_path.exists() {
+        let content = fs::read_to_string(&pipfile_path)
+            .context(format!("无法读取{}文件", pipfile_path.display()))?;
+        add(parse_pipfile(&content)
+            .context(format!("无法解析{}文件", pipfile_path.display()))?, "Pipfile");
+    }
+
+    Ok(declared)
+}
+
+/// PyWand生成的requirements.txt第一行的标记，没有该标记的文件视为用户维护的文件
+pub const GENERATED_MARKER: &str = "# Generated by PyWand";
+
+/// 判断requirements.txt是否由PyWand生成（第一行为生成标记）
+pub fn is_generated_requirements(content: &str) -> bool {
+    content.lines().next().is_some_and(|line| line.trim_start().starts_with(GENERATED_MARKER))
+}
+
+/// 解析requirements.txt，跳过注释、空行和选项行（如 -r、-e、--index-url）
+pub fn parse_requirements_txt(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .map(requirement_line)
+        .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with('-'))
+        .map(|line| line.to_string())
+        .collect()
+}
+
+/// 读取requirements文件，跟随 -r/--requirement 引用的文件并合并其中的依赖
+///
+/// 引用的路径相对于所在文件的目录解析。-c/--constraint 引用的约束文件不会引入新的依赖，
+/// 只为已声明的包补充版本约束。存在循环引用时返回错误
+pub fn read_requirements_file(path: &Path) -> Result<Vec<String>> {
+    let mut requirements = Vec::new();
+    let mut constraints = Vec::new();
+    collect_requirements(path, false, &mut Vec::new(), &mut requirements, &mut constraints)?;
+
+    let names: Vec<String> = requirements.iter().map(|requirement| requirement_name(requirement)).collect();
+    for constraint in constraints {
+        if names.contains(&requirement_name(&constraint)) && !requirements.contains(&constraint) {
+            requirements.push(constraint);
+        }
+    }
+    Ok(requirements)
+}
+
+/// 递归读取requirements文件，stack记录当前的引用链，用于检测循环引用
+fn collect_requirements(
+    path: &Path,
+    constraint: bool,
+    stack: &mut Vec<PathBuf>,
+    requirements: &mut Vec<String>,
+    constraints: &mut Vec<String>,
+) -> Result<()> {
+    let canonical = fs::canonicalize(path)
+        .context(format!("无法读取{}文件", path.display()))?;
+    if let Some(start) = stack.iter().position(|visited| visited == &canonical) {
+        let chain: Vec<String> = stack[start..]
+            .iter()
+            .chain(std::iter::once(&canonical))
+            .map(|file| file.display().to_string())
+            .collect();
+        bail!("requirements文件存在循环引用: {}", chain.join(" -> "));
+    }
+
+    let content = fs::read_to_string(path)
+        .context(format!("无法读取{}文件", path.display()))?;
+    let base_dir = path.parent().unwrap_or(Path::new("."));
+
+    stack.push(canonical);
+    for line in content.lines() {
+        if let Some((include_constraint, target)) = requirement_include(requirement_line(line)) {
+            // 约束文件中引用的文件同样只作为约束
+            collect_requirements(&base_dir.join(target), constraint || include_constraint, stack, requirements, constraints)?;
+            continue;
+        }
+        let entries = if constraint { &mut *constraints } else { &mut *requirements };
+        for requirement in parse_requirements_txt(line) {
+            if !entries.contains(&requirement) {
+                entries.push(requirement);
+            }
+        }
+    }
+    stack.pop();
+
+    Ok(())
+}
+
+/// 解析 -r/-c 引用行，返回（是否为约束文件，引用的路径）；远程URL不跟随
+fn requirement_include(line: &str) -> Option<(bool, &str)> {
+    for (prefix, constraint) in [("--requirement", false), ("--constraint", true), ("-r", false), ("-c", true)] {
+        if let Some(rest) = line.strip_prefix(prefix) {
+            let target = rest.strip_prefix('=').unwrap_or(rest).trim();
+            if target.is_empty() || target.contains("://") {
+                return None;
+            }
+            return Some((constraint, target));
+        }
+    }
+    None
+}
+
+/// 去掉行尾注释和首尾空白
+fn requirement_line(line: &str) -> &str {
+    line.split(" #").next().unwrap_or(line).trim()
+}
+
+/// 解析setup.py中的install_requires列表
+fn parse_setup_py(content: &str) -> Vec<String> {
+    INSTALL_REQUIRES_RE
+        .find_iter(content)
+        .flat_map(|list| list_string_literals(&content[list.end()..]))
+        .map(|requirement| requirement.trim().to_string())
+        .filter(|requirement| !requirement.is_empty())
+        .collect()
+}
+
+/// 读取Python列表中的字符串字面量，直到与开头的[配对的]为止
+///
+/// 字符串中的括号（如"requests[socks]"）和注释中的内容不影响配对
+fn list_string_literals(list: &str) -> Vec<&str> {
+    let mut literals = Vec::new();
+    let mut depth = 1;
+    let mut chars = list.char_indices();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' | '\'' => {
+                let start = index + 1;
+                let end = chars.by_ref().find(|&(_, next)| next == c).map_or(list.len(), |(end, _)| end);
+                literals.push(&list[start..end]);
+            }
+            '#' => {
+                chars.by_ref().find(|&(_, next)| next == '\n');
+            }
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    literals
+}
+
+/// 解析pyproject.toml中的[project] dependencies数组和poetry的[tool.poetry.dependencies]表
+fn parse_pyproject_toml(content: &str) -> Result<Vec<String>> {
+    let document = content.parse::<DocumentMut>()?;
+
+    let mut dependencies: Vec<String> = document
+        .get("project")
+        .and_then(|project| project.get("dependencies"))
+        .and_then(|dependencies| dependencies.as_array())
+        .map(|dependencies| {
+            dependencies
+                .iter()
+                .filter_map(|dep| dep.as_str())
+                .map(|dep| dep.trim().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // poetry的python条目是项目要求的Python版本，不是依赖
+    if let Some(poetry) = document
+        .get("tool")
+        .and_then(|tool| tool.get("poetry"))
+        .and_then(|poetry| poetry.get("dependencies"))
+        .and_then(|dependencies| dependencies.as_table_like())
+    {
+        dependencies.extend(
+            poetry
+                .iter()
+                .filter(|(name, _)| !name.eq_ignore_ascii_case("python"))
+                .filter_map(|(name, item)| table_requirement(name, item, poetry_constraint)),
+        );
+    }
+
+    Ok(dependencies)
+}
+
+/// 解析Pipfile中的[packages]表，[dev-packages]中的开发依赖不包括在内
+fn parse_pipfile(content: &str) -> Result<Vec<String>> {
+    let document = content.parse::<DocumentMut>()?;
+
+    // Pipfile中的版本约束已经是PEP 440格式，"*"表示任意版本
+    let pipfile_constraint = |spec: &str| Some(if spec == "*" { String::new() } else { spec.to_string() });
+    let dependencies = document
+        .get("packages")
+        .and_then(|packages| packages.as_table_like())
+        .map(|packages| {
+            packages
+                .iter()
+                .filter_map(|(name, item)| table_requirement(name, item, pipfile_constraint))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(dependencies)
+}
+
+/// 把poetry或Pipfile中的一个依赖条目转换为依赖声明
+///
+/// 条目可以是版本字符串，也可以是带version和extras的表；git、path、url依赖和可选依赖（optional = true）
+/// 无法用PyPI包名表示或默认不会安装，返回None。`constraint`把版本约束转换为PEP 440格式，无法转换时只保留包名
+fn table_requirement(name: &str, item: &Item, constraint: impl Fn(&str) -> Option<String>) -> Option<String> {
+    let (version, extras) = match item.as_table_like() {
+        Some(table) => {
+            if ["git", "path", "url", "file"].iter().any(|key| table.contains_key(key))
+                || table.get("optional").and_then(|optional| optional.as_bool()) == Some(true)
+            {
+                return None;
+            }
+            let extras: Vec<&str> = table
+                .get("extras")
+                .and_then(|extras| extras.as_array())
+                .map(|extras| extras.iter().filter_map(|extra| extra.as_str()).collect())
+                .unwrap_or_default();
+            (table.get("version").and_then(|version| version.as_str()).unwrap_or("*"), extras)
+        }
+        None => (item.as_str()?, Vec::new()),
+    };
+
+    let mut requirement = name.trim().to_string();
+    if !extras.is_empty() {
+        requirement.push_str(&format!("[{}]", extras.join(",")));
+    }
+    requirement.push_str(&constraint(version.trim()).unwrap_or_default());
+    Some(requirement)
+}
+
+/// 把poetry的版本约束转换为PEP 440格式，任意版本返回空字符串，无法表示（如"||"）时返回None
+///
+/// ^1.2.3对应>=1.2.3,<2，^0.2.3对应>=0.2.3,<0.3；~1.2.3对应>=1.2.3,<1.3；不带运算符的版本（1.2.3）表示精确版本
+fn poetry_constraint(spec: &str) -> Option<String> {
+    if spec.is_empty() || spec == "*" {
+        return Some(String::new());
+    }
+    if spec.contains("||") {
+        return None;
+    }
+
+    let mut parts = Vec::new();
+    for part in spec.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+        let converted = if let Some(version) = part.strip_prefix('^') {
+            let numbers = version_numbers(version.trim())?;
+            // 第一个非零的部分不变，全为零时最后一个部分不变
+            let index = numbers.iter().position(|&number| number != 0).unwrap_or(numbers.len() - 1);
+            format!(">={},<{}", version.trim(), upper_bound(&numbers, index))
+        } else if let Some(version) = part.strip_prefix('~').filter(|version| !version.starts_with('=')) {
+            let numbers = version_numbers(version.trim())?;
+            let index = if numbers.len() > 1 { 1 } else { 0 };
+            format!(">={},<{}", version.trim(), upper_bound(&numbers, index))
+        } else if part.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("=={}", part)
+        } else {
+            part.replace(' ', "")
+        };
+        parts.push(converted);
+    }
+    Some(parts.join(","))
+}
+
+/// 解析版本号中的数字部分，如"1.2.3"得到[1, 2, 3]；带预发布标记等无法解析时返回None
+fn version_numbers(version: &str) -> Option<Vec<u64>> {
+    version.split('.').map(|part| part.parse().ok()).collect()
+}
+
+/// 版本上限：保留`index`之前的部分，`index`处加一，如[1, 2, 3]和1得到"1.3"
+fn upper_bound(numbers: &[u64], index: usize) -> String {
+    let mut bound: Vec<String> = numbers[..index].iter().map(u64::to_string).collect();
+    bound.push((numbers[index] + 1).to_string());
+    bound.join(".")
+}
+
+/// 读取项目声明的Python版本：优先使用.python-version，其次是pyproject.toml中的requires-python
+///
+/// 返回原始声明（如"3.11.7"或">=3.9"），未声明时返回None
+pub fn parse_declared_python_version(path: &Path) -> Result<Option<String>> {
+    let python_version_path = path.join(".python-version");
+    if python_version_path.exists() {
+        let content = fs::read_to_string(&python_version_path)
+            .context(format!("无法读取{}文件", python_version_path.display()))?;
+        let version = content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty() && !line.starts_with('#'));
+        if let Some(version) = version {
+            return Ok(Some(version.to_string()));
+        }
+    }
+
+    let pyproject_path = path.join("pyproject.toml");
+    if pyproject_path.exists() {
+        let content = fs::read_to_string(&pyproject_path)
+            .context(format!("无法读取{}文件", pyproject_path.display()))?;
+        let document = content.parse::<DocumentMut>()
+            .context(format!("无法解析{}文件", pyproject_path.display()))?;
+        let requires_python = document
+            .get("project")
+            .and_then(|project| project.get("requires-python"))
+            .and_then(|requires_python| requires_python.as_str())
+            .map(|requires_python| requires_python.trim().to_string());
+        return Ok(requires_python);
+    }
+
+    Ok(None)
+}
+
+/// 获取依赖声明中的包名（去掉版本约束、extras和环境标记），用于比较
+///
+/// 按PEP 503规范化：转为小写，连续的-、_、.合并为一个-，因此Flask-SQLAlchemy和flask_sqlalchemy得到相同的名称
+pub fn requirement_name(requirement: &str) -> String {
+    let name = requirement
+        .split(|c: char| "<>=!~;[@ ".contains(c))
+        .next()
+        .unwrap_or(requirement)
+        .trim()
+        .to_lowercase();
+
+    let mut canonical = String::with_capacity(name.len());
+    for c in name.chars() {
+        if "-_.".contains(c) {
+            if !canonical.ends_with('-') {
+                canonical.push('-');
+            }
+        } else {
+            canonical.push(c);
+        }
+    }
+    canonical
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 在临时目录中创建文件，路径中的目录会自动创建
+    fn write_file(root: &Path, relative: &str, content: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn requirement_includes_and_constraints_are_followed() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "requirements.txt", "-r base.txt\n--constraint=constraints/pins.txt\nflask  # web\n-r https://example.com/remote.txt\n");
+        write_file(dir.path(), "base.txt", "requests\n");
+        write_file(dir.path(), "constraints/pins.txt", "requests<3\nurllib3<2\n");
+
+        let requirements = read_requirements_file(&dir.path().join("requirements.txt")).unwrap();
+        // 约束只补充已声明的包，不引入urllib3
+        assert_eq!(requirements, vec!["requests", "flask", "requests<3"]);
+    }
+
+    #[test]
+    fn requirement_include_cycle_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "requirements.txt", "-r dev.txt\nflask\n");
+        write_file(dir.path(), "dev.txt", "-r requirements.txt\npytest\n");
+
+        let error = read_requirements_file(&dir.path().join("requirements.txt")).unwrap_err();
+        let message = error.to_string();
+        assert!(message.starts_with("requirements文件存在循环引用"), "{}", message);
+        assert!(message.contains("dev.txt -> "), "{}", message);
+    }
+
+    #[test]
+    fn setup_py_requirements_with_extras_are_read() {
+        let content = r#"
+from setuptools import setup
+
+setup(
+    name="app",
+    install_requires=[
+        "requests[socks]>=2.0",  # 代理支持 [socks]
+        'flask>=2',
+        "uvicorn[standard, http2]",
+    ],
+    extras_require={"dev": ["pytest"]},
+)
+"#;
+        assert_eq!(parse_setup_py(content), vec!["requests[socks]>=2.0", "flask>=2", "uvicorn[standard, http2]"]);
+    }
+
+    #[test]
+    fn project_dependencies_are_read_from_pyproject() {
+        let content = r#"
+[project]
+name = "app"
+dependencies = ["requests[socks]>=2.0", "flask>=2"]
+
+[project.optional-dependencies]
+dev = ["pytest"]
+"#;
+        assert_eq!(parse_pyproject_toml(content).unwrap(), vec!["requests[socks]>=2.0", "flask>=2"]);
+    }
+
+    #[test]
+    fn poetry_constraints_convert_to_pep440() {
+        assert_eq!(poetry_constraint("^1.2.3").as_deref(), Some(">=1.2.3,<2"));
+        assert_eq!(poetry_constraint("^0.2.3").as_deref(), Some(">=0.2.3,<0.3"));
+        assert_eq!(poetry_constraint("^0.0.3").as_deref(), Some(">=0.0.3,<0.0.4"));
+        assert_eq!(poetry_constraint("~1.2.3").as_deref(), Some(">=1.2.3,<1.3"));
+        assert_eq!(poetry_constraint("~1").as_deref(), Some(">=1,<2"));
+        assert_eq!(poetry_constraint("~=1.2").as_deref(), Some("~=1.2"));
+        assert_eq!(poetry_constraint("1.2.3").as_deref(), Some("==1.2.3"));
+        assert_eq!(poetry_constraint(">= 1.2, < 2").as_deref(), Some(">=1.2,<2"));
+        assert_eq!(poetry_constraint("*").as_deref(), Some(""));
+        assert_eq!(poetry_constraint("^1.2 || ^2.0"), None);
+        assert_eq!(poetry_constraint("^1.2b1"), None);
+    }
+
+    #[test]
+    fn poetry_dependencies_are_read_from_pyproject() {
+        let content = r#"
+[tool.poetry.dependencies]
+python = "^3.9"
+requests = "^2.31"
+uvicorn = { version = "~0.30.1", extras = ["standard"] }
+black = { version = "^24.1", optional = true }
+mylib = { path = "../mylib" }
+tool = { git = "https://github.com/example/tool.git" }
+"#;
+        assert_eq!(
+            parse_pyproject_toml(content).unwrap(),
+            vec!["requests>=2.31,<3", "uvicorn[standard]>=0.30.1,<0.31"]
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+/// 需要通过退出码区分的错误，其他错误的退出码为1
+///
+/// 作为anyhow错误的context使用，原始错误信息保留在错误链中
+#[derive(Debug, Error)]
+pub enum PyWandError {
+    /// 扫描的目录中没有Python文件
+    #[error("在{0}中没有找到Python文件")]
+    NoPythonFiles(String),
+    /// 找不到可用的UV，也无法下载
+    #[error("UV不可用")]
+    UvUnavailable,
+    /// uv安装依赖失败
+    #[error("依赖安装失败")]
+    InstallFailed,
+    /// 网络请求失败或处于离线状态
+    #[error("网络请求失败")]
+    Network,
+}
+
+impl PyWandError {
+    /// 进程退出码
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            PyWandError::NoPythonFiles(_) => 2,
+            PyWandError::UvUnavailable => 3,
+            PyWandError::InstallFailed => 4,
+            PyWandError::Network => 5,
+        }
+    }
+}
+
+/// 错误对应的退出码，错误链中任一层是PyWandError时使用它的退出码（最外层优先），否则为1
+pub fn exit_code(error: &anyhow::Error) -> u8 {
+    error.downcast_ref::<PyWandError>().map_or(1, PyWandError::exit_code)
+}
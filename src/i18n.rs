@@ -1,6 +1,8 @@
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum Language {
@@ -11,6 +13,9 @@ pub enum Language {
     French,
     German,
     Russian,
+    Spanish,
+    Portuguese,
+    Italian,
 }
 
 impl Default for Language {
@@ -19,6 +24,59 @@ impl Default for Language {
     }
 }
 
+impl Language {
+    /// 所有支持的语言
+    pub const ALL: [Language; 10] = [
+        Language::English,
+        Language::Chinese,
+        Language::Japanese,
+        Language::Korean,
+        Language::French,
+        Language::German,
+        Language::Russian,
+        Language::Spanish,
+        Language::Portuguese,
+        Language::Italian,
+    ];
+    
+    /// 从语言代码解析语言
+    pub fn from_code(code: &str) -> Option<Language> {
+        Language::ALL.into_iter().find(|language| language.code() == code)
+    }
+    
+    /// 语言代码
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Chinese => "zh",
+            Language::Japanese => "ja",
+            Language::Korean => "ko",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Russian => "ru",
+            Language::Spanish => "es",
+            Language::Portuguese => "pt",
+            Language::Italian => "it",
+        }
+    }
+    
+    /// 语言的本地名称
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Chinese => "中文",
+            Language::Japanese => "日本語",
+            Language::Korean => "한국어",
+            Language::French => "Français",
+            Language::German => "Deutsch",
+            Language::Russian => "Русский",
+            Language::Spanish => "Español",
+            Language::Portuguese => "Português",
+            Language::Italian => "Italiano",
+        }
+    }
+}
+
 pub fn detect_system_language() -> Language {
     let lang = env::var("LANG")
         .or_else(|_| env::var("LC_ALL"))
@@ -35,6 +93,9 @@ pub fn detect_system_language() -> Language {
         "fr" => Language::French,
         "de" => Language::German,
         "ru" => Language::Russian,
+        "es" => Language::Spanish,
+        "pt" => Language::Portuguese,
+        "it" => Language::Italian,
         _ => Language::English,
     }
 }
@@ -47,7 +108,10 @@ macro_rules! translation_map {
         ko: $ko:expr,
         fr: $fr:expr,
         de: $de:expr,
-        ru: $ru:expr
+        ru: $ru:expr,
+        es: $es:expr,
+        pt: $pt:expr,
+        it: $it:expr
     }),* $(,)?) => {
         {
             let mut map = HashMap::new();
@@ -60,6 +124,9 @@ macro_rules! translation_map {
                 inner_map.insert(Language::French, $fr);
                 inner_map.insert(Language::German, $de);
                 inner_map.insert(Language::Russian, $ru);
+                inner_map.insert(Language::Spanish, $es);
+                inner_map.insert(Language::Portuguese, $pt);
+                inner_map.insert(Language::Italian, $it);
                 map.insert($key, inner_map);
             )*
             map
@@ -77,7 +144,10 @@ lazy_static! {
             ko: "PyWand - Python 종속성 분석기",
             fr: "PyWand - Analyseur de dépendances Python",
             de: "PyWand - Python-Abhängigkeitsanalysator",
-            ru: "PyWand - Анализатор зависимостей Python"
+            ru: "PyWand - Анализатор зависимостей Python",
+            es: "PyWand - Analizador de dependencias de Python",
+            pt: "PyWand - Analisador de dependências Python",
+            it: "PyWand - Analizzatore di dipendenze Python"
         },
         "what_to_do" => {
             en: "What would you like to do?",
@@ -86,7 +156,10 @@ lazy_static! {
             ko: "무엇을 하시겠습니까?",
             fr: "Que souhaitez-vous faire ?",
             de: "Was möchten Sie tun?",
-            ru: "Что вы хотите сделать?"
+            ru: "Что вы хотите сделать?",
+            es: "¿Qué desea hacer?",
+            pt: "O que você deseja fazer?",
+            it: "Cosa vuoi fare?"
         },
         "local_development" => {
             en: "Local Development",
@@ -95,7 +168,10 @@ lazy_static! {
             ko: "로컬 개발",
             fr: "Développement local",
             de: "Lokale Entwicklung",
-            ru: "Локальная разработка"
+            ru: "Локальная разработка",
+            es: "Desarrollo local",
+            pt: "Desenvolvimento local",
+            it: "Sviluppo locale"
         },
         "export_offline" => {
             en: "Export for Offline Development",
@@ -104,7 +180,10 @@ lazy_static! {
             ko: "오프라인 개발을 위해 내보내기",
             fr: "Exporter pour le développement hors ligne",
             de: "Export für die Offline-Entwicklung",
-            ru: "Экспорт для автономной разработки"
+            ru: "Экспорт для автономной разработки",
+            es: "Exportar para desarrollo sin conexión",
+            pt: "Exportar para desenvolvimento offline",
+            it: "Esporta per lo sviluppo offline"
         },
         "exit" => {
             en: "Exit",
@@ -113,7 +192,10 @@ lazy_static! {
             ko: "종료",
             fr: "Quitter",
             de: "Beenden",
-            ru: "Выход"
+            ru: "Выход",
+            es: "Salir",
+            pt: "Sair",
+            it: "Esci"
         },
         
         // 文件和依赖扫描
@@ -124,25 +206,35 @@ lazy_static! {
             ko: "Python 파일 스캔 중...",
             fr: "Analyse des fichiers Python...",
             de: "Scanne Python-Dateien...",
-            ru: "Сканирование файлов Python..."
+            ru: "Сканирование файлов Python...",
+            es: "Escaneando archivos Python...",
+            pt: "Verificando arquivos Python...",
+            it: "Scansione dei file Python..."
         },
+        // 复数形式用|分隔，由get_plural按语言规则选择
         "found_files" => {
-            en: "Found {} Python files",
+            en: "Found {} Python file|Found {} Python files",
             zh: "找到{}个Python文件",
             ja: "{}個のPythonファイルが見つかりました",
             ko: "{}개의 Python 파일을 찾았습니다",
-            fr: "{} fichiers Python trouvés",
-            de: "{} Python-Dateien gefunden",
-            ru: "Найдено {} файлов Python"
+            fr: "{} fichier Python trouvé|{} fichiers Python trouvés",
+            de: "{} Python-Datei gefunden|{} Python-Dateien gefunden",
+            ru: "Найден {} файл Python|Найдено {} файла Python|Найдено {} файлов Python",
+            es: "Se encontró {} archivo Python|Se encontraron {} archivos Python",
+            pt: "{} arquivo Python encontrado|{} arquivos Python encontrados",
+            it: "Trovato {} file Python|Trovati {} file Python"
         },
         "found_dependencies" => {
-            en: "Found {} dependencies",
+            en: "Found {} dependency|Found {} dependencies",
             zh: "找到{}个依赖",
             ja: "{}個の依存関係が見つかりました",
             ko: "{}개의 종속성을 찾았습니다",
-            fr: "{} dépendances trouvées",
-            de: "{} Abhängigkeiten gefunden",
-            ru: "Найдено {} зависимостей"
+            fr: "{} dépendance trouvée|{} dépendances trouvées",
+            de: "{} Abhängigkeit gefunden|{} Abhängigkeiten gefunden",
+            ru: "Найдена {} зависимость|Найдено {} зависимости|Найдено {} зависимостей",
+            es: "Se encontró {} dependencia|Se encontraron {} dependencias",
+            pt: "{} dependência encontrada|{} dependências encontradas",
+            it: "Trovata {} dipendenza|Trovate {} dipendenze"
         },
         "no_dependencies" => {
             en: "No external dependencies found.",
@@ -151,7 +243,82 @@ lazy_static! {
             ko: "외부 종속성을 찾을 수 없습니다.",
             fr: "Aucune dépendance externe trouvée.",
             de: "Keine externen Abhängigkeiten gefunden.",
-            ru: "Внешние зависимости не найдены."
+            ru: "Внешние зависимости не найдены.",
+            es: "No se encontraron dependencias externas.",
+            pt: "Nenhuma dependência externa encontrada.",
+            it: "Nessuna dipendenza esterna trovata."
+        },
+        "diff_title" => {
+            en: "Comparing imported packages with {}",
+            zh: "对比导入的包与 {}",
+            ja: "インポートされたパッケージを {} と比較しています",
+            ko: "가져온 패키지를 {}와(과) 비교합니다",
+            fr: "Comparaison des paquets importés avec {}",
+            de: "Importierte Pakete werden mit {} verglichen",
+            ru: "Сравнение импортируемых пакетов с {}",
+            es: "Comparando los paquetes importados con {}",
+            pt: "Comparando os pacotes importados com {}",
+            it: "Confronto dei pacchetti importati con {}"
+        },
+        "diff_unused" => {
+            en: "Declared but not imported ({}):",
+            zh: "已声明但未导入（{}）：",
+            ja: "宣言済みだがインポートされていない（{}）：",
+            ko: "선언되었지만 가져오지 않음 ({}):",
+            fr: "Déclarés mais non importés ({}) :",
+            de: "Deklariert, aber nicht importiert ({}):",
+            ru: "Объявлены, но не импортируются ({}):",
+            es: "Declarados pero no importados ({}):",
+            pt: "Declarados mas não importados ({}):",
+            it: "Dichiarati ma non importati ({}):"
+        },
+        "diff_undeclared" => {
+            en: "Imported but not declared ({}):",
+            zh: "已导入但未声明（{}）：",
+            ja: "インポートされているが宣言されていない（{}）：",
+            ko: "가져왔지만 선언되지 않음 ({}):",
+            fr: "Importés mais non déclarés ({}) :",
+            de: "Importiert, aber nicht deklariert ({}):",
+            ru: "Импортируются, но не объявлены ({}):",
+            es: "Importados pero no declarados ({}):",
+            pt: "Importados mas não declarados ({}):",
+            it: "Importati ma non dichiarati ({}):"
+        },
+        "diff_matching" => {
+            en: "Declared and imported ({}):",
+            zh: "已声明且已导入（{}）：",
+            ja: "宣言済みかつインポート済み（{}）：",
+            ko: "선언되고 가져옴 ({}):",
+            fr: "Déclarés et importés ({}) :",
+            de: "Deklariert und importiert ({}):",
+            ru: "Объявлены и импортируются ({}):",
+            es: "Declarados e importados ({}):",
+            pt: "Declarados e importados ({}):",
+            it: "Dichiarati e importati ({}):"
+        },
+        "not_interactive" => {
+            en: "No interactive terminal available (stdin or stdout is not a terminal). Pass the choice on the command line instead, e.g. {}",
+            zh: "当前不是交互式终端（标准输入或输出不是终端），无法显示选择提示。请通过命令行参数指定，例如: {}",
+            ja: "対話型ターミナルではありません（標準入力または標準出力が端末ではありません）。コマンドライン引数で指定してください。例: {}",
+            ko: "대화형 터미널이 아닙니다(표준 입력 또는 출력이 터미널이 아님). 명령줄 인수로 지정하세요. 예: {}",
+            fr: "Aucun terminal interactif disponible (l'entrée ou la sortie standard n'est pas un terminal). Indiquez le choix en ligne de commande, par exemple : {}",
+            de: "Kein interaktives Terminal verfügbar (Standardeingabe oder -ausgabe ist kein Terminal). Geben Sie die Auswahl stattdessen auf der Kommandozeile an, z. B.: {}",
+            ru: "Интерактивный терминал недоступен (stdin или stdout не является терминалом). Укажите выбор в командной строке, например: {}",
+            es: "No hay un terminal interactivo (la entrada o salida estándar no es un terminal). Indique la opción en la línea de comandos, por ejemplo: {}",
+            pt: "Nenhum terminal interativo disponível (a entrada ou saída padrão não é um terminal). Informe a opção na linha de comando, por exemplo: {}",
+            it: "Nessun terminale interattivo disponibile (l'input o l'output standard non è un terminale). Specifica la scelta dalla riga di comando, ad esempio: {}"
+        },
+        "review_dependencies" => {
+            en: "Review dependencies (space to toggle, enter to confirm)",
+            zh: "确认依赖（空格切换勾选，回车确认）",
+            ja: "依存関係を確認してください（スペースで切り替え、Enterで確定）",
+            ko: "종속성 확인 (스페이스로 선택 전환, 엔터로 확인)",
+            fr: "Vérifiez les dépendances (espace pour cocher/décocher, entrée pour valider)",
+            de: "Abhängigkeiten prüfen (Leertaste zum Umschalten, Enter zum Bestätigen)",
+            ru: "Проверьте зависимости (пробел — переключить, Enter — подтвердить)",
+            es: "Revise las dependencias (espacio para marcar/desmarcar, Enter para confirmar)",
+            pt: "Revise as dependências (espaço para marcar/desmarcar, Enter para confirmar)",
+            it: "Verifica le dipendenze (spazio per selezionare/deselezionare, Invio per confermare)"
         },
         "external_dependencies" => {
             en: "Found the following external dependencies:",
@@ -160,7 +327,10 @@ lazy_static! {
             ko: "다음 외부 종속성을 찾았습니다:",
             fr: "Les dépendances externes suivantes ont été trouvées :",
             de: "Die folgenden externen Abhängigkeiten wurden gefunden:",
-            ru: "Найдены следующие внешние зависимости:"
+            ru: "Найдены следующие внешние зависимости:",
+            es: "Se encontraron las siguientes dependencias externas:",
+            pt: "Foram encontradas as seguintes dependências externas:",
+            it: "Trovate le seguenti dipendenze esterne:"
         },
         
         // 本地开发
@@ -171,7 +341,10 @@ lazy_static! {
             ko: "로컬 개발 설정",
             fr: "Configuration du développement local",
             de: "Lokale Entwicklungseinrichtung",
-            ru: "Настройка локальной разработки"
+            ru: "Настройка локальной разработки",
+            es: "Configuración del desarrollo local",
+            pt: "Configuração do desenvolvimento local",
+            it: "Configurazione dello sviluppo locale"
         },
         "no_python_files" => {
             en: "No Python files found!",
@@ -180,7 +353,10 @@ lazy_static! {
             ko: "Python 파일을 찾을 수 없습니다!",
             fr: "Aucun fichier Python trouvé !",
             de: "Keine Python-Dateien gefunden!",
-            ru: "Файлы Python не найдены!"
+            ru: "Файлы Python не найдены!",
+            es: "¡No se encontraron archivos Python!",
+            pt: "Nenhum arquivo Python encontrado!",
+            it: "Nessun file Python trovato!"
         },
         "how_to_continue" => {
             en: "How to continue?",
@@ -189,7 +365,10 @@ lazy_static! {
             ko: "어떻게 계속하시겠습니까?",
             fr: "Comment continuer ?",
             de: "Wie möchten Sie fortfahren?",
-            ru: "Как продолжить?"
+            ru: "Как продолжить?",
+            es: "¿Cómo desea continuar?",
+            pt: "Como deseja continuar?",
+            it: "Come vuoi procedere?"
         },
         "use_test_suite" => {
             en: "Use example files from test suite",
@@ -198,7 +377,10 @@ lazy_static! {
             ko: "테스트 스위트의 예제 파일 사용",
             fr: "Utiliser les fichiers exemple de la suite de test",
             de: "Beispieldateien aus der Testsuite verwenden",
-            ru: "Использовать примеры файлов из тестового набора"
+            ru: "Использовать примеры файлов из тестового набора",
+            es: "Usar los archivos de ejemplo del conjunto de pruebas",
+            pt: "Usar os arquivos de exemplo do conjunto de testes",
+            it: "Usa i file di esempio della suite di test"
         },
         "specify_directory" => {
             en: "Manually specify Python files directory",
@@ -207,7 +389,10 @@ lazy_static! {
             ko: "Python 파일 디렉토리를 수동으로 지정",
             fr: "Spécifier manuellement le répertoire des fichiers Python",
             de: "Python-Dateiverzeichnis manuell angeben",
-            ru: "Вручную указать каталог файлов Python"
+            ru: "Вручную указать каталог файлов Python",
+            es: "Especificar manualmente el directorio de archivos Python",
+            pt: "Especificar manualmente o diretório dos arquivos Python",
+            it: "Specifica manualmente la directory dei file Python"
         },
         "cancel" => {
             en: "Cancel operation",
@@ -216,19 +401,157 @@ lazy_static! {
             ko: "작업 취소",
             fr: "Annuler l'opération",
             de: "Vorgang abbrechen",
-            ru: "Отменить операцию"
+            ru: "Отменить операцию",
+            es: "Cancelar operación",
+            pt: "Cancelar operação",
+            it: "Annulla operazione"
         },
         
         // 更多翻译...
 
+        "confirm_overwrite_requirements" => {
+            en: "{0} was not generated by PyWand. Overwrite it? The original will be backed up to {1}",
+            zh: "{0} 不是PyWand生成的文件，是否覆盖？原文件会备份为 {1}",
+            ja: "{0} はPyWandで生成されたファイルではありません。上書きしますか？元のファイルは {1} にバックアップされます",
+            ko: "{0} 은(는) PyWand가 생성한 파일이 아닙니다. 덮어쓰시겠습니까? 원본은 {1} 에 백업됩니다",
+            fr: "{0} n'a pas été généré par PyWand. L'écraser ? L'original sera sauvegardé dans {1}",
+            de: "{0} wurde nicht von PyWand erzeugt. Überschreiben? Das Original wird nach {1} gesichert",
+            ru: "{0} создан не PyWand. Перезаписать? Исходный файл будет сохранён в {1}",
+            es: "{0} no fue generado por PyWand. ¿Sobrescribirlo? El original se guardará en {1}",
+            pt: "{0} não foi gerado pelo PyWand. Sobrescrever? O original será salvo em {1}",
+            it: "{0} non è stato generato da PyWand. Sovrascriverlo? L'originale verrà salvato in {1}"
+        },
+        "requirements_backed_up" => {
+            en: "Backed up the existing file to {}",
+            zh: "已将原文件备份为 {}",
+            ja: "既存のファイルを {} にバックアップしました",
+            ko: "기존 파일을 {} 에 백업했습니다",
+            fr: "Fichier existant sauvegardé dans {}",
+            de: "Vorhandene Datei nach {} gesichert",
+            ru: "Существующий файл сохранён в {}",
+            es: "Se guardó una copia del archivo existente en {}",
+            pt: "O arquivo existente foi salvo em {}",
+            it: "File esistente salvato in {}"
+        },
+        "requirements_kept" => {
+            en: "Kept the existing {}, no requirements were written",
+            zh: "保留现有的 {}，未写入依赖",
+            ja: "既存の {} を保持しました。依存関係は書き込まれていません",
+            ko: "기존 {} 을(를) 유지했습니다. 의존성을 쓰지 않았습니다",
+            fr: "{} existant conservé, aucune dépendance écrite",
+            de: "Vorhandene {} beibehalten, keine Abhängigkeiten geschrieben",
+            ru: "Существующий {} сохранён, зависимости не записаны",
+            es: "Se conservó el {} existente, no se escribieron dependencias",
+            pt: "O {} existente foi mantido, nenhuma dependência foi escrita",
+            it: "{} esistente mantenuto, nessuna dipendenza scritta"
+        },
+        "pinning_requirements" => {
+            en: "Pinning dependency versions...",
+            zh: "锁定依赖版本...",
+            ja: "依存関係のバージョンを固定しています...",
+            ko: "종속성 버전을 고정하는 중...",
+            fr: "Épinglage des versions des dépendances...",
+            de: "Abhängigkeitsversionen werden festgelegt...",
+            ru: "Фиксация версий зависимостей...",
+            es: "Fijando las versiones de las dependencias...",
+            pt: "Fixando as versões das dependências...",
+            it: "Blocco delle versioni delle dipendenze..."
+        },
+        "pin_skipped_user_file" => {
+            en: "{} was not generated by PyWand, skipping version pinning",
+            zh: "{} 不是PyWand生成的文件，跳过版本锁定",
+            ja: "{} はPyWandで生成されたファイルではないため、バージョンの固定をスキップします",
+            ko: "{} 은(는) PyWand가 생성한 파일이 아니므로 버전 고정을 건너뜁니다",
+            fr: "{} n'a pas été généré par PyWand, épinglage des versions ignoré",
+            de: "{} wurde nicht von PyWand erzeugt, Versionsfixierung übersprungen",
+            ru: "{} создан не PyWand, фиксация версий пропущена",
+            es: "{} no fue generado por PyWand, se omite la fijación de versiones",
+            pt: "{} não foi gerado pelo PyWand, fixação de versões ignorada",
+            it: "{} non è stato generato da PyWand, blocco delle versioni saltato"
+        },
+        "requirements_diff" => {
+            en: "Changes in {}:",
+            zh: "{} 的变化：",
+            ja: "{} の変更点:",
+            ko: "{} 변경 사항:",
+            fr: "Modifications de {} :",
+            de: "Änderungen in {}:",
+            ru: "Изменения в {}:",
+            es: "Cambios en {}:",
+            pt: "Alterações em {}:",
+            it: "Modifiche in {}:"
+        },
+        "requirements_unchanged" => {
+            en: "{} is unchanged",
+            zh: "{} 没有变化",
+            ja: "{} に変更はありません",
+            ko: "{} 에 변경 사항이 없습니다",
+            fr: "{} n'a pas changé",
+            de: "{} ist unverändert",
+            ru: "{} не изменился",
+            es: "{} no ha cambiado",
+            pt: "{} não foi alterado",
+            it: "{} non è cambiato"
+        },
+        "initializing_project" => {
+            en: "Initializing the PyWand project...",
+            zh: "正在初始化PyWand项目...",
+            ja: "PyWandプロジェクトを初期化しています...",
+            ko: "PyWand 프로젝트를 초기화하는 중...",
+            fr: "Initialisation du projet PyWand...",
+            de: "PyWand-Projekt wird initialisiert...",
+            ru: "Инициализация проекта PyWand...",
+            es: "Inicializando el proyecto PyWand...",
+            pt: "Inicializando o projeto PyWand...",
+            it: "Inizializzazione del progetto PyWand..."
+        },
+        "project_initialized" => {
+            en: "Project initialized!",
+            zh: "项目初始化完成！",
+            ja: "プロジェクトを初期化しました！",
+            ko: "프로젝트 초기화가 완료되었습니다!",
+            fr: "Projet initialisé !",
+            de: "Projekt initialisiert!",
+            ru: "Проект инициализирован!",
+            es: "¡Proyecto inicializado!",
+            pt: "Projeto inicializado!",
+            it: "Progetto inizializzato!"
+        },
+        "select_project_language" => {
+            en: "Select the interface language for this project",
+            zh: "选择项目使用的界面语言",
+            ja: "このプロジェクトで使用する表示言語を選択してください",
+            ko: "이 프로젝트에서 사용할 인터페이스 언어를 선택하세요",
+            fr: "Sélectionnez la langue de l'interface pour ce projet",
+            de: "Oberflächensprache für dieses Projekt auswählen",
+            ru: "Выберите язык интерфейса для этого проекта",
+            es: "Seleccione el idioma de la interfaz para este proyecto",
+            pt: "Selecione o idioma da interface para este projeto",
+            it: "Seleziona la lingua dell'interfaccia per questo progetto"
+        },
+        "init_file_kept" => {
+            en: "{} already exists, left unchanged",
+            zh: "{}已存在，保持不变",
+            ja: "{}は既に存在するため、変更しません",
+            ko: "{}이(가) 이미 있어 변경하지 않습니다",
+            fr: "{} existe déjà, il reste inchangé",
+            de: "{} existiert bereits und bleibt unverändert",
+            ru: "{} уже существует и оставлен без изменений",
+            es: "{} ya existe, se deja sin cambios",
+            pt: "{} já existe, mantido sem alterações",
+            it: "{} esiste già, lasciato invariato"
+        },
         "requirements_created" => {
-            en: "Created requirements.txt file in {}",
-            zh: "创建了requirements.txt文件在 {}",
-            ja: "{}にrequirements.txtファイルを作成しました",
-            ko: "{}에 requirements.txt 파일을 생성했습니다",
-            fr: "Fichier requirements.txt créé dans {}",
-            de: "requirements.txt-Datei in {} erstellt",
-            ru: "Файл requirements.txt создан в {}"
+            en: "Created {}",
+            zh: "创建了 {}",
+            ja: "{}を作成しました",
+            ko: "{}을(를) 생성했습니다",
+            fr: "Fichier {} créé",
+            de: "{} erstellt",
+            ru: "Создан файл {}",
+            es: "Archivo {} creado",
+            pt: "Arquivo {} criado",
+            it: "File {} creato"
         },
         
         // 运行脚本
@@ -239,7 +562,10 @@ lazy_static! {
             ko: "Python 스크립트 실행 중",
             fr: "Exécution du script Python",
             de: "Python-Skript wird ausgeführt",
-            ru: "Запуск скрипта Python"
+            ru: "Запуск скрипта Python",
+            es: "Ejecutando script de Python",
+            pt: "Executando script Python",
+            it: "Esecuzione dello script Python"
         },
         "script" => {
             en: "Script: {}",
@@ -248,7 +574,10 @@ lazy_static! {
             ko: "스크립트: {}",
             fr: "Script : {}",
             de: "Skript: {}",
-            ru: "Скрипт: {}"
+            ru: "Скрипт: {}",
+            es: "Script: {}",
+            pt: "Script: {}",
+            it: "Script: {}"
         },
         
         // 使用提示
@@ -259,7 +588,10 @@ lazy_static! {
             ko: "PyWand 사용 팁:",
             fr: "Conseils d'utilisation de PyWand :",
             de: "PyWand Nutzungstipps:",
-            ru: "Советы по использованию PyWand:"
+            ru: "Советы по использованию PyWand:",
+            es: "Consejos de uso de PyWand:",
+            pt: "Dicas de uso do PyWand:",
+            it: "Suggerimenti per l'uso di PyWand:"
         },
 
         // 添加下面这些新翻译
@@ -270,7 +602,22 @@ lazy_static! {
             ko: "Python 버전 선택",
             fr: "Sélectionner la version Python",
             de: "Python-Version auswählen",
-            ru: "Выберите версию Python"
+            ru: "Выберите версию Python",
+            es: "Seleccione la versión de Python",
+            pt: "Selecione a versão do Python",
+            it: "Seleziona la versione di Python"
+        },
+        "python_versions_fallback" => {
+            en: "Could not query Python versions from uv, using the built-in list: {}",
+            zh: "无法通过uv查询Python版本，使用内置列表: {}",
+            ja: "uvからPythonバージョンを取得できないため、内蔵リストを使用します: {}",
+            ko: "uv에서 Python 버전을 조회할 수 없어 내장 목록을 사용합니다: {}",
+            fr: "Impossible d'interroger uv sur les versions de Python, utilisation de la liste intégrée : {}",
+            de: "Python-Versionen konnten nicht über uv abgefragt werden, integrierte Liste wird verwendet: {}",
+            ru: "Не удалось получить версии Python через uv, используется встроенный список: {}",
+            es: "No se pudieron consultar las versiones de Python con uv, se usa la lista integrada: {}",
+            pt: "Não foi possível consultar as versões do Python pelo uv, usando a lista embutida: {}",
+            it: "Impossibile interrogare uv sulle versioni di Python, verrà usato l'elenco integrato: {}"
         },
         "creating_venv" => {
             en: "Creating Python {} virtual environment...",
@@ -279,7 +626,46 @@ lazy_static! {
             ko: "Python {} 가상 환경 생성 중...",
             fr: "Création de l'environnement virtuel Python {}...",
             de: "Python {}-Virtualenv wird erstellt...",
-            ru: "Создание виртуальной среды Python {}..."
+            ru: "Создание виртуальной среды Python {}...",
+            es: "Creando el entorno virtual de Python {}...",
+            pt: "Criando o ambiente virtual Python {}...",
+            it: "Creazione dell'ambiente virtuale Python {}..."
+        },
+        "remove_partial_venv" => {
+            en: "Dependency installation failed. Remove the newly created virtual environment {}?",
+            zh: "依赖安装失败，是否删除本次新建的虚拟环境 {}？",
+            ja: "依存関係のインストールに失敗しました。新しく作成した仮想環境 {} を削除しますか？",
+            ko: "종속성 설치에 실패했습니다. 새로 만든 가상 환경 {}을(를) 삭제하시겠습니까?",
+            fr: "L'installation des dépendances a échoué. Supprimer l'environnement virtuel nouvellement créé {} ?",
+            de: "Installation der Abhängigkeiten fehlgeschlagen. Neu erstellte virtuelle Umgebung {} entfernen?",
+            ru: "Не удалось установить зависимости. Удалить только что созданную виртуальную среду {}?",
+            es: "La instalación de dependencias falló. ¿Eliminar el entorno virtual recién creado {}?",
+            pt: "A instalação das dependências falhou. Remover o ambiente virtual recém-criado {}?",
+            it: "Installazione delle dipendenze non riuscita. Rimuovere l'ambiente virtuale appena creato {}?"
+        },
+        "partial_venv_removed" => {
+            en: "Removed incomplete virtual environment: {}",
+            zh: "已删除不完整的虚拟环境: {}",
+            ja: "不完全な仮想環境を削除しました: {}",
+            ko: "불완전한 가상 환경을 삭제했습니다: {}",
+            fr: "Environnement virtuel incomplet supprimé : {}",
+            de: "Unvollständige virtuelle Umgebung entfernt: {}",
+            ru: "Неполная виртуальная среда удалена: {}",
+            es: "Entorno virtual incompleto eliminado: {}",
+            pt: "Ambiente virtual incompleto removido: {}",
+            it: "Ambiente virtuale incompleto rimosso: {}"
+        },
+        "partial_venv_kept" => {
+            en: "Kept incomplete virtual environment {} (use --clean-on-failure to remove it automatically)",
+            zh: "已保留不完整的虚拟环境 {}（使用--clean-on-failure可自动删除）",
+            ja: "不完全な仮想環境 {} を残しました（--clean-on-failure で自動削除できます）",
+            ko: "불완전한 가상 환경 {}을(를) 유지했습니다 (--clean-on-failure로 자동 삭제 가능)",
+            fr: "Environnement virtuel incomplet {} conservé (utilisez --clean-on-failure pour le supprimer automatiquement)",
+            de: "Unvollständige virtuelle Umgebung {} beibehalten (mit --clean-on-failure automatisch entfernen)",
+            ru: "Неполная виртуальная среда {} сохранена (используйте --clean-on-failure для автоматического удаления)",
+            es: "Se conservó el entorno virtual incompleto {} (use --clean-on-failure para eliminarlo automáticamente)",
+            pt: "Ambiente virtual incompleto {} mantido (use --clean-on-failure para removê-lo automaticamente)",
+            it: "Ambiente virtuale incompleto {} mantenuto (usa --clean-on-failure per rimuoverlo automaticamente)"
         },
         "installing_dependencies" => {
             en: "Installing dependencies...",
@@ -288,7 +674,10 @@ lazy_static! {
             ko: "종속성 설치 중...",
             fr: "Installation des dépendances...",
             de: "Abhängigkeiten werden installiert...",
-            ru: "Установка зависимостей..."
+            ru: "Установка зависимостей...",
+            es: "Instalando dependencias...",
+            pt: "Instalando dependências...",
+            it: "Installazione delle dipendenze..."
         },
         "created_activation_scripts" => {
             en: "Created activation scripts",
@@ -297,7 +686,10 @@ lazy_static! {
             ko: "활성화 스크립트 생성됨",
             fr: "Scripts d'activation créés",
             de: "Aktivierungsskripte erstellt",
-            ru: "Созданы скрипты активации"
+            ru: "Созданы скрипты активации",
+            es: "Scripts de activación creados",
+            pt: "Scripts de ativação criados",
+            it: "Script di attivazione creati"
         },
         "setup_complete" => {
             en: "Setup complete!",
@@ -306,7 +698,10 @@ lazy_static! {
             ko: "설정 완료!",
             fr: "Configuration terminée !",
             de: "Einrichtung abgeschlossen!",
-            ru: "Настройка завершена!"
+            ru: "Настройка завершена!",
+            es: "¡Configuración completada!",
+            pt: "Configuração concluída!",
+            it: "Configurazione completata!"
         },
         "to_activate_venv" => {
             en: "To activate virtual environment, run:",
@@ -315,7 +710,10 @@ lazy_static! {
             ko: "가상 환경을 활성화하려면 실행하세요:",
             fr: "Pour activer l'environnement virtuel, exécutez :",
             de: "Um die virtuelle Umgebung zu aktivieren, führen Sie aus:",
-            ru: "Чтобы активировать виртуальную среду, выполните:"
+            ru: "Чтобы активировать виртуальную среду, выполните:",
+            es: "Para activar el entorno virtual, ejecute:",
+            pt: "Para ativar o ambiente virtual, execute:",
+            it: "Per attivare l'ambiente virtuale, esegui:"
         },
         "exporting_offline" => {
             en: "Export for Offline Development",
@@ -324,7 +722,10 @@ lazy_static! {
             ko: "오프라인 개발용 내보내기",
             fr: "Exporter pour le développement hors ligne",
             de: "Export für die Offline-Entwicklung",
-            ru: "Экспорт для автономной разработки"
+            ru: "Экспорт для автономной разработки",
+            es: "Exportar para desarrollo sin conexión",
+            pt: "Exportar para desenvolvimento offline",
+            it: "Esporta per lo sviluppo offline"
         },
         "select_os" => {
             en: "Select target operating system",
@@ -333,7 +734,10 @@ lazy_static! {
             ko: "대상 운영 체제 선택",
             fr: "Sélectionner le système d'exploitation cible",
             de: "Ziel-Betriebssystem auswählen",
-            ru: "Выберите целевую операционную систему"
+            ru: "Выберите целевую операционную систему",
+            es: "Seleccione el sistema operativo de destino",
+            pt: "Selecione o sistema operacional de destino",
+            it: "Seleziona il sistema operativo di destinazione"
         },
         "preparing_package" => {
             en: "Preparing package for {} and Python {}...",
@@ -342,7 +746,10 @@ lazy_static! {
             ko: "{}와 Python {}용 패키지 준비 중...",
             fr: "Préparation du package pour {} et Python {}...",
             de: "Paket für {} und Python {} wird vorbereitet...",
-            ru: "Подготовка пакета для {} и Python {}..."
+            ru: "Подготовка пакета для {} и Python {}...",
+            es: "Preparando el paquete para {} y Python {}...",
+            pt: "Preparando o pacote para {} e Python {}...",
+            it: "Preparazione del pacchetto per {} e Python {}..."
         },
         "files_copied" => {
             en: "Files copied successfully",
@@ -351,7 +758,10 @@ lazy_static! {
             ko: "파일이 성공적으로 복사됨",
             fr: "Fichiers copiés avec succès",
             de: "Dateien erfolgreich kopiert",
-            ru: "Файлы успешно скопированы"
+            ru: "Файлы успешно скопированы",
+            es: "Archivos copiados correctamente",
+            pt: "Arquivos copiados com sucesso",
+            it: "File copiati correttamente"
         },
         "scripts_created" => {
             en: "Setup scripts created",
@@ -360,7 +770,10 @@ lazy_static! {
             ko: "설정 스크립트 생성됨",
             fr: "Scripts de configuration créés",
             de: "Setup-Skripte erstellt",
-            ru: "Созданы скрипты настройки"
+            ru: "Созданы скрипты настройки",
+            es: "Scripts de instalación creados",
+            pt: "Scripts de instalação criados",
+            it: "Script di installazione creati"
         },
         "readme_created" => {
             en: "README file created",
@@ -369,7 +782,106 @@ lazy_static! {
             ko: "README 파일 생성됨",
             fr: "Fichier README créé",
             de: "README-Datei erstellt",
-            ru: "Файл README создан"
+            ru: "Файл README создан",
+            es: "Archivo README creado",
+            pt: "Arquivo README criado",
+            it: "File README creato"
+        },
+        "manifest_created" => {
+            en: "MANIFEST.sha256 checksum list created",
+            zh: "创建了MANIFEST.sha256校验清单",
+            ja: "MANIFEST.sha256チェックサム一覧が作成されました",
+            ko: "MANIFEST.sha256 체크섬 목록 생성됨",
+            fr: "Liste de sommes de contrôle MANIFEST.sha256 créée",
+            de: "Prüfsummenliste MANIFEST.sha256 erstellt",
+            ru: "Список контрольных сумм MANIFEST.sha256 создан",
+            es: "Lista de sumas de verificación MANIFEST.sha256 creada",
+            pt: "Lista de checksums MANIFEST.sha256 criada",
+            it: "Elenco dei checksum MANIFEST.sha256 creato"
+        },
+        "export_size" => {
+            en: "Package contents: {} files, {} uncompressed",
+            zh: "导出内容: {}个文件，未压缩大小{}",
+            ja: "エクスポート内容: {}個のファイル、非圧縮サイズ{}",
+            ko: "내보내기 내용: 파일 {}개, 압축 전 크기 {}",
+            fr: "Contenu du paquet : {} fichiers, {} non compressés",
+            de: "Paketinhalt: {} Dateien, {} unkomprimiert",
+            ru: "Содержимое пакета: файлов — {}, {} без сжатия",
+            es: "Contenido del paquete: {} archivos, {} sin comprimir",
+            pt: "Conteúdo do pacote: {} arquivos, {} sem compressão",
+            it: "Contenuto del pacchetto: {} file, {} non compressi"
+        },
+        "confirm_archive" => {
+            en: "Create the archive?",
+            zh: "是否创建归档？",
+            ja: "アーカイブを作成しますか？",
+            ko: "아카이브를 만드시겠습니까?",
+            fr: "Créer l'archive ?",
+            de: "Archiv erstellen?",
+            ru: "Создать архив?",
+            es: "¿Crear el archivo comprimido?",
+            pt: "Criar o arquivo compactado?",
+            it: "Creare l'archivio?"
+        },
+        "data_files_included" => {
+            en: "Including {} data file|Including {} data files",
+            zh: "导出{}个数据文件",
+            ja: "{}個のデータファイルを含めます",
+            ko: "데이터 파일 {}개를 포함합니다",
+            fr: "{} fichier de données inclus|{} fichiers de données inclus",
+            de: "{} Datendatei wird einbezogen|{} Datendateien werden einbezogen",
+            ru: "Включён {} файл данных|Включено {} файла данных|Включено {} файлов данных",
+            es: "Se incluye {} archivo de datos|Se incluyen {} archivos de datos",
+            pt: "Incluindo {} arquivo de dados|Incluindo {} arquivos de dados",
+            it: "Incluso {} file di dati|Inclusi {} file di dati"
+        },
+        "batch_export_summary" => {
+            en: "Created {} package:|Created {} packages:",
+            zh: "已生成{}个导出包：",
+            ja: "{}個のパッケージを作成しました:",
+            ko: "패키지 {}개를 생성했습니다:",
+            fr: "{} paquet créé :|{} paquets créés :",
+            de: "{} Paket erstellt:|{} Pakete erstellt:",
+            ru: "Создан {} пакет:|Создано {} пакета:|Создано {} пакетов:",
+            es: "Se creó {} paquete:|Se crearon {} paquetes:",
+            pt: "{} pacote criado:|{} pacotes criados:",
+            it: "Creato {} pacchetto:|Creati {} pacchetti:"
+        },
+        "downloading_wheels" => {
+            en: "Downloading wheels for the target platform...",
+            zh: "下载目标平台的wheel文件...",
+            ja: "対象プラットフォームのwheelファイルをダウンロードしています...",
+            ko: "대상 플랫폼용 wheel 파일을 다운로드하는 중...",
+            fr: "Téléchargement des wheels pour la plateforme cible...",
+            de: "Wheels für die Zielplattform werden heruntergeladen...",
+            ru: "Загрузка wheel-файлов для целевой платформы...",
+            es: "Descargando wheels para la plataforma de destino...",
+            pt: "Baixando wheels para a plataforma de destino...",
+            it: "Download dei wheel per la piattaforma di destinazione..."
+        },
+        "wheels_bundled" => {
+            en: "Bundled {} wheel file|Bundled {} wheel files",
+            zh: "已打包{}个wheel文件",
+            ja: "{}個のwheelファイルを同梱しました",
+            ko: "wheel 파일 {}개를 포함했습니다",
+            fr: "{} fichier wheel inclus|{} fichiers wheel inclus",
+            de: "{} Wheel-Datei gebündelt|{} Wheel-Dateien gebündelt",
+            ru: "Добавлен {} wheel-файл|Добавлено {} wheel-файла|Добавлено {} wheel-файлов",
+            es: "Se incluyó {} archivo wheel|Se incluyeron {} archivos wheel",
+            pt: "{} arquivo wheel incluído|{} arquivos wheel incluídos",
+            it: "Incluso {} file wheel|Inclusi {} file wheel"
+        },
+        "wheel_download_failed" => {
+            en: "Failed to download wheels for {}; packages without a binary wheel for this platform cannot be bundled",
+            zh: "无法下载{}的wheel文件，没有该平台二进制wheel的包无法打包",
+            ja: "{}のwheelファイルをダウンロードできませんでした。このプラットフォーム用のバイナリwheelがないパッケージは同梱できません",
+            ko: "{}용 wheel 파일을 다운로드하지 못했습니다. 이 플랫폼용 바이너리 wheel이 없는 패키지는 포함할 수 없습니다",
+            fr: "Impossible de télécharger les wheels pour {} ; les paquets sans wheel binaire pour cette plateforme ne peuvent pas être inclus",
+            de: "Wheels für {} konnten nicht heruntergeladen werden; Pakete ohne binäres Wheel für diese Plattform können nicht gebündelt werden",
+            ru: "Не удалось загрузить wheel-файлы для {}; пакеты без бинарного wheel для этой платформы нельзя добавить",
+            es: "No se pudieron descargar los wheels para {}; los paquetes sin wheel binario para esta plataforma no se pueden incluir",
+            pt: "Não foi possível baixar os wheels para {}; pacotes sem wheel binário para esta plataforma não podem ser incluídos",
+            it: "Impossibile scaricare i wheel per {}; i pacchetti senza wheel binario per questa piattaforma non possono essere inclusi"
         },
         "creating_archive" => {
             en: "Creating archive {}...",
@@ -378,7 +890,10 @@ lazy_static! {
             ko: "아카이브 {} 생성 중...",
             fr: "Création de l'archive {}...",
             de: "Archiv {} wird erstellt...",
-            ru: "Создание архива {}..."
+            ru: "Создание архива {}...",
+            es: "Creando el archivo comprimido {}...",
+            pt: "Criando o arquivo compactado {}...",
+            it: "Creazione dell'archivio {}..."
         },
         "archive_created" => {
             en: "Archive created successfully",
@@ -387,7 +902,10 @@ lazy_static! {
             ko: "아카이브가 성공적으로 생성됨",
             fr: "Archive créée avec succès",
             de: "Archiv erfolgreich erstellt",
-            ru: "Архив успешно создан"
+            ru: "Архив успешно создан",
+            es: "Archivo comprimido creado correctamente",
+            pt: "Arquivo compactado criado com sucesso",
+            it: "Archivio creato correttamente"
         },
         "export_complete" => {
             en: "Export completed successfully!",
@@ -396,16 +914,22 @@ lazy_static! {
             ko: "내보내기가 성공적으로 완료되었습니다!",
             fr: "Exportation terminée avec succès !",
             de: "Export erfolgreich abgeschlossen!",
-            ru: "Экспорт успешно завершен!"
+            ru: "Экспорт успешно завершен!",
+            es: "¡Exportación completada correctamente!",
+            pt: "Exportação concluída com sucesso!",
+            it: "Esportazione completata con successo!"
         },
         "package_saved" => {
-            en: "Package saved to: ./{}",
-            zh: "包已保存到: ./{}",
-            ja: "パッケージが./{}に保存されました",
-            ko: "패키지가 ./{}에 저장됨",
-            fr: "Package enregistré dans: ./{}",
-            de: "Paket gespeichert unter: ./{}",
-            ru: "Пакет сохранен в: ./{}"
+            en: "Package saved to: {}",
+            zh: "包已保存到: {}",
+            ja: "パッケージが{}に保存されました",
+            ko: "패키지가 {}에 저장됨",
+            fr: "Package enregistré dans : {}",
+            de: "Paket gespeichert unter: {}",
+            ru: "Пакет сохранен в: {}",
+            es: "Paquete guardado en: {}",
+            pt: "Pacote salvo em: {}",
+            it: "Pacchetto salvato in: {}"
         },
         "running_in_test" => {
             en: "Running in test mode with test suite",
@@ -414,7 +938,10 @@ lazy_static! {
             ko: "테스트 스위트로 테스트 모드에서 실행 중",
             fr: "Exécution en mode test avec la suite de tests",
             de: "Ausführung im Testmodus mit Test-Suite",
-            ru: "Запуск в тестовом режиме с использованием тестового набора"
+            ru: "Запуск в тестовом режиме с использованием тестового набора",
+            es: "Ejecutando en modo de prueba con el conjunto de pruebas",
+            pt: "Executando em modo de teste com o conjunto de testes",
+            it: "Esecuzione in modalità test con la suite di test"
         },
         "using_directory" => {
             en: "Using directory: {}",
@@ -423,7 +950,10 @@ lazy_static! {
             ko: "디렉토리 사용: {}",
             fr: "Utilisation du répertoire : {}",
             de: "Verzeichnis wird verwendet: {}",
-            ru: "Используется каталог: {}"
+            ru: "Используется каталог: {}",
+            es: "Usando el directorio: {}",
+            pt: "Usando o diretório: {}",
+            it: "Directory in uso: {}"
         },
         "running_local_dev" => {
             en: "Running local development workflow",
@@ -432,7 +962,10 @@ lazy_static! {
             ko: "로컬 개발 워크플로우 실행 중",
             fr: "Exécution du flux de développement local",
             de: "Lokaler Entwicklungsablauf wird ausgeführt",
-            ru: "Выполнение рабочего процесса локальной разработки"
+            ru: "Выполнение рабочего процесса локальной разработки",
+            es: "Ejecutando el flujo de desarrollo local",
+            pt: "Executando o fluxo de desenvolvimento local",
+            it: "Esecuzione del flusso di sviluppo locale"
         },
         "generating_req" => {
             en: "Generating requirements.txt file",
@@ -441,7 +974,10 @@ lazy_static! {
             ko: "requirements.txt 파일 생성 중",
             fr: "Génération du fichier requirements.txt",
             de: "requirements.txt-Datei wird generiert",
-            ru: "Создание файла requirements.txt"
+            ru: "Создание файла requirements.txt",
+            es: "Generando el archivo requirements.txt",
+            pt: "Gerando o arquivo requirements.txt",
+            it: "Generazione del file requirements.txt"
         },
         "scanning_dir" => {
             en: "Scanning directory: {}",
@@ -450,7 +986,10 @@ lazy_static! {
             ko: "디렉토리 스캔: {}",
             fr: "Analyse du répertoire : {}",
             de: "Verzeichnis wird gescannt: {}",
-            ru: "Сканирование каталога: {}"
+            ru: "Сканирование каталога: {}",
+            es: "Escaneando el directorio: {}",
+            pt: "Verificando o diretório: {}",
+            it: "Scansione della directory: {}"
         },
         "output_dir" => {
             en: "Output directory: {}",
@@ -459,7 +998,10 @@ lazy_static! {
             ko: "출력 디렉토리: {}",
             fr: "Répertoire de sortie : {}",
             de: "Ausgabeverzeichnis: {}",
-            ru: "Выходной каталог: {}"
+            ru: "Выходной каталог: {}",
+            es: "Directorio de salida: {}",
+            pt: "Diretório de saída: {}",
+            it: "Directory di output: {}"
         },
         "req_generated" => {
             en: "Requirements file generated!",
@@ -468,7 +1010,10 @@ lazy_static! {
             ko: "요구 사항 파일이 생성되었습니다!",
             fr: "Fichier des exigences généré !",
             de: "Anforderungsdatei wurde generiert!",
-            ru: "Файл требований создан!"
+            ru: "Файл требований создан!",
+            es: "¡Archivo de dependencias generado!",
+            pt: "Arquivo de dependências gerado!",
+            it: "File delle dipendenze generato!"
         },
         "no_command" => {
             en: "No command specified, using default workflow",
@@ -477,7 +1022,10 @@ lazy_static! {
             ko: "명령이 지정되지 않았습니다. 기본 워크플로우 사용",
             fr: "Aucune commande spécifiée, utilisation du flux par défaut",
             de: "Kein Befehl angegeben, Standardablauf wird verwendet",
-            ru: "Команда не указана, используется рабочий процесс по умолчанию"
+            ru: "Команда не указана, используется рабочий процесс по умолчанию",
+            es: "No se especificó ningún comando, usando el flujo predeterminado",
+            pt: "Nenhum comando especificado, usando o fluxo padrão",
+            it: "Nessun comando specificato, uso del flusso predefinito"
         },
         "scanning_current" => {
             en: "Scanning current directory",
@@ -486,7 +1034,10 @@ lazy_static! {
             ko: "현재 디렉토리 스캔 중",
             fr: "Analyse du répertoire courant",
             de: "Aktuelles Verzeichnis wird gescannt",
-            ru: "Сканирование текущего каталога"
+            ru: "Сканирование текущего каталога",
+            es: "Escaneando el directorio actual",
+            pt: "Verificando o diretório atual",
+            it: "Scansione della directory corrente"
         },
         
         // 语言设置
@@ -497,7 +1048,10 @@ lazy_static! {
             ko: "언어 설정이 변경되었습니다",
             fr: "Paramètre de langue modifié",
             de: "Spracheinstellung geändert",
-            ru: "Настройки языка изменены"
+            ru: "Настройки языка изменены",
+            es: "Idioma cambiado",
+            pt: "Idioma alterado",
+            it: "Lingua modificata"
         },
         "unsupported_language" => {
             en: "Unsupported language code: {}. Using default language (system language)",
@@ -506,7 +1060,10 @@ lazy_static! {
             ko: "지원되지 않는 언어 코드: {}. 기본 언어(시스템 언어) 사용",
             fr: "Code de langue non pris en charge : {}. Utilisation de la langue par défaut (langue du système)",
             de: "Nicht unterstützter Sprachcode: {}. Standardsprache (Systemsprache) wird verwendet",
-            ru: "Неподдерживаемый код языка: {}. Используется язык по умолчанию (системный язык)"
+            ru: "Неподдерживаемый код языка: {}. Используется язык по умолчанию (системный язык)",
+            es: "Código de idioma no compatible: {}. Se usará el idioma predeterminado (idioma del sistema)",
+            pt: "Código de idioma não suportado: {}. Usando o idioma padrão (idioma do sistema)",
+            it: "Codice lingua non supportato: {}. Verrà usata la lingua predefinita (lingua di sistema)"
         },
         "available_languages" => {
             en: "Available language codes",
@@ -515,7 +1072,10 @@ lazy_static! {
             ko: "사용 가능한 언어 코드",
             fr: "Codes de langue disponibles",
             de: "Verfügbare Sprachcodes",
-            ru: "Доступные коды языков"
+            ru: "Доступные коды языков",
+            es: "Códigos de idioma disponibles",
+            pt: "Códigos de idioma disponíveis",
+            it: "Codici lingua disponibili"
         },
         "scan_create_req" => {
             en: "Scan project and create requirements.txt",
@@ -524,7 +1084,10 @@ lazy_static! {
             ko: "프로젝트를 스캔하고 requirements.txt 생성",
             fr: "Analyser le projet et créer requirements.txt",
             de: "Projekt scannen und requirements.txt erstellen",
-            ru: "Сканировать проект и создать requirements.txt"
+            ru: "Сканировать проект и создать requirements.txt",
+            es: "Escanear el proyecto y crear requirements.txt",
+            pt: "Verificar o projeto e criar requirements.txt",
+            it: "Scansiona il progetto e crea requirements.txt"
         },
         "setup_local_dev" => {
             en: "Set up local development environment",
@@ -533,7 +1096,10 @@ lazy_static! {
             ko: "로컬 개발 환경 설정",
             fr: "Configurer l'environnement de développement local",
             de: "Lokale Entwicklungsumgebung einrichten",
-            ru: "Настроить локальную среду разработки"
+            ru: "Настроить локальную среду разработки",
+            es: "Configurar el entorno de desarrollo local",
+            pt: "Configurar o ambiente de desenvolvimento local",
+            it: "Configura l'ambiente di sviluppo locale"
         },
         "export_to_other" => {
             en: "Export project to other platforms",
@@ -542,7 +1108,10 @@ lazy_static! {
             ko: "다른 플랫폼으로 프로젝트 내보내기",
             fr: "Exporter le projet vers d'autres plateformes",
             de: "Projekt auf andere Plattformen exportieren",
-            ru: "Экспортировать проект на другие платформы"
+            ru: "Экспортировать проект на другие платформы",
+            es: "Exportar el proyecto a otras plataformas",
+            pt: "Exportar o projeto para outras plataformas",
+            it: "Esporta il progetto su altre piattaforme"
         },
         "run_python_script" => {
             en: "Run Python script",
@@ -551,7 +1120,10 @@ lazy_static! {
             ko: "Python 스크립트 실행",
             fr: "Exécuter un script Python",
             de: "Python-Skript ausführen",
-            ru: "Запустить скрипт Python"
+            ru: "Запустить скрипт Python",
+            es: "Ejecutar un script de Python",
+            pt: "Executar um script Python",
+            it: "Esegui uno script Python"
         },
         "execute_uv_command" => {
             en: "Execute UV command",
@@ -560,7 +1132,10 @@ lazy_static! {
             ko: "UV 명령 실행",
             fr: "Exécuter la commande UV",
             de: "UV-Befehl ausführen",
-            ru: "Выполнить команду UV"
+            ru: "Выполнить команду UV",
+            es: "Ejecutar un comando de UV",
+            pt: "Executar um comando UV",
+            it: "Esegui un comando UV"
         },
         "set_interface_language" => {
             en: "Set interface language",
@@ -569,7 +1144,10 @@ lazy_static! {
             ko: "인터페이스 언어 설정",
             fr: "Définir la langue de l'interface",
             de: "Oberflächensprache festlegen",
-            ru: "Установить язык интерфейса"
+            ru: "Установить язык интерфейса",
+            es: "Establecer el idioma de la interfaz",
+            pt: "Definir o idioma da interface",
+            it: "Imposta la lingua dell'interfaccia"
         },
         "installing_packages" => {
             en: "Installing Python packages",
@@ -578,7 +1156,10 @@ lazy_static! {
             ko: "Python 패키지 설치",
             fr: "Installation des paquets Python",
             de: "Python-Pakete installieren",
-            ru: "Установка пакетов Python"
+            ru: "Установка пакетов Python",
+            es: "Instalando paquetes de Python",
+            pt: "Instalando pacotes Python",
+            it: "Installazione dei pacchetti Python"
         },
         "packages_installed" => {
             en: "Packages installed successfully",
@@ -587,7 +1168,10 @@ lazy_static! {
             ko: "패키지가 성공적으로 설치됨",
             fr: "Paquets installés avec succès",
             de: "Pakete erfolgreich installiert",
-            ru: "Пакеты успешно установлены"
+            ru: "Пакеты успешно установлены",
+            es: "Paquetes instalados correctamente",
+            pt: "Pacotes instalados com sucesso",
+            it: "Pacchetti installati correttamente"
         },
         "packages_install_failed" => {
             en: "Package installation failed",
@@ -596,7 +1180,10 @@ lazy_static! {
             ko: "패키지 설치 실패",
             fr: "L'installation du paquet a échoué",
             de: "Paketinstallation fehlgeschlagen",
-            ru: "Установка пакета не удалась"
+            ru: "Установка пакета не удалась",
+            es: "Error al instalar los paquetes",
+            pt: "Falha na instalação dos pacotes",
+            it: "Installazione dei pacchetti non riuscita"
         },
         "install_python_packages" => {
             en: "Install Python packages",
@@ -605,55 +1192,1614 @@ lazy_static! {
             ko: "Python 패키지 설치",
             fr: "Installer des paquets Python",
             de: "Python-Pakete installieren",
-            ru: "Установить пакеты Python"
+            ru: "Установить пакеты Python",
+            es: "Instalar paquetes de Python",
+            pt: "Instalar pacotes Python",
+            it: "Installa pacchetti Python"
+        },
+        
+        // 翻译文件
+        "translations_load_failed" => {
+            en: "Warning: failed to load translation file {}: {}",
+            zh: "警告: 无法加载翻译文件 {}: {}",
+            ja: "警告: 翻訳ファイル{}を読み込めません: {}",
+            ko: "경고: 번역 파일 {}을(를) 불러올 수 없습니다: {}",
+            fr: "Avertissement : impossible de charger le fichier de traduction {} : {}",
+            de: "Warnung: Übersetzungsdatei {} konnte nicht geladen werden: {}",
+            ru: "Предупреждение: не удалось загрузить файл перевода {}: {}",
+            es: "Advertencia: no se pudo cargar el archivo de traducción {}: {}",
+            pt: "Aviso: não foi possível carregar o arquivo de tradução {}: {}",
+            it: "Avviso: impossibile caricare il file di traduzione {}: {}"
+        },
+        
+        // 内置uv
+        "uv_command_retrying" => {
+            en: "The uv command failed, possibly due to a temporary network problem. Retrying...",
+            zh: "UV命令执行失败，可能是临时的网络问题，正在重试...",
+            ja: "uvコマンドが失敗しました。一時的なネットワークの問題の可能性があります。再試行しています...",
+            ko: "uv 명령이 실패했습니다. 일시적인 네트워크 문제일 수 있습니다. 다시 시도하는 중...",
+            fr: "La commande uv a échoué, peut-être à cause d'un problème réseau temporaire. Nouvelle tentative...",
+            de: "Der uv-Befehl ist fehlgeschlagen, möglicherweise wegen eines vorübergehenden Netzwerkproblems. Neuer Versuch...",
+            ru: "Команда uv завершилась с ошибкой, возможно из-за временной проблемы с сетью. Повторная попытка...",
+            es: "El comando uv falló, posiblemente por un problema temporal de red. Reintentando...",
+            pt: "O comando uv falhou, possivelmente por um problema temporário de rede. Tentando novamente...",
+            it: "Il comando uv non è riuscito, forse per un problema di rete temporaneo. Nuovo tentativo..."
+        },
+        "using_system_uv" => {
+            en: "Using system uv: {}",
+            zh: "使用系统安装的UV: {}",
+            ja: "システムにインストールされたuvを使用します: {}",
+            ko: "시스템에 설치된 uv 사용: {}",
+            fr: "Utilisation de uv installé sur le système : {}",
+            de: "Verwende systemweit installiertes uv: {}",
+            ru: "Используется системный uv: {}",
+            es: "Usando uv instalado en el sistema: {}",
+            pt: "Usando o uv instalado no sistema: {}",
+            it: "Uso di uv installato nel sistema: {}"
+        },
+        "using_embedded_uv" => {
+            en: "Using bundled uv: {}",
+            zh: "使用内置的UV: {}",
+            ja: "内蔵のuvを使用します: {}",
+            ko: "내장된 uv 사용: {}",
+            fr: "Utilisation de uv intégré : {}",
+            de: "Verwende mitgeliefertes uv: {}",
+            ru: "Используется встроенный uv: {}",
+            es: "Usando uv integrado: {}",
+            pt: "Usando o uv embutido: {}",
+            it: "Uso di uv incluso: {}"
+        },
+        "system_uv_not_found" => {
+            en: "uv was not found on the system, using the bundled version...",
+            zh: "系统中未找到UV，使用内置版本...",
+            ja: "システムにuvが見つからないため、内蔵のバージョンを使用します...",
+            ko: "시스템에서 uv를 찾을 수 없어 내장 버전을 사용합니다...",
+            fr: "uv est introuvable sur le système, utilisation de la version intégrée...",
+            de: "uv wurde auf dem System nicht gefunden, die mitgelieferte Version wird verwendet...",
+            ru: "uv не найден в системе, используется встроенная версия...",
+            es: "No se encontró uv en el sistema, usando la versión integrada...",
+            pt: "uv não foi encontrado no sistema, usando a versão embutida...",
+            it: "uv non trovato nel sistema, uso della versione inclusa..."
+        },
+        "uv_setup_failed" => {
+            en: "Warning: failed to set up the bundled uv: {}",
+            zh: "警告: 无法设置内置的uv工具: {}",
+            ja: "警告: 内蔵uvツールを設定できません: {}",
+            ko: "경고: 내장 uv 도구를 설정할 수 없습니다: {}",
+            fr: "Avertissement : impossible de configurer l'outil uv intégré : {}",
+            de: "Warnung: Das integrierte uv-Tool konnte nicht eingerichtet werden: {}",
+            ru: "Предупреждение: не удалось настроить встроенный uv: {}",
+            es: "Advertencia: no se pudo configurar el uv integrado: {}",
+            pt: "Aviso: não foi possível configurar o uv embutido: {}",
+            it: "Avviso: impossibile configurare uv integrato: {}"
+        },
+        "uv_first_run" => {
+            en: "First run, setting up the bundled uv...",
+            zh: "首次运行，正在设置内置uv工具...",
+            ja: "初回実行です。内蔵uvツールを設定しています...",
+            ko: "첫 실행입니다. 내장 uv 도구를 설정하는 중...",
+            fr: "Première exécution, configuration de l'outil uv intégré...",
+            de: "Erster Start, integriertes uv-Tool wird eingerichtet...",
+            ru: "Первый запуск, настройка встроенного uv...",
+            es: "Primera ejecución, configurando el uv integrado...",
+            pt: "Primeira execução, configurando o uv embutido...",
+            it: "Prima esecuzione, configurazione di uv integrato..."
+        },
+        "uv_binary_missing" => {
+            en: "No bundled uv found for this platform: {}",
+            zh: "找不到适用于当前平台的uv工具: {}",
+            ja: "このプラットフォーム用のuvツールが見つかりません: {}",
+            ko: "현재 플랫폼용 uv 도구를 찾을 수 없습니다: {}",
+            fr: "Aucun outil uv intégré pour cette plateforme : {}",
+            de: "Kein integriertes uv-Tool für diese Plattform gefunden: {}",
+            ru: "Не найден встроенный uv для этой платформы: {}",
+            es: "No se encontró un uv integrado para esta plataforma: {}",
+            pt: "Nenhum uv embutido encontrado para esta plataforma: {}",
+            it: "Nessun uv integrato trovato per questa piattaforma: {}"
+        },
+        "uv_setup_done" => {
+            en: "Bundled uv is ready!",
+            zh: "内置uv工具已设置完成！",
+            ja: "内蔵uvツールの設定が完了しました！",
+            ko: "내장 uv 도구 설정이 완료되었습니다!",
+            fr: "L'outil uv intégré est prêt !",
+            de: "Integriertes uv-Tool ist bereit!",
+            ru: "Встроенный uv готов!",
+            es: "¡El uv integrado está listo!",
+            pt: "O uv embutido está pronto!",
+            it: "uv integrato è pronto!"
+        },
+        
+        // 本地开发
+        "using_test_suite_files" => {
+            en: "Using example files from the test suite...",
+            zh: "使用测试套件中的示例文件...",
+            ja: "テストスイートのサンプルファイルを使用します...",
+            ko: "테스트 스위트의 예제 파일을 사용합니다...",
+            fr: "Utilisation des fichiers d'exemple de la suite de tests...",
+            de: "Beispieldateien aus der Testsuite werden verwendet...",
+            ru: "Используются примеры файлов из набора тестов...",
+            es: "Usando los archivos de ejemplo del conjunto de pruebas...",
+            pt: "Usando os arquivos de exemplo do conjunto de testes...",
+            it: "Uso dei file di esempio della suite di test..."
+        },
+        "test_suite_empty" => {
+            en: "No Python files found in the test suite either!",
+            zh: "测试套件中也未找到Python文件！",
+            ja: "テストスイートにもPythonファイルが見つかりません！",
+            ko: "테스트 스위트에서도 Python 파일을 찾을 수 없습니다!",
+            fr: "Aucun fichier Python trouvé non plus dans la suite de tests !",
+            de: "Auch in der Testsuite wurden keine Python-Dateien gefunden!",
+            ru: "В наборе тестов файлы Python тоже не найдены!",
+            es: "¡Tampoco se encontraron archivos Python en el conjunto de pruebas!",
+            pt: "Também não foram encontrados arquivos Python no conjunto de testes!",
+            it: "Nessun file Python trovato nemmeno nella suite di test!"
+        },
+        "create_python_files_hint" => {
+            en: "Please create some Python files first, or run the test suite with 'pywand test'.",
+            zh: "请先创建一些Python文件，或使用'pywand test'命令运行测试套件。",
+            ja: "先にPythonファイルを作成するか、'pywand test'でテストスイートを実行してください。",
+            ko: "먼저 Python 파일을 만들거나 'pywand test' 명령으로 테스트 스위트를 실행하세요.",
+            fr: "Créez d'abord des fichiers Python, ou lancez la suite de tests avec 'pywand test'.",
+            de: "Erstellen Sie zuerst Python-Dateien oder führen Sie die Testsuite mit 'pywand test' aus.",
+            ru: "Сначала создайте файлы Python или запустите набор тестов командой 'pywand test'.",
+            es: "Cree primero algunos archivos Python o ejecute el conjunto de pruebas con 'pywand test'.",
+            pt: "Crie alguns arquivos Python primeiro ou execute o conjunto de testes com 'pywand test'.",
+            it: "Crea prima alcuni file Python oppure esegui la suite di test con 'pywand test'."
+        },
+        "enter_python_dir" => {
+            en: "Enter the directory containing Python files",
+            zh: "请输入Python文件所在的目录路径",
+            ja: "Pythonファイルがあるディレクトリのパスを入力してください",
+            ko: "Python 파일이 있는 디렉토리 경로를 입력하세요",
+            fr: "Saisissez le répertoire contenant les fichiers Python",
+            de: "Verzeichnis mit den Python-Dateien eingeben",
+            ru: "Введите путь к каталогу с файлами Python",
+            es: "Introduzca el directorio que contiene los archivos Python",
+            pt: "Informe o diretório que contém os arquivos Python",
+            it: "Inserisci la directory che contiene i file Python"
+        },
+        "dir_no_python_files" => {
+            en: "No Python files found in the specified directory!",
+            zh: "指定目录中未找到Python文件！",
+            ja: "指定したディレクトリにPythonファイルが見つかりません！",
+            ko: "지정한 디렉토리에서 Python 파일을 찾을 수 없습니다!",
+            fr: "Aucun fichier Python trouvé dans le répertoire indiqué !",
+            de: "Im angegebenen Verzeichnis wurden keine Python-Dateien gefunden!",
+            ru: "В указанном каталоге файлы Python не найдены!",
+            es: "¡No se encontraron archivos Python en el directorio indicado!",
+            pt: "Nenhum arquivo Python encontrado no diretório informado!",
+            it: "Nessun file Python trovato nella directory indicata!"
+        },
+        "operation_cancelled" => {
+            en: "Operation cancelled.",
+            zh: "操作已取消。",
+            ja: "操作がキャンセルされました。",
+            ko: "작업이 취소되었습니다.",
+            fr: "Opération annulée.",
+            de: "Vorgang abgebrochen.",
+            ru: "Операция отменена.",
+            es: "Operación cancelada.",
+            pt: "Operação cancelada.",
+            it: "Operazione annullata."
+        },
+        
+        // 导出
+        "archive_path_prompt" => {
+            en: "Archive save path (directory or file name)",
+            zh: "归档保存路径（目录或文件名）",
+            ja: "アーカイブの保存先（ディレクトリまたはファイル名）",
+            ko: "아카이브 저장 경로(디렉토리 또는 파일 이름)",
+            fr: "Chemin de l'archive (répertoire ou nom de fichier)",
+            de: "Speicherpfad des Archivs (Verzeichnis oder Dateiname)",
+            ru: "Путь для сохранения архива (каталог или имя файла)",
+            es: "Ruta para guardar el archivo comprimido (directorio o nombre de archivo)",
+            pt: "Caminho para salvar o arquivo compactado (diretório ou nome do arquivo)",
+            it: "Percorso di salvataggio dell'archivio (directory o nome file)"
+        },
+        
+        // 扫描和依赖分析
+        "oversized_file_skipped" => {
+            en: "Warning: {0} is larger than {1} MB and was skipped (use --max-file-size to raise the limit, or --oversized-head to analyze only its beginning)",
+            zh: "警告: {0} 超过{1}MB，已跳过（使用--max-file-size提高上限，或使用--oversized-head只分析文件开头）",
+            ja: "警告: {0} は{1}MBを超えているためスキップしました（--max-file-sizeで上限を変更するか、--oversized-headで先頭のみを解析できます）",
+            ko: "경고: {0} 이(가) {1}MB를 초과하여 건너뛰었습니다(--max-file-size로 상한을 높이거나 --oversized-head로 파일 앞부분만 분석할 수 있습니다)",
+            fr: "Avertissement : {0} dépasse {1} Mo et a été ignoré (utilisez --max-file-size pour augmenter la limite, ou --oversized-head pour n'analyser que le début)",
+            de: "Warnung: {0} ist größer als {1} MB und wurde übersprungen (mit --max-file-size die Grenze erhöhen oder mit --oversized-head nur den Anfang analysieren)",
+            ru: "Предупреждение: {0} больше {1} МБ и был пропущен (увеличьте предел через --max-file-size или анализируйте только начало файла через --oversized-head)",
+            es: "Advertencia: {0} supera {1} MB y se omitió (use --max-file-size para aumentar el límite o --oversized-head para analizar solo el inicio)",
+            pt: "Aviso: {0} é maior que {1} MB e foi ignorado (use --max-file-size para aumentar o limite ou --oversized-head para analisar apenas o início)",
+            it: "Avviso: {0} supera {1} MB ed è stato saltato (usa --max-file-size per aumentare il limite o --oversized-head per analizzarne solo l'inizio)"
+        },
+        "oversized_file_head" => {
+            en: "Warning: {0} is larger than {1} MB, only its first {2} KB were analyzed",
+            zh: "警告: {0} 超过{1}MB，只分析了文件开头的{2}KB",
+            ja: "警告: {0} は{1}MBを超えているため、先頭の{2}KBのみを解析しました",
+            ko: "경고: {0} 이(가) {1}MB를 초과하여 앞부분 {2}KB만 분석했습니다",
+            fr: "Avertissement : {0} dépasse {1} Mo, seuls les {2} premiers Ko ont été analysés",
+            de: "Warnung: {0} ist größer als {1} MB, nur die ersten {2} KB wurden analysiert",
+            ru: "Предупреждение: {0} больше {1} МБ, проанализированы только первые {2} КБ",
+            es: "Advertencia: {0} supera {1} MB, solo se analizaron los primeros {2} KB",
+            pt: "Aviso: {0} é maior que {1} MB, apenas os primeiros {2} KB foram analisados",
+            it: "Avviso: {0} supera {1} MB, sono stati analizzati solo i primi {2} KB"
+        },
+        "python_path_version_unknown" => {
+            en: "Warning: could not determine the version of {}; standard library modules are detected for the latest Python",
+            zh: "警告: 无法获取 {} 的版本，按最新的Python版本判断标准库模块",
+            ja: "警告: {} のバージョンを取得できませんでした。標準ライブラリは最新のPythonを基準に判定します",
+            ko: "경고: {} 의 버전을 확인할 수 없어 최신 Python 기준으로 표준 라이브러리를 판별합니다",
+            fr: "Avertissement : impossible de déterminer la version de {} ; la bibliothèque standard est déterminée pour la dernière version de Python",
+            de: "Warnung: Die Version von {} konnte nicht ermittelt werden; Standardbibliotheksmodule werden für die neueste Python-Version erkannt",
+            ru: "Предупреждение: не удалось определить версию {}; модули стандартной библиотеки определяются для последней версии Python",
+            es: "Advertencia: no se pudo determinar la versión de {}; los módulos de la biblioteca estándar se detectan para la última versión de Python",
+            pt: "Aviso: não foi possível determinar a versão de {}; os módulos da biblioteca padrão são detectados para a versão mais recente do Python",
+            it: "Avviso: impossibile determinare la versione di {}; i moduli della libreria standard vengono rilevati per l'ultima versione di Python"
+        },
+        "stdlib_module_removed" => {
+            en: "Warning: the standard library module {0} was removed in Python {1}; use {2} instead",
+            zh: "警告: 标准库模块 {0} 已在Python {1}中移除，可以改用 {2}",
+            ja: "警告: 標準ライブラリモジュール {0} はPython {1}で削除されました。代わりに {2} を使用してください",
+            ko: "경고: 표준 라이브러리 모듈 {0} 은(는) Python {1}에서 제거되었습니다. 대신 {2} 을(를) 사용하세요",
+            fr: "Avertissement : le module de la bibliothèque standard {0} a été supprimé dans Python {1} ; utilisez {2} à la place",
+            de: "Warnung: Das Standardbibliotheksmodul {0} wurde in Python {1} entfernt; verwenden Sie stattdessen {2}",
+            ru: "Предупреждение: модуль стандартной библиотеки {0} удалён в Python {1}; используйте {2}",
+            es: "Advertencia: el módulo de la biblioteca estándar {0} se eliminó en Python {1}; use {2} en su lugar",
+            pt: "Aviso: o módulo da biblioteca padrão {0} foi removido no Python {1}; use {2} no lugar",
+            it: "Avviso: il modulo della libreria standard {0} è stato rimosso in Python {1}; usa {2} al suo posto"
+        },
+        "stdlib_module_deprecated" => {
+            en: "Warning: the standard library module {0} is deprecated since Python {1} and will be removed in {2}; consider {3}",
+            zh: "警告: 标准库模块 {0} 自Python {1}起已弃用，将在{2}中移除，建议改用 {3}",
+            ja: "警告: 標準ライブラリモジュール {0} はPython {1}から非推奨で、{2}で削除されます。{3} の使用を検討してください",
+            ko: "경고: 표준 라이브러리 모듈 {0} 은(는) Python {1}부터 더 이상 사용되지 않으며 {2}에서 제거됩니다. {3} 사용을 고려하세요",
+            fr: "Avertissement : le module de la bibliothèque standard {0} est obsolète depuis Python {1} et sera supprimé dans {2} ; envisagez {3}",
+            de: "Warnung: Das Standardbibliotheksmodul {0} ist seit Python {1} veraltet und wird in {2} entfernt; erwägen Sie {3}",
+            ru: "Предупреждение: модуль стандартной библиотеки {0} устарел начиная с Python {1} и будет удалён в {2}; рассмотрите {3}",
+            es: "Advertencia: el módulo de la biblioteca estándar {0} está obsoleto desde Python {1} y se eliminará en {2}; considere {3}",
+            pt: "Aviso: o módulo da biblioteca padrão {0} está obsoleto desde o Python {1} e será removido no {2}; considere {3}",
+            it: "Avviso: il modulo della libreria standard {0} è deprecato da Python {1} e verrà rimosso in {2}; valuta {3}"
+        },
+        "depth_limit_reached" => {
+            en: "Warning: {} directories exceed the maximum scan depth {}; files in them were skipped (use --max-depth 0 to remove the limit)",
+            zh: "警告: {}个目录超过最大扫描深度{}，其中的文件已被跳过（使用--max-depth 0取消限制）",
+            ja: "警告: {}個のディレクトリが最大スキャン深度{}を超えたため、その中のファイルはスキップされました（--max-depth 0で制限を解除）",
+            ko: "경고: {}개 디렉토리가 최대 스캔 깊이 {}을(를) 초과하여 해당 파일을 건너뛰었습니다(--max-depth 0으로 제한 해제)",
+            fr: "Avertissement : {} répertoires dépassent la profondeur maximale {} ; leurs fichiers ont été ignorés (utilisez --max-depth 0 pour lever la limite)",
+            de: "Warnung: {} Verzeichnisse überschreiten die maximale Scantiefe {}; ihre Dateien wurden übersprungen (--max-depth 0 hebt das Limit auf)",
+            ru: "Предупреждение: {} каталогов превышают максимальную глубину {}; файлы в них пропущены (используйте --max-depth 0, чтобы снять ограничение)",
+            es: "Advertencia: {} directorios superan la profundidad máxima de escaneo {}; se omitieron sus archivos (use --max-depth 0 para quitar el límite)",
+            pt: "Aviso: {} diretórios excedem a profundidade máxima de verificação {}; seus arquivos foram ignorados (use --max-depth 0 para remover o limite)",
+            it: "Avviso: {} directory superano la profondità massima di scansione {}; i loro file sono stati ignorati (usa --max-depth 0 per rimuovere il limite)"
+        },
+        "python_file_count" => {
+            en: "Python files found: {}",
+            zh: "找到Python文件数量: {}",
+            ja: "見つかったPythonファイル数: {}",
+            ko: "찾은 Python 파일 수: {}",
+            fr: "Fichiers Python trouvés : {}",
+            de: "Gefundene Python-Dateien: {}",
+            ru: "Найдено файлов Python: {}",
+            es: "Archivos Python encontrados: {}",
+            pt: "Arquivos Python encontrados: {}",
+            it: "File Python trovati: {}"
+        },
+        "no_files_to_extract" => {
+            en: "No Python files found, cannot extract dependencies.",
+            zh: "没有找到Python文件，无法提取依赖。",
+            ja: "Pythonファイルが見つからないため、依存関係を抽出できません。",
+            ko: "Python 파일을 찾을 수 없어 종속성을 추출할 수 없습니다.",
+            fr: "Aucun fichier Python trouvé, impossible d'extraire les dépendances.",
+            de: "Keine Python-Dateien gefunden, Abhängigkeiten können nicht ermittelt werden.",
+            ru: "Файлы Python не найдены, невозможно извлечь зависимости.",
+            es: "No se encontraron archivos Python, no se pueden extraer las dependencias.",
+            pt: "Nenhum arquivo Python encontrado, não é possível extrair as dependências.",
+            it: "Nessun file Python trovato, impossibile estrarre le dipendenze."
+        },
+        "declared_deps_failed" => {
+            en: "Warning: failed to read declared dependencies: {}",
+            zh: "警告: 无法读取已声明的依赖: {}",
+            ja: "警告: 宣言済みの依存関係を読み込めません: {}",
+            ko: "경고: 선언된 종속성을 읽을 수 없습니다: {}",
+            fr: "Avertissement : impossible de lire les dépendances déclarées : {}",
+            de: "Warnung: Deklarierte Abhängigkeiten konnten nicht gelesen werden: {}",
+            ru: "Предупреждение: не удалось прочитать объявленные зависимости: {}",
+            es: "Advertencia: no se pudieron leer las dependencias declaradas: {}",
+            pt: "Aviso: não foi possível ler as dependências declaradas: {}",
+            it: "Avviso: impossibile leggere le dipendenze dichiarate: {}"
+        },
+        "declared_deps_count" => {
+            en: "Read {} dependency from dependency files|Read {} dependencies from dependency files",
+            zh: "从依赖声明文件中读取到{}个依赖",
+            ja: "依存関係ファイルから{}個の依存関係を読み込みました",
+            ko: "종속성 파일에서 {}개의 종속성을 읽었습니다",
+            fr: "{} dépendance lue depuis les fichiers de dépendances|{} dépendances lues depuis les fichiers de dépendances",
+            de: "{} Abhängigkeit aus Abhängigkeitsdateien gelesen|{} Abhängigkeiten aus Abhängigkeitsdateien gelesen",
+            ru: "Из файлов зависимостей прочитана {} зависимость|Из файлов зависимостей прочитано {} зависимости|Из файлов зависимостей прочитано {} зависимостей",
+            es: "Se leyó {} dependencia de los archivos de dependencias|Se leyeron {} dependencias de los archivos de dependencias",
+            pt: "{} dependência lida dos arquivos de dependências|{} dependências lidas dos arquivos de dependências",
+            it: "Letta {} dipendenza dai file delle dipendenze|Lette {} dipendenze dai file delle dipendenze"
+        },
+        "unresolved_imports" => {
+            en: "Imports not mapped to any package:",
+            zh: "未能对应到包名的导入：",
+            ja: "パッケージ名に対応付けられなかったインポート:",
+            ko: "패키지 이름에 매핑되지 않은 import:",
+            fr: "Imports sans paquet correspondant :",
+            de: "Importe ohne zugeordnetes Paket:",
+            ru: "Импорты без соответствующего пакета:",
+            es: "Importaciones sin paquete correspondiente:",
+            pt: "Importações sem pacote correspondente:",
+            it: "Import senza pacchetto corrispondente:"
+        },
+        "ambiguous_import" => {
+            en: "{} (provided by several packages: {})",
+            zh: "{}（多个包提供该模块：{}）",
+            ja: "{}（複数のパッケージが提供しています: {}）",
+            ko: "{} (여러 패키지가 제공함: {})",
+            fr: "{} (fourni par plusieurs paquets : {})",
+            de: "{} (von mehreren Paketen bereitgestellt: {})",
+            ru: "{} (предоставляется несколькими пакетами: {})",
+            es: "{} (proporcionado por varios paquetes: {})",
+            pt: "{} (fornecido por vários pacotes: {})",
+            it: "{} (fornito da più pacchetti: {})"
+        },
+        "unresolved_mapping_hint" => {
+            en: "These imports are not in the requirements. Add an entry to {} to map an import to its PyPI package",
+            zh: "这些导入不会写入依赖，可以在{}中添加导入名到PyPI包名的映射",
+            ja: "これらのインポートは依存関係に含まれません。{}にインポート名からPyPIパッケージ名への対応を追加できます",
+            ko: "이 import는 종속성에 포함되지 않습니다. {}에 import 이름과 PyPI 패키지 이름의 매핑을 추가할 수 있습니다",
+            fr: "Ces imports ne figurent pas dans les dépendances. Ajoutez une entrée dans {} pour associer un import à son paquet PyPI",
+            de: "Diese Importe werden nicht in die Abhängigkeiten übernommen. Fügen Sie in {} eine Zuordnung vom Importnamen zum PyPI-Paket hinzu",
+            ru: "Эти импорты не попадут в зависимости. Добавьте в {} сопоставление имени импорта с пакетом PyPI",
+            es: "Estas importaciones no se incluyen en las dependencias. Añada una entrada en {} para asociar una importación con su paquete de PyPI",
+            pt: "Essas importações não entram nas dependências. Adicione uma entrada em {} para associar uma importação ao seu pacote do PyPI",
+            it: "Questi import non vengono inclusi nelle dipendenze. Aggiungi una voce in {} per associare un import al suo pacchetto PyPI"
+        },
+        "strict_unresolved_imports" => {
+            en: "{0} import(s) could not be mapped to a PyPI package: {1}\nAdd mappings in {2}",
+            zh: "有{0}个导入无法对应到PyPI包名: {1}\n请在{2}中添加映射",
+            ja: "{0}個のインポートをPyPIパッケージ名に対応付けられません: {1}\n{2}に対応を追加してください",
+            ko: "{0}개의 import를 PyPI 패키지 이름에 매핑할 수 없습니다: {1}\n{2}에 매핑을 추가하세요",
+            fr: "{0} import(s) sans paquet PyPI correspondant : {1}\nAjoutez des correspondances dans {2}",
+            de: "{0} Import(e) konnten keinem PyPI-Paket zugeordnet werden: {1}\nFügen Sie Zuordnungen in {2} hinzu",
+            ru: "Импорты без соответствующего пакета PyPI ({0}): {1}\nДобавьте сопоставления в {2}",
+            es: "{0} importación(es) sin paquete de PyPI correspondiente: {1}\nAñada asociaciones en {2}",
+            pt: "{0} importação(ões) sem pacote do PyPI correspondente: {1}\nAdicione associações em {2}",
+            it: "{0} import senza pacchetto PyPI corrispondente: {1}\nAggiungi le associazioni in {2}"
+        },
+        "verifying_pypi" => {
+            en: "Checking packages on PyPI...",
+            zh: "正在通过PyPI校验包名...",
+            ja: "PyPIでパッケージを確認しています...",
+            ko: "PyPI에서 패키지를 확인하는 중...",
+            fr: "Vérification des paquets sur PyPI...",
+            de: "Pakete werden auf PyPI geprüft...",
+            ru: "Проверка пакетов на PyPI...",
+            es: "Comprobando paquetes en PyPI...",
+            pt: "Verificando pacotes no PyPI...",
+            it: "Verifica dei pacchetti su PyPI..."
+        },
+        "pypi_packages_missing" => {
+            en: "Not found on PyPI, removed from the requirements (probably local modules):",
+            zh: "以下包在PyPI上不存在，已从依赖中移除（可能是本地模块）：",
+            ja: "PyPIに存在しないため依存関係から除外しました（ローカルモジュールの可能性があります）:",
+            ko: "PyPI에 없어 종속성에서 제거했습니다 (로컬 모듈일 수 있음):",
+            fr: "Introuvables sur PyPI, retirés des dépendances (probablement des modules locaux) :",
+            de: "Nicht auf PyPI gefunden, aus den Abhängigkeiten entfernt (vermutlich lokale Module):",
+            ru: "Не найдены на PyPI и удалены из зависимостей (вероятно, локальные модули):",
+            es: "No encontrados en PyPI, eliminados de las dependencias (probablemente módulos locales):",
+            pt: "Não encontrados no PyPI, removidos das dependências (provavelmente módulos locais):",
+            it: "Non trovati su PyPI, rimossi dalle dipendenze (probabilmente moduli locali):"
+        },
+        "pypi_unreachable" => {
+            en: "Warning: could not reach PyPI, unverified packages are kept: {}",
+            zh: "警告：无法访问PyPI，未校验的包将被保留：{}",
+            ja: "警告: PyPIにアクセスできません。未確認のパッケージはそのまま残します: {}",
+            ko: "경고: PyPI에 접근할 수 없어 확인되지 않은 패키지를 유지합니다: {}",
+            fr: "Avertissement : PyPI est inaccessible, les paquets non vérifiés sont conservés : {}",
+            de: "Warnung: PyPI ist nicht erreichbar, ungeprüfte Pakete werden beibehalten: {}",
+            ru: "Предупреждение: PyPI недоступен, непроверенные пакеты сохранены: {}",
+            es: "Advertencia: no se pudo acceder a PyPI, se conservan los paquetes no verificados: {}",
+            pt: "Aviso: não foi possível acessar o PyPI, os pacotes não verificados são mantidos: {}",
+            it: "Avviso: PyPI non raggiungibile, i pacchetti non verificati vengono mantenuti: {}"
+        },
+        "checking_requires_python" => {
+            en: "Checking the Python version requirements of the packages on PyPI...",
+            zh: "正在通过PyPI检查依赖的Python版本要求...",
+            ja: "PyPIでパッケージのPythonバージョン要件を確認しています...",
+            ko: "PyPI에서 패키지의 Python 버전 요구 사항을 확인하는 중...",
+            fr: "Vérification des versions de Python requises par les paquets sur PyPI...",
+            de: "Python-Versionsanforderungen der Pakete werden auf PyPI geprüft...",
+            ru: "Проверка требований пакетов к версии Python на PyPI...",
+            es: "Comprobando en PyPI las versiones de Python requeridas por los paquetes...",
+            pt: "Verificando no PyPI as versões do Python exigidas pelos pacotes...",
+            it: "Verifica su PyPI delle versioni di Python richieste dai pacchetti..."
+        },
+        "requires_python_incompatible" => {
+            en: "Warning: the following packages do not support Python {}:",
+            zh: "警告：以下包不支持Python {}：",
+            ja: "警告: 次のパッケージはPython {}をサポートしていません:",
+            ko: "경고: 다음 패키지는 Python {}을(를) 지원하지 않습니다:",
+            fr: "Avertissement : les paquets suivants ne prennent pas en charge Python {} :",
+            de: "Warnung: Die folgenden Pakete unterstützen Python {} nicht:",
+            ru: "Предупреждение: следующие пакеты не поддерживают Python {}:",
+            es: "Advertencia: los siguientes paquetes no son compatibles con Python {}:",
+            pt: "Aviso: os seguintes pacotes não suportam o Python {}:",
+            it: "Avviso: i seguenti pacchetti non supportano Python {}:"
+        },
+        "requires_python_unchecked" => {
+            en: "Warning: could not reach PyPI, skipping the Python version compatibility check: {}",
+            zh: "警告：无法访问PyPI，跳过Python版本兼容性检查：{}",
+            ja: "警告: PyPIにアクセスできないため、Pythonバージョンの互換性チェックをスキップします: {}",
+            ko: "경고: PyPI에 접근할 수 없어 Python 버전 호환성 검사를 건너뜁니다: {}",
+            fr: "Avertissement : PyPI est inaccessible, la vérification de compatibilité avec la version de Python est ignorée : {}",
+            de: "Warnung: PyPI ist nicht erreichbar, die Prüfung der Python-Versionskompatibilität wird übersprungen: {}",
+            ru: "Предупреждение: PyPI недоступен, проверка совместимости с версией Python пропущена: {}",
+            es: "Advertencia: no se pudo acceder a PyPI, se omite la comprobación de compatibilidad con la versión de Python: {}",
+            pt: "Aviso: não foi possível acessar o PyPI, a verificação de compatibilidade com a versão do Python foi ignorada: {}",
+            it: "Avviso: PyPI non raggiungibile, il controllo di compatibilità con la versione di Python viene saltato: {}"
+        },
+        "constraint_conflict_resolved" => {
+            en: "Warning: conflicting constraints for {0}: {1}; using {2}",
+            zh: "警告：{0}的版本约束冲突：{1}；使用{2}",
+            ja: "警告: {0}のバージョン制約が競合しています: {1}。{2}を使用します",
+            ko: "경고: {0}의 버전 제약이 충돌합니다: {1}. {2}을(를) 사용합니다",
+            fr: "Avertissement : contraintes incompatibles pour {0} : {1} ; utilisation de {2}",
+            de: "Warnung: widersprüchliche Versionsangaben für {0}: {1}; verwendet wird {2}",
+            ru: "Предупреждение: конфликтующие ограничения версий для {0}: {1}; используется {2}",
+            es: "Advertencia: restricciones en conflicto para {0}: {1}; se usa {2}",
+            pt: "Aviso: restrições conflitantes para {0}: {1}; usando {2}",
+            it: "Avviso: vincoli in conflitto per {0}: {1}; viene usato {2}"
+        },
+        "constraint_conflict_reported" => {
+            en: "Warning: conflicting constraints for {0}: {1}; gen-req, lock, local-dev and export need --prefer to resolve them",
+            zh: "警告：{0}的版本约束冲突：{1}；gen-req、lock、local-dev和export需要使用--prefer选择保留的约束",
+            ja: "警告: {0}のバージョン制約が競合しています: {1}。gen-req、lock、local-dev、exportでは--preferで解決方法を指定する必要があります",
+            ko: "경고: {0}의 버전 제약이 충돌합니다: {1}. gen-req, lock, local-dev, export에서는 --prefer로 해결 방법을 지정해야 합니다",
+            fr: "Avertissement : contraintes incompatibles pour {0} : {1} ; gen-req, lock, local-dev et export nécessitent --prefer pour les résoudre",
+            de: "Warnung: widersprüchliche Versionsangaben für {0}: {1}; gen-req, lock, local-dev und export benötigen --prefer, um sie aufzulösen",
+            ru: "Предупреждение: конфликтующие ограничения версий для {0}: {1}; для gen-req, lock, local-dev и export укажите --prefer",
+            es: "Advertencia: restricciones en conflicto para {0}: {1}; gen-req, lock, local-dev y export necesitan --prefer para resolverlas",
+            pt: "Aviso: restrições conflitantes para {0}: {1}; gen-req, lock, local-dev e export precisam de --prefer para resolvê-las",
+            it: "Avviso: vincoli in conflitto per {0}: {1}; gen-req, lock, local-dev ed export richiedono --prefer per risolverli"
+        },
+        "config_load_failed" => {
+            en: "Warning: failed to load config file {}, using default settings: {}",
+            zh: "警告: 无法加载配置文件 {}，使用默认设置: {}",
+            ja: "警告: 設定ファイル{}を読み込めません。既定の設定を使用します: {}",
+            ko: "경고: 설정 파일 {}을(를) 불러올 수 없어 기본 설정을 사용합니다: {}",
+            fr: "Avertissement : impossible de charger le fichier de configuration {}, paramètres par défaut utilisés : {}",
+            de: "Warnung: Konfigurationsdatei {} konnte nicht geladen werden, Standardeinstellungen werden verwendet: {}",
+            ru: "Предупреждение: не удалось загрузить файл конфигурации {}, используются настройки по умолчанию: {}",
+            es: "Advertencia: no se pudo cargar el archivo de configuración {}, se usa la configuración predeterminada: {}",
+            pt: "Aviso: não foi possível carregar o arquivo de configuração {}, usando as configurações padrão: {}",
+            it: "Avviso: impossibile caricare il file di configurazione {}, verranno usate le impostazioni predefinite: {}"
+        },
+        "config_unknown_key" => {
+            en: "Warning: unknown setting \"{}\" in {} was ignored",
+            zh: "警告: 已忽略{1}中无法识别的配置项\"{0}\"",
+            ja: "警告: {1}の不明な設定項目\"{0}\"を無視しました",
+            ko: "경고: {1}의 알 수 없는 설정 항목 \"{0}\"을(를) 무시했습니다",
+            fr: "Avertissement : paramètre inconnu \"{}\" ignoré dans {}",
+            de: "Warnung: Unbekannte Einstellung \"{}\" in {} wurde ignoriert",
+            ru: "Предупреждение: неизвестный параметр \"{}\" в {} проигнорирован",
+            es: "Advertencia: se ignoró la opción desconocida \"{}\" en {}",
+            pt: "Aviso: a opção desconhecida \"{}\" em {} foi ignorada",
+            it: "Avviso: l'opzione sconosciuta \"{}\" in {} è stata ignorata"
+        },
+        "mappings_load_failed" => {
+            en: "Warning: failed to load package mapping file {}: {}",
+            zh: "警告: 无法加载包名映射文件 {}: {}",
+            ja: "警告: パッケージ名マッピングファイル{}を読み込めません: {}",
+            ko: "경고: 패키지 이름 매핑 파일 {}을(를) 불러올 수 없습니다: {}",
+            fr: "Avertissement : impossible de charger le fichier de correspondances {} : {}",
+            de: "Warnung: Paketnamen-Zuordnungsdatei {} konnte nicht geladen werden: {}",
+            ru: "Предупреждение: не удалось загрузить файл сопоставлений {}: {}",
+            es: "Advertencia: no se pudo cargar el archivo de correspondencias {}: {}",
+            pt: "Aviso: não foi possível carregar o arquivo de mapeamento {}: {}",
+            it: "Avviso: impossibile caricare il file di mappatura {}: {}"
+        },
+        
+        // Python版本
+        "using_declared_python" => {
+            en: "Using the Python version declared by the project: {} ({})",
+            zh: "使用项目声明的Python版本: {} ({})",
+            ja: "プロジェクトで宣言されたPythonバージョンを使用します: {} ({})",
+            ko: "프로젝트에 선언된 Python 버전을 사용합니다: {} ({})",
+            fr: "Utilisation de la version de Python déclarée par le projet : {} ({})",
+            de: "Vom Projekt deklarierte Python-Version wird verwendet: {} ({})",
+            ru: "Используется версия Python, объявленная проектом: {} ({})",
+            es: "Usando la versión de Python declarada por el proyecto: {} ({})",
+            pt: "Usando a versão do Python declarada pelo projeto: {} ({})",
+            it: "Uso della versione di Python dichiarata dal progetto: {} ({})"
+        },
+        "using_saved_python" => {
+            en: "Using the Python version saved in {1}: {0}",
+            zh: "使用{1}中保存的Python版本: {0}",
+            ja: "{1}に保存されたPythonバージョンを使用します: {0}",
+            ko: "{1}에 저장된 Python 버전을 사용합니다: {0}",
+            fr: "Utilisation de la version de Python enregistrée dans {1} : {0}",
+            de: "In {1} gespeicherte Python-Version wird verwendet: {0}",
+            ru: "Используется версия Python, сохранённая в {1}: {0}",
+            es: "Usando la versión de Python guardada en {1}: {0}",
+            pt: "Usando a versão do Python salva em {1}: {0}",
+            it: "Uso della versione di Python salvata in {1}: {0}"
+        },
+        "saved_python_unsupported" => {
+            en: "Warning: the saved Python version {} in {} is not available on this platform",
+            zh: "警告: {1}中保存的Python版本{0}在当前平台不可用",
+            ja: "警告: {1}に保存されたPythonバージョン{0}はこのプラットフォームでは利用できません",
+            ko: "경고: {1}에 저장된 Python 버전 {0}은(는) 이 플랫폼에서 사용할 수 없습니다",
+            fr: "Avertissement : la version de Python {} enregistrée dans {} n'est pas disponible sur cette plateforme",
+            de: "Warnung: Die in {1} gespeicherte Python-Version {0} ist auf dieser Plattform nicht verfügbar",
+            ru: "Предупреждение: сохранённая в {1} версия Python {0} недоступна на этой платформе",
+            es: "Advertencia: la versión de Python {} guardada en {} no está disponible en esta plataforma",
+            pt: "Aviso: a versão do Python {} salva em {} não está disponível nesta plataforma",
+            it: "Avviso: la versione di Python {} salvata in {} non è disponibile su questa piattaforma"
+        },
+        "python_version_saved" => {
+            en: "Saved Python {} to {}; it will be used next time without asking",
+            zh: "已将Python {}保存到{}，下次不再询问",
+            ja: "Python {}を{}に保存しました。次回からは確認しません",
+            ko: "Python {}을(를) {}에 저장했습니다. 다음부터는 묻지 않습니다",
+            fr: "Python {} enregistré dans {} ; il sera utilisé la prochaine fois sans demander",
+            de: "Python {} wurde in {} gespeichert und wird beim nächsten Mal ohne Nachfrage verwendet",
+            ru: "Python {} сохранён в {} и в следующий раз будет использован без запроса",
+            es: "Python {} guardado en {}; se usará la próxima vez sin preguntar",
+            pt: "Python {} salvo em {}; será usado da próxima vez sem perguntar",
+            it: "Python {} salvato in {}; verrà usato la prossima volta senza chiedere"
+        },
+        "python_version_save_failed" => {
+            en: "Warning: failed to save the Python version to {}: {}",
+            zh: "警告: 无法将Python版本保存到{}: {}",
+            ja: "警告: Pythonバージョンを{}に保存できません: {}",
+            ko: "경고: Python 버전을 {}에 저장할 수 없습니다: {}",
+            fr: "Avertissement : impossible d'enregistrer la version de Python dans {} : {}",
+            de: "Warnung: Python-Version konnte nicht in {} gespeichert werden: {}",
+            ru: "Предупреждение: не удалось сохранить версию Python в {}: {}",
+            es: "Advertencia: no se pudo guardar la versión de Python en {}: {}",
+            pt: "Aviso: não foi possível salvar a versão do Python em {}: {}",
+            it: "Avviso: impossibile salvare la versione di Python in {}: {}"
+        },
+        "declared_python_unsupported" => {
+            en: "Warning: the Python version {} declared by the project is not supported",
+            zh: "警告: 项目声明的Python版本{}不在支持的版本中",
+            ja: "警告: プロジェクトで宣言されたPythonバージョン{}はサポートされていません",
+            ko: "경고: 프로젝트에 선언된 Python 버전 {}은(는) 지원되지 않습니다",
+            fr: "Avertissement : la version de Python {} déclarée par le projet n'est pas prise en charge",
+            de: "Warnung: Die vom Projekt deklarierte Python-Version {} wird nicht unterstützt",
+            ru: "Предупреждение: объявленная проектом версия Python {} не поддерживается",
+            es: "Advertencia: la versión de Python {} declarada por el proyecto no es compatible",
+            pt: "Aviso: a versão do Python {} declarada pelo projeto não é suportada",
+            it: "Avviso: la versione di Python {} dichiarata dal progetto non è supportata"
+        },
+        "declared_python_failed" => {
+            en: "Warning: failed to read the declared Python version: {}",
+            zh: "警告: 无法读取项目声明的Python版本: {}",
+            ja: "警告: 宣言されたPythonバージョンを読み込めません: {}",
+            ko: "경고: 선언된 Python 버전을 읽을 수 없습니다: {}",
+            fr: "Avertissement : impossible de lire la version de Python déclarée : {}",
+            de: "Warnung: Deklarierte Python-Version konnte nicht gelesen werden: {}",
+            ru: "Предупреждение: не удалось прочитать объявленную версию Python: {}",
+            es: "Advertencia: no se pudo leer la versión de Python declarada: {}",
+            pt: "Aviso: não foi possível ler a versão do Python declarada: {}",
+            it: "Avviso: impossibile leggere la versione di Python dichiarata: {}"
+        },
+        
+        // 依赖文件
+        "locking_dependencies" => {
+            en: "Locking dependencies",
+            zh: "锁定依赖",
+            ja: "依存関係のロック",
+            ko: "종속성 잠금",
+            fr: "Verrouillage des dépendances",
+            de: "Abhängigkeiten sperren",
+            ru: "Фиксация зависимостей",
+            es: "Bloqueo de dependencias",
+            pt: "Travamento de dependências",
+            it: "Blocco delle dipendenze"
+        },
+        "compiling_lock" => {
+            en: "Resolving dependencies and generating the lock file...",
+            zh: "解析依赖并生成锁定文件...",
+            ja: "依存関係を解決してロックファイルを生成しています...",
+            ko: "종속성을 해석하고 잠금 파일을 생성하는 중...",
+            fr: "Résolution des dépendances et génération du fichier de verrouillage...",
+            de: "Abhängigkeiten werden aufgelöst und die Lock-Datei wird erstellt...",
+            ru: "Разрешение зависимостей и создание lock-файла...",
+            es: "Resolviendo dependencias y generando el archivo de bloqueo...",
+            pt: "Resolvendo dependências e gerando o arquivo de bloqueio...",
+            it: "Risoluzione delle dipendenze e generazione del file di lock..."
+        },
+        "lock_written" => {
+            en: "Lockfile written: {}",
+            zh: "已生成锁定文件: {}",
+            ja: "ロックファイルを作成しました: {}",
+            ko: "잠금 파일을 생성했습니다: {}",
+            fr: "Fichier de verrouillage écrit : {}",
+            de: "Lockdatei geschrieben: {}",
+            ru: "Lock-файл записан: {}",
+            es: "Archivo de bloqueo generado: {}",
+            pt: "Arquivo de lock gerado: {}",
+            it: "File di lock generato: {}"
+        },
+        "lock_unsupported" => {
+            en: "The available uv does not support `uv pip compile`; no lockfile was generated. Please upgrade uv.",
+            zh: "当前的uv不支持`uv pip compile`，未生成锁定文件，请升级uv。",
+            ja: "使用中のuvは`uv pip compile`に対応していないため、ロックファイルを作成しませんでした。uvを更新してください。",
+            ko: "현재 uv가 `uv pip compile`을 지원하지 않아 잠금 파일을 생성하지 않았습니다. uv를 업그레이드하세요.",
+            fr: "Le uv disponible ne prend pas en charge `uv pip compile` ; aucun fichier de verrouillage n'a été généré. Veuillez mettre à jour uv.",
+            de: "Das verfügbare uv unterstützt `uv pip compile` nicht; es wurde keine Lockdatei erzeugt. Bitte aktualisieren Sie uv.",
+            ru: "Доступная версия uv не поддерживает `uv pip compile`; lock-файл не создан. Обновите uv.",
+            es: "El uv disponible no admite `uv pip compile`; no se generó el archivo de bloqueo. Actualice uv.",
+            pt: "O uv disponível não suporta `uv pip compile`; nenhum arquivo de lock foi gerado. Atualize o uv.",
+            it: "L'uv disponibile non supporta `uv pip compile`; nessun file di lock è stato generato. Aggiorna uv."
+        },
+        "lock_included" => {
+            en: "Included requirements.lock in the export package",
+            zh: "已将requirements.lock加入导出包",
+            ja: "requirements.lockをエクスポートパッケージに含めました",
+            ko: "requirements.lock을 내보내기 패키지에 포함했습니다",
+            fr: "requirements.lock inclus dans le paquet d'exportation",
+            de: "requirements.lock wurde in das Exportpaket aufgenommen",
+            ru: "requirements.lock добавлен в пакет экспорта",
+            es: "requirements.lock incluido en el paquete de exportación",
+            pt: "requirements.lock incluído no pacote de exportação",
+            it: "requirements.lock incluso nel pacchetto di esportazione"
+        },
+        "conda_environment_created" => {
+            en: "Included environment.yml for conda in the export package",
+            zh: "已将conda使用的environment.yml加入导出包",
+            ja: "conda用のenvironment.ymlをエクスポートパッケージに含めました",
+            ko: "conda용 environment.yml을 내보내기 패키지에 포함했습니다",
+            fr: "environment.yml pour conda inclus dans le paquet d'exportation",
+            de: "environment.yml für conda wurde in das Exportpaket aufgenommen",
+            ru: "environment.yml для conda добавлен в пакет экспорта",
+            es: "environment.yml para conda incluido en el paquete de exportación",
+            pt: "environment.yml para conda incluído no pacote de exportação",
+            it: "environment.yml per conda incluso nel pacchetto di esportazione"
+        },
+        "pyproject_updated" => {
+            en: "Updated dependencies in {}",
+            zh: "更新了{}中的依赖",
+            ja: "{}の依存関係を更新しました",
+            ko: "{}의 종속성을 업데이트했습니다",
+            fr: "Dépendances mises à jour dans {}",
+            de: "Abhängigkeiten in {} aktualisiert",
+            ru: "Зависимости в {} обновлены",
+            es: "Dependencias actualizadas en {}",
+            pt: "Dependências atualizadas em {}",
+            it: "Dipendenze aggiornate in {}"
+        },
+        
+        // 运行脚本和命令
+        "using_package_index" => {
+            en: "Using package index: {}",
+            zh: "使用包索引: {}",
+            ja: "パッケージインデックスを使用: {}",
+            ko: "패키지 인덱스 사용: {}",
+            fr: "Index de paquets utilisé : {}",
+            de: "Verwendeter Paketindex: {}",
+            ru: "Используется индекс пакетов: {}",
+            es: "Usando el índice de paquetes: {}",
+            pt: "Usando o índice de pacotes: {}",
+            it: "Indice dei pacchetti in uso: {}"
+        },
+        "venv_missing_creating" => {
+            en: "No virtual environment found, creating one...",
+            zh: "未检测到虚拟环境，正在创建...",
+            ja: "仮想環境が見つかりません。作成しています...",
+            ko: "가상 환경을 찾을 수 없어 생성하는 중...",
+            fr: "Aucun environnement virtuel trouvé, création en cours...",
+            de: "Keine virtuelle Umgebung gefunden, sie wird erstellt...",
+            ru: "Виртуальная среда не найдена, создаётся...",
+            es: "No se encontró un entorno virtual, creando uno...",
+            pt: "Nenhum ambiente virtual encontrado, criando um...",
+            it: "Nessun ambiente virtuale trovato, creazione in corso..."
+        },
+        "requirements_found_installing" => {
+            en: "Found requirements.txt, installing dependencies...",
+            zh: "检测到requirements.txt，正在安装依赖...",
+            ja: "requirements.txtが見つかりました。依存関係をインストールしています...",
+            ko: "requirements.txt를 찾았습니다. 종속성을 설치하는 중...",
+            fr: "requirements.txt trouvé, installation des dépendances...",
+            de: "requirements.txt gefunden, Abhängigkeiten werden installiert...",
+            ru: "Найден requirements.txt, установка зависимостей...",
+            es: "Se encontró requirements.txt, instalando dependencias...",
+            pt: "requirements.txt encontrado, instalando dependências...",
+            it: "Trovato requirements.txt, installazione delle dipendenze..."
+        },
+        "requirement_already_present" => {
+            en: "{} is already in {}, left unchanged",
+            zh: "{}已在{}中，保持不变",
+            ja: "{}は既に{}に含まれているため、変更しません",
+            ko: "{}은(는) 이미 {}에 있어 변경하지 않습니다",
+            fr: "{} figure déjà dans {}, inchangé",
+            de: "{} ist bereits in {} enthalten und bleibt unverändert",
+            ru: "{} уже есть в {}, оставлен без изменений",
+            es: "{} ya está en {}, se deja sin cambios",
+            pt: "{} já está em {}, mantido sem alterações",
+            it: "{} è già presente in {}, lasciato invariato"
+        },
+        "packages_added" => {
+            en: "Packages installed and {} updated!",
+            zh: "包已安装，{}已更新！",
+            ja: "パッケージをインストールし、{}を更新しました！",
+            ko: "패키지를 설치하고 {}을(를) 업데이트했습니다!",
+            fr: "Paquets installés et {} mis à jour !",
+            de: "Pakete installiert und {} aktualisiert!",
+            ru: "Пакеты установлены, {} обновлён!",
+            es: "¡Paquetes instalados y {} actualizado!",
+            pt: "Pacotes instalados e {} atualizado!",
+            it: "Pacchetti installati e {} aggiornato!"
+        },
+        "removing_packages" => {
+            en: "Removing Python packages...",
+            zh: "正在删除Python包...",
+            ja: "Pythonパッケージを削除しています...",
+            ko: "Python 패키지를 제거하는 중...",
+            fr: "Suppression des paquets Python...",
+            de: "Python-Pakete werden entfernt...",
+            ru: "Удаление пакетов Python...",
+            es: "Eliminando paquetes de Python...",
+            pt: "Removendo pacotes Python...",
+            it: "Rimozione dei pacchetti Python..."
+        },
+        "more_sources" => {
+            en: "{} and {} more",
+            zh: "{}等{}个文件",
+            ja: "{} ほか{}件",
+            ko: "{} 외 {}개",
+            fr: "{} et {} autres",
+            de: "{} und {} weitere",
+            ru: "{} и ещё {}",
+            es: "{} y {} más",
+            pt: "{} e mais {}",
+            it: "{} e altri {}"
+        },
+        "package_still_imported" => {
+            en: "Warning: {} is still imported in: {}",
+            zh: "警告：源代码中仍导入了{}：{}",
+            ja: "警告: {}はまだ次のファイルでインポートされています: {}",
+            ko: "경고: {}이(가) 아직 다음 파일에서 import됩니다: {}",
+            fr: "Avertissement : {} est toujours importé dans : {}",
+            de: "Warnung: {} wird noch importiert in: {}",
+            ru: "Предупреждение: {} всё ещё импортируется в: {}",
+            es: "Advertencia: {} todavía se importa en: {}",
+            pt: "Aviso: {} ainda é importado em: {}",
+            it: "Avviso: {} è ancora importato in: {}"
+        },
+        "venv_missing_skip_uninstall" => {
+            en: "Virtual environment {} not found, skipping uninstall",
+            zh: "未找到虚拟环境{}，跳过卸载",
+            ja: "仮想環境{}が見つからないため、アンインストールをスキップします",
+            ko: "가상 환경 {}을(를) 찾을 수 없어 제거를 건너뜁니다",
+            fr: "Environnement virtuel {} introuvable, désinstallation ignorée",
+            de: "Virtuelle Umgebung {} nicht gefunden, Deinstallation wird übersprungen",
+            ru: "Виртуальное окружение {} не найдено, удаление пакетов пропущено",
+            es: "No se encontró el entorno virtual {}, se omite la desinstalación",
+            pt: "Ambiente virtual {} não encontrado, desinstalação ignorada",
+            it: "Ambiente virtuale {} non trovato, disinstallazione saltata"
+        },
+        "requirements_file_missing" => {
+            en: "{} not found, nothing to update",
+            zh: "未找到{}，无需更新",
+            ja: "{}が見つからないため、更新するものはありません",
+            ko: "{}을(를) 찾을 수 없어 업데이트할 내용이 없습니다",
+            fr: "{} introuvable, rien à mettre à jour",
+            de: "{} nicht gefunden, nichts zu aktualisieren",
+            ru: "{} не найден, обновлять нечего",
+            es: "No se encontró {}, no hay nada que actualizar",
+            pt: "{} não encontrado, nada para atualizar",
+            it: "{} non trovato, niente da aggiornare"
+        },
+        "requirements_nothing_removed" => {
+            en: "None of the packages are listed in {}",
+            zh: "{}中没有这些包",
+            ja: "{}にはこれらのパッケージは含まれていません",
+            ko: "{}에 해당 패키지가 없습니다",
+            fr: "Aucun de ces paquets ne figure dans {}",
+            de: "Keines der Pakete ist in {} aufgeführt",
+            ru: "Ни один из этих пакетов не указан в {}",
+            es: "Ninguno de los paquetes figura en {}",
+            pt: "Nenhum dos pacotes está listado em {}",
+            it: "Nessuno dei pacchetti è elencato in {}"
+        },
+        "packages_removed" => {
+            en: "Removed from {}!",
+            zh: "已从{}中删除！",
+            ja: "{}から削除しました！",
+            ko: "{}에서 제거했습니다!",
+            fr: "Supprimés de {} !",
+            de: "Aus {} entfernt!",
+            ru: "Удалено из {}!",
+            es: "¡Eliminados de {}!",
+            pt: "Removidos de {}!",
+            it: "Rimossi da {}!"
+        },
+        "language_fallback_invalid" => {
+            en: "Ignoring unknown language code in PYWAND_LANG_FALLBACK: {}",
+            zh: "忽略PYWAND_LANG_FALLBACK中无法识别的语言代码：{}",
+            ja: "PYWAND_LANG_FALLBACKの不明な言語コードを無視します: {}",
+            ko: "PYWAND_LANG_FALLBACK의 알 수 없는 언어 코드를 무시합니다: {}",
+            fr: "Code de langue inconnu ignoré dans PYWAND_LANG_FALLBACK : {}",
+            de: "Unbekannter Sprachcode in PYWAND_LANG_FALLBACK wird ignoriert: {}",
+            ru: "Неизвестный код языка в PYWAND_LANG_FALLBACK пропущен: {}",
+            es: "Se ignora el código de idioma desconocido en PYWAND_LANG_FALLBACK: {}",
+            pt: "Código de idioma desconhecido ignorado em PYWAND_LANG_FALLBACK: {}",
+            it: "Codice lingua sconosciuto ignorato in PYWAND_LANG_FALLBACK: {}"
+        },
+        "file_exists_use_force" => {
+            en: "File already exists: {}. Use --force to overwrite",
+            zh: "文件已存在: {}，使用--force覆盖",
+            ja: "ファイルは既に存在します: {}。上書きするには--forceを使用してください",
+            ko: "파일이 이미 존재합니다: {}. 덮어쓰려면 --force를 사용하세요",
+            fr: "Le fichier existe déjà : {}. Utilisez --force pour l'écraser",
+            de: "Datei existiert bereits: {}. Verwenden Sie --force zum Überschreiben",
+            ru: "Файл уже существует: {}. Используйте --force для перезаписи",
+            es: "El archivo ya existe: {}. Use --force para sobrescribirlo",
+            pt: "O arquivo já existe: {}. Use --force para sobrescrever",
+            it: "Il file esiste già: {}. Usa --force per sovrascriverlo"
+        },
+        "export_duplicate_target" => {
+            en: "Duplicate export target: {}/{} Python {}",
+            zh: "重复的导出目标: {}/{} Python {}",
+            ja: "エクスポート対象が重複しています: {}/{} Python {}",
+            ko: "중복된 내보내기 대상: {}/{} Python {}",
+            fr: "Cible d'export en double : {}/{} Python {}",
+            de: "Doppeltes Exportziel: {}/{} Python {}",
+            ru: "Повторяющаяся цель экспорта: {}/{} Python {}",
+            es: "Destino de exportación duplicado: {}/{} Python {}",
+            pt: "Destino de exportação duplicado: {}/{} Python {}",
+            it: "Destinazione di esportazione duplicata: {}/{} Python {}"
+        },
+        "python_version_unsupported" => {
+            en: "Python {} is not supported on this platform. Available versions: {}",
+            zh: "当前平台不支持Python {}，可选版本: {}",
+            ja: "このプラットフォームはPython {}をサポートしていません。利用可能なバージョン: {}",
+            ko: "이 플랫폼은 Python {}을(를) 지원하지 않습니다. 사용 가능한 버전: {}",
+            fr: "Python {} n'est pas pris en charge sur cette plateforme. Versions disponibles : {}",
+            de: "Python {} wird auf dieser Plattform nicht unterstützt. Verfügbare Versionen: {}",
+            ru: "Python {} не поддерживается на этой платформе. Доступные версии: {}",
+            es: "Python {} no es compatible con esta plataforma. Versiones disponibles: {}",
+            pt: "Python {} não é suportado nesta plataforma. Versões disponíveis: {}",
+            it: "Python {} non è supportato su questa piattaforma. Versioni disponibili: {}"
+        },
+        "file_read_failed" => {
+            en: "Cannot read the file",
+            zh: "无法读取文件",
+            ja: "ファイルを読み込めません",
+            ko: "파일을 읽을 수 없습니다",
+            fr: "Impossible de lire le fichier",
+            de: "Die Datei kann nicht gelesen werden",
+            ru: "Не удалось прочитать файл",
+            es: "No se puede leer el archivo",
+            pt: "Não foi possível ler o arquivo",
+            it: "Impossibile leggere il file"
+        },
+        "file_format_invalid" => {
+            en: "Invalid file format",
+            zh: "文件格式无效",
+            ja: "ファイル形式が無効です",
+            ko: "파일 형식이 잘못되었습니다",
+            fr: "Format de fichier invalide",
+            de: "Ungültiges Dateiformat",
+            ru: "Неверный формат файла",
+            es: "Formato de archivo no válido",
+            pt: "Formato de arquivo inválido",
+            it: "Formato del file non valido"
+        },
+        "config_value_not_string_list" => {
+            en: "{} should be a list of strings",
+            zh: "{}应为字符串列表",
+            ja: "{}は文字列のリストである必要があります",
+            ko: "{}은(는) 문자열 목록이어야 합니다",
+            fr: "{} doit être une liste de chaînes",
+            de: "{} muss eine Liste von Zeichenketten sein",
+            ru: "{} должен быть списком строк",
+            es: "{} debe ser una lista de cadenas",
+            pt: "{} deve ser uma lista de strings",
+            it: "{} deve essere un elenco di stringhe"
+        },
+        "config_value_not_string" => {
+            en: "{} should be a string",
+            zh: "{}应为字符串",
+            ja: "{}は文字列である必要があります",
+            ko: "{}은(는) 문자열이어야 합니다",
+            fr: "{} doit être une chaîne",
+            de: "{} muss eine Zeichenkette sein",
+            ru: "{} должен быть строкой",
+            es: "{} debe ser una cadena",
+            pt: "{} deve ser uma string",
+            it: "{} deve essere una stringa"
+        },
+        "config_invalid_exclude_mode" => {
+            en: "exclude_dirs_mode must be \"extend\" or \"override\"",
+            zh: "exclude_dirs_mode只能是\"extend\"或\"override\"",
+            ja: "exclude_dirs_modeは\"extend\"または\"override\"のみ指定できます",
+            ko: "exclude_dirs_mode는 \"extend\" 또는 \"override\"만 가능합니다",
+            fr: "exclude_dirs_mode doit valoir \"extend\" ou \"override\"",
+            de: "exclude_dirs_mode muss \"extend\" oder \"override\" sein",
+            ru: "exclude_dirs_mode может быть только \"extend\" или \"override\"",
+            es: "exclude_dirs_mode solo puede ser \"extend\" u \"override\"",
+            pt: "exclude_dirs_mode só pode ser \"extend\" ou \"override\"",
+            it: "exclude_dirs_mode può essere solo \"extend\" o \"override\""
+        },
+        "config_language_unsupported" => {
+            en: "Unsupported language code: {}",
+            zh: "不支持的语言代码: {}",
+            ja: "サポートされていない言語コード: {}",
+            ko: "지원되지 않는 언어 코드: {}",
+            fr: "Code de langue non pris en charge : {}",
+            de: "Nicht unterstützter Sprachcode: {}",
+            ru: "Неподдерживаемый код языка: {}",
+            es: "Código de idioma no compatible: {}",
+            pt: "Código de idioma não suportado: {}",
+            it: "Codice lingua non supportato: {}"
+        },
+        "mapping_package_invalid" => {
+            en: "Invalid package name for mapping \"{}\": {} (write extras as package[extra1,extra2])",
+            zh: "映射\"{}\"的包名无效: {}（extras应写作 包名[extra1,extra2]）",
+            ja: "マッピング\"{}\"のパッケージ名が無効です: {}（extrasは パッケージ名[extra1,extra2] と記述します）",
+            ko: "매핑 \"{}\"의 패키지 이름이 잘못되었습니다: {} (extras는 패키지명[extra1,extra2] 형식으로 작성)",
+            fr: "Nom de paquet invalide pour l'association \"{}\" : {} (les extras s'écrivent paquet[extra1,extra2])",
+            de: "Ungültiger Paketname für die Zuordnung \"{}\": {} (Extras werden als paket[extra1,extra2] geschrieben)",
+            ru: "Неверное имя пакета для сопоставления \"{}\": {} (extras записываются как пакет[extra1,extra2])",
+            es: "Nombre de paquete no válido para la asociación \"{}\": {} (los extras se escriben paquete[extra1,extra2])",
+            pt: "Nome de pacote inválido para a associação \"{}\": {} (extras são escritos como pacote[extra1,extra2])",
+            it: "Nome di pacchetto non valido per l'associazione \"{}\": {} (gli extra si scrivono pacchetto[extra1,extra2])"
+        },
+        "requirement_provenance" => {
+            en: "Where each requirement comes from:",
+            zh: "各依赖的来源：",
+            ja: "各依存関係の出所:",
+            ko: "각 종속성의 출처:",
+            fr: "Origine de chaque dépendance :",
+            de: "Herkunft der Abhängigkeiten:",
+            ru: "Источники зависимостей:",
+            es: "Origen de cada dependencia:",
+            pt: "Origem de cada dependência:",
+            it: "Provenienza di ogni dipendenza:"
+        },
+        "interrupted" => {
+            en: "Interrupted, cleaning up...",
+            zh: "已中断，正在清理...",
+            ja: "中断しました。後片付けをしています...",
+            ko: "중단되었습니다. 정리하는 중...",
+            fr: "Interrompu, nettoyage en cours...",
+            de: "Abgebrochen, es wird aufgeräumt...",
+            ru: "Прервано, выполняется очистка...",
+            es: "Interrumpido, limpiando...",
+            pt: "Interrompido, limpando...",
+            it: "Interrotto, pulizia in corso..."
+        },
+        "interrupted_output_removed" => {
+            en: "Removed incomplete output: {}",
+            zh: "已删除未完成的输出: {}",
+            ja: "未完成の出力を削除しました: {}",
+            ko: "완료되지 않은 출력을 삭제했습니다: {}",
+            fr: "Sortie incomplète supprimée : {}",
+            de: "Unvollständige Ausgabe entfernt: {}",
+            ru: "Удалён незавершённый вывод: {}",
+            es: "Se eliminó la salida incompleta: {}",
+            pt: "Saída incompleta removida: {}",
+            it: "Output incompleto rimosso: {}"
+        },
+        "requirements_added" => {
+            en: "Added newly detected imports to requirements.txt:",
+            zh: "已将新检测到的导入添加到requirements.txt：",
+            ja: "新しく検出したインポートをrequirements.txtに追加しました:",
+            ko: "새로 감지된 import를 requirements.txt에 추가했습니다:",
+            fr: "Imports nouvellement détectés ajoutés à requirements.txt :",
+            de: "Neu erkannte Importe zu requirements.txt hinzugefügt:",
+            ru: "Новые импорты добавлены в requirements.txt:",
+            es: "Importaciones detectadas añadidas a requirements.txt:",
+            pt: "Importações detectadas adicionadas ao requirements.txt:",
+            it: "Import rilevati aggiunti a requirements.txt:"
+        },
+        "requirements_missing_generating" => {
+            en: "No requirements.txt found, scanning and generating one...",
+            zh: "未检测到requirements.txt，正在扫描并生成...",
+            ja: "requirements.txtが見つかりません。スキャンして生成しています...",
+            ko: "requirements.txt를 찾을 수 없어 스캔 후 생성하는 중...",
+            fr: "Aucun requirements.txt trouvé, analyse et génération...",
+            de: "Keine requirements.txt gefunden, sie wird durch Scannen erzeugt...",
+            ru: "requirements.txt не найден, выполняется сканирование и генерация...",
+            es: "No se encontró requirements.txt, escaneando y generando uno...",
+            pt: "requirements.txt não encontrado, verificando e gerando um...",
+            it: "requirements.txt non trovato, scansione e generazione in corso..."
+        },
+        "script_running" => {
+            en: "Running script...",
+            zh: "正在运行脚本...",
+            ja: "スクリプトを実行しています...",
+            ko: "스크립트 실행 중...",
+            fr: "Exécution du script...",
+            de: "Skript wird ausgeführt...",
+            ru: "Выполнение скрипта...",
+            es: "Ejecutando el script...",
+            pt: "Executando o script...",
+            it: "Esecuzione dello script..."
+        },
+        "script_succeeded" => {
+            en: "Script finished successfully!",
+            zh: "脚本执行成功!",
+            ja: "スクリプトが正常に終了しました！",
+            ko: "스크립트가 성공적으로 실행되었습니다!",
+            fr: "Script exécuté avec succès !",
+            de: "Skript erfolgreich ausgeführt!",
+            ru: "Скрипт успешно выполнен!",
+            es: "¡El script finalizó correctamente!",
+            pt: "Script concluído com sucesso!",
+            it: "Script completato con successo!"
+        },
+        "script_failed" => {
+            en: "Script failed!",
+            zh: "脚本执行失败!",
+            ja: "スクリプトの実行に失敗しました！",
+            ko: "스크립트 실행에 실패했습니다!",
+            fr: "Échec de l'exécution du script !",
+            de: "Skriptausführung fehlgeschlagen!",
+            ru: "Ошибка выполнения скрипта!",
+            es: "¡El script falló!",
+            pt: "O script falhou!",
+            it: "Script non riuscito!"
+        },
+        "exit_code" => {
+            en: "Exit code: {}",
+            zh: "退出码: {}",
+            ja: "終了コード: {}",
+            ko: "종료 코드: {}",
+            fr: "Code de sortie : {}",
+            de: "Exit-Code: {}",
+            ru: "Код выхода: {}",
+            es: "Código de salida: {}",
+            pt: "Código de saída: {}",
+            it: "Codice di uscita: {}"
+        },
+        "current_uv_version" => {
+            en: "uv {} ({})",
+            zh: "uv {}（{}）",
+            ja: "uv {}（{}）",
+            ko: "uv {} ({})",
+            fr: "uv {} ({})",
+            de: "uv {} ({})",
+            ru: "uv {} ({})",
+            es: "uv {} ({})",
+            pt: "uv {} ({})",
+            it: "uv {} ({})"
+        },
+        "current_uv_version_short" => {
+            en: "Current uv version: {}",
+            zh: "当前uv版本: {}",
+            ja: "現在のuvバージョン: {}",
+            ko: "현재 uv 버전: {}",
+            fr: "Version actuelle de uv : {}",
+            de: "Aktuelle uv-Version: {}",
+            ru: "Текущая версия uv: {}",
+            es: "Versión actual de uv: {}",
+            pt: "Versão atual do uv: {}",
+            it: "Versione attuale di uv: {}"
+        },
+        "uv_version_unknown" => {
+            en: "unknown",
+            zh: "未知",
+            ja: "不明",
+            ko: "알 수 없음",
+            fr: "inconnue",
+            de: "unbekannt",
+            ru: "неизвестно",
+            es: "desconocida",
+            pt: "desconhecida",
+            it: "sconosciuta"
+        },
+        "updating_uv" => {
+            en: "Downloading the latest uv...",
+            zh: "正在下载最新的uv...",
+            ja: "最新のuvをダウンロードしています...",
+            ko: "최신 uv를 다운로드하는 중...",
+            fr: "Téléchargement de la dernière version de uv...",
+            de: "Neueste uv-Version wird heruntergeladen...",
+            ru: "Загрузка последней версии uv...",
+            es: "Descargando la última versión de uv...",
+            pt: "Baixando a versão mais recente do uv...",
+            it: "Download dell'ultima versione di uv..."
+        },
+        "uv_updated" => {
+            en: "uv updated: {} -> {} ({})",
+            zh: "uv已更新: {} -> {}（{}）",
+            ja: "uvを更新しました: {} -> {}（{}）",
+            ko: "uv 업데이트 완료: {} -> {} ({})",
+            fr: "uv mis à jour : {} -> {} ({})",
+            de: "uv aktualisiert: {} -> {} ({})",
+            ru: "uv обновлён: {} -> {} ({})",
+            es: "uv actualizado: {} -> {} ({})",
+            pt: "uv atualizado: {} -> {} ({})",
+            it: "uv aggiornato: {} -> {} ({})"
+        },
+        "system_uv_takes_precedence" => {
+            en: "Note: the uv on your PATH ({}) is still used before the updated copy",
+            zh: "注意: 系统PATH中的uv（{}）仍会优先于更新后的版本使用",
+            ja: "注意: PATH上のuv（{}）が更新されたuvより優先して使用されます",
+            ko: "참고: PATH에 있는 uv({})가 업데이트된 uv보다 먼저 사용됩니다",
+            fr: "Remarque : le uv présent dans le PATH ({}) reste prioritaire sur la copie mise à jour",
+            de: "Hinweis: Das uv im PATH ({}) wird weiterhin vor der aktualisierten Kopie verwendet",
+            ru: "Примечание: uv из PATH ({}) по-прежнему используется вместо обновлённой копии",
+            es: "Nota: el uv del PATH ({}) se sigue usando antes que la copia actualizada",
+            pt: "Observação: o uv no PATH ({}) continua sendo usado antes da cópia atualizada",
+            it: "Nota: l'uv nel PATH ({}) viene ancora usato prima della copia aggiornata"
+        },
+        "executing_uv" => {
+            en: "Executing UV command",
+            zh: "执行UV命令",
+            ja: "UVコマンドを実行",
+            ko: "UV 명령 실행",
+            fr: "Exécution de la commande UV",
+            de: "UV-Befehl wird ausgeführt",
+            ru: "Выполнение команды UV",
+            es: "Ejecutando comando de UV",
+            pt: "Executando comando UV",
+            it: "Esecuzione del comando UV"
+        },
+        "uv_succeeded" => {
+            en: "UV command finished successfully!",
+            zh: "UV命令执行成功!",
+            ja: "UVコマンドが正常に終了しました！",
+            ko: "UV 명령이 성공적으로 실행되었습니다!",
+            fr: "Commande UV exécutée avec succès !",
+            de: "UV-Befehl erfolgreich ausgeführt!",
+            ru: "Команда UV успешно выполнена!",
+            es: "¡El comando de UV finalizó correctamente!",
+            pt: "Comando UV concluído com sucesso!",
+            it: "Comando UV completato con successo!"
+        },
+        "uv_failed" => {
+            en: "UV command failed!",
+            zh: "UV命令执行失败!",
+            ja: "UVコマンドの実行に失敗しました！",
+            ko: "UV 명령 실행에 실패했습니다!",
+            fr: "Échec de la commande UV !",
+            de: "UV-Befehl fehlgeschlagen!",
+            ru: "Ошибка выполнения команды UV!",
+            es: "¡El comando de UV falló!",
+            pt: "O comando UV falhou!",
+            it: "Comando UV non riuscito!"
+        },
+        
+        // 演练模式
+        "dry_run_create_venv" => {
+            en: "[dry run] Would create a Python {} virtual environment in {}",
+            zh: "[演练] 将在{1}中创建Python {0}虚拟环境",
+            ja: "[ドライラン] {1}にPython {0}仮想環境を作成します",
+            ko: "[드라이 런] {1}에 Python {0} 가상 환경을 생성합니다",
+            fr: "[simulation] Créerait un environnement virtuel Python {} dans {}",
+            de: "[Probelauf] Würde eine virtuelle Python-{}-Umgebung in {} erstellen",
+            ru: "[пробный запуск] Будет создана виртуальная среда Python {} в {}",
+            es: "[simulación] Se crearía un entorno virtual de Python {} en {}",
+            pt: "[simulação] Seria criado um ambiente virtual Python {} em {}",
+            it: "[prova] Verrebbe creato un ambiente virtuale Python {} in {}"
+        },
+        "dry_run_write_file" => {
+            en: "[dry run] Would write {} with:",
+            zh: "[演练] 将写入{}，内容：",
+            ja: "[ドライラン] {}に次の内容を書き込みます:",
+            ko: "[드라이 런] {}에 다음 내용을 기록합니다:",
+            fr: "[simulation] Écrirait {} avec :",
+            de: "[Probelauf] Würde {} schreiben mit:",
+            ru: "[пробный запуск] Будет записан {} со следующим содержимым:",
+            es: "[simulación] Se escribiría {} con:",
+            pt: "[simulação] Seria gravado {} com:",
+            it: "[prova] Verrebbe scritto {} con:"
+        },
+        "dry_run_install_requirements" => {
+            en: "[dry run] Would install dependencies from {} into {}",
+            zh: "[演练] 将把{}中的依赖安装到{}",
+            ja: "[ドライラン] {}の依存関係を{}にインストールします",
+            ko: "[드라이 런] {}의 종속성을 {}에 설치합니다",
+            fr: "[simulation] Installerait les dépendances de {} dans {}",
+            de: "[Probelauf] Würde Abhängigkeiten aus {} in {} installieren",
+            ru: "[пробный запуск] Будут установлены зависимости из {} в {}",
+            es: "[simulación] Se instalarían las dependencias de {} en {}",
+            pt: "[simulação] Seriam instaladas as dependências de {} em {}",
+            it: "[prova] Verrebbero installate le dipendenze di {} in {}"
+        },
+        "dry_run_pin_requirements" => {
+            en: "[dry run] Would pin installed versions in {}",
+            zh: "[演练] 将在{}中锁定已安装的版本",
+            ja: "[ドライラン] {}にインストール済みのバージョンを固定します",
+            ko: "[드라이 런] {}에 설치된 버전을 고정합니다",
+            fr: "[simulation] Fixerait les versions installées dans {}",
+            de: "[Probelauf] Würde installierte Versionen in {} festschreiben",
+            ru: "[пробный запуск] Установленные версии будут зафиксированы в {}",
+            es: "[simulación] Se fijarían las versiones instaladas en {}",
+            pt: "[simulação] As versões instaladas seriam fixadas em {}",
+            it: "[prova] Verrebbero fissate le versioni installate in {}"
+        },
+        "dry_run_install_packages" => {
+            en: "[dry run] Would install into {}: {}",
+            zh: "[演练] 将安装到{}: {}",
+            ja: "[ドライラン] {}にインストールします: {}",
+            ko: "[드라이 런] {}에 설치합니다: {}",
+            fr: "[simulation] Installerait dans {} : {}",
+            de: "[Probelauf] Würde in {} installieren: {}",
+            ru: "[пробный запуск] Будет установлено в {}: {}",
+            es: "[simulación] Se instalaría en {}: {}",
+            pt: "[simulação] Seria instalado em {}: {}",
+            it: "[prova] Verrebbe installato in {}: {}"
+        },
+        "dry_run_activation_scripts" => {
+            en: "[dry run] Would create activation scripts for {}",
+            zh: "[演练] 将为{}创建激活脚本",
+            ja: "[ドライラン] {}用のアクティベーションスクリプトを作成します",
+            ko: "[드라이 런] {}용 활성화 스크립트를 생성합니다",
+            fr: "[simulation] Créerait les scripts d'activation pour {}",
+            de: "[Probelauf] Würde Aktivierungsskripte für {} erstellen",
+            ru: "[пробный запуск] Будут созданы скрипты активации для {}",
+            es: "[simulación] Se crearían los scripts de activación para {}",
+            pt: "[simulação] Seriam criados os scripts de ativação para {}",
+            it: "[prova] Verrebbero creati gli script di attivazione per {}"
+        },
+        "dry_run_command" => {
+            en: "[dry run] Would run: {}",
+            zh: "[演练] 将执行: {}",
+            ja: "[ドライラン] 実行するコマンド: {}",
+            ko: "[드라이 런] 실행할 명령: {}",
+            fr: "[simulation] Exécuterait : {}",
+            de: "[Probelauf] Würde ausführen: {}",
+            ru: "[пробный запуск] Будет выполнено: {}",
+            es: "[simulación] Se ejecutaría: {}",
+            pt: "[simulação] Seria executado: {}",
+            it: "[prova] Verrebbe eseguito: {}"
+        },
+        "dry_run_update_uv" => {
+            en: "[dry run] Would download the latest uv and replace the cached copy",
+            zh: "[演练] 将下载最新的uv并替换缓存的版本",
+            ja: "[ドライラン] 最新のuvをダウンロードしてキャッシュを置き換えます",
+            ko: "[드라이 런] 최신 uv를 다운로드하여 캐시된 버전을 교체합니다",
+            fr: "[simulation] Téléchargerait la dernière version de uv et remplacerait la copie en cache",
+            de: "[Probelauf] Würde die neueste uv-Version herunterladen und die zwischengespeicherte Kopie ersetzen",
+            ru: "[пробный запуск] Будет загружена последняя версия uv вместо кэшированной",
+            es: "[simulación] Se descargaría la última versión de uv y se reemplazaría la copia en caché",
+            pt: "[simulação] Baixaria a versão mais recente do uv e substituiria a cópia em cache",
+            it: "[prova] Verrebbe scaricata l'ultima versione di uv sostituendo la copia in cache"
+        },
+        "export_dir_kept" => {
+            en: "Export contents are kept in: {}",
+            zh: "导出内容保留在: {}",
+            ja: "エクスポート内容は次の場所に保持されます: {}",
+            ko: "내보내기 내용이 다음 위치에 보존됩니다: {}",
+            fr: "Le contenu de l'exportation est conservé dans : {}",
+            de: "Exportinhalte bleiben erhalten in: {}",
+            ru: "Содержимое экспорта сохранено в: {}",
+            es: "El contenido de la exportación se conserva en: {}",
+            pt: "O conteúdo da exportação foi mantido em: {}",
+            it: "Il contenuto dell'esportazione è conservato in: {}"
+        },
+        "dry_run_archive" => {
+            en: "[dry run] Would create archive {}",
+            zh: "[演练] 将创建归档{}",
+            ja: "[ドライラン] アーカイブ{}を作成します",
+            ko: "[드라이 런] 아카이브 {}을(를) 생성합니다",
+            fr: "[simulation] Créerait l'archive {}",
+            de: "[Probelauf] Würde das Archiv {} erstellen",
+            ru: "[пробный запуск] Будет создан архив {}",
+            es: "[simulación] Se crearía el archivo comprimido {}",
+            pt: "[simulação] Seria criado o arquivo compactado {}",
+            it: "[prova] Verrebbe creato l'archivio {}"
+        },
+        "dry_run_save_language" => {
+            en: "[dry run] Would save language setting: {}",
+            zh: "[演练] 将保存语言设置: {}",
+            ja: "[ドライラン] 言語設定を保存します: {}",
+            ko: "[드라이 런] 언어 설정을 저장합니다: {}",
+            fr: "[simulation] Enregistrerait la langue : {}",
+            de: "[Probelauf] Würde die Spracheinstellung speichern: {}",
+            ru: "[пробный запуск] Будет сохранен язык: {}",
+            es: "[simulación] Se guardaría el idioma: {}",
+            pt: "[simulação] Seria salvo o idioma: {}",
+            it: "[prova] Verrebbe salvata la lingua: {}"
+        },
+        
+        // 使用提示中的参数占位符
+        "arg_script" => {
+            en: "<script>",
+            zh: "<脚本>",
+            ja: "<スクリプト>",
+            ko: "<스크립트>",
+            fr: "<script>",
+            de: "<Skript>",
+            ru: "<скрипт>",
+            es: "<script>",
+            pt: "<script>",
+            it: "<script>"
+        },
+        "arg_command" => {
+            en: "<command>",
+            zh: "<命令>",
+            ja: "<コマンド>",
+            ko: "<명령>",
+            fr: "<commande>",
+            de: "<Befehl>",
+            ru: "<команда>",
+            es: "<comando>",
+            pt: "<comando>",
+            it: "<comando>"
+        },
+        "arg_packages" => {
+            en: "<packages...>",
+            zh: "<包名...>",
+            ja: "<パッケージ...>",
+            ko: "<패키지...>",
+            fr: "<paquets...>",
+            de: "<Pakete...>",
+            ru: "<пакеты...>",
+            es: "<paquetes...>",
+            pt: "<pacotes...>",
+            it: "<pacchetti...>"
+        },
+        "arg_language_code" => {
+            en: "<language code>",
+            zh: "<语言代码>",
+            ja: "<言語コード>",
+            ko: "<언어 코드>",
+            fr: "<code de langue>",
+            de: "<Sprachcode>",
+            ru: "<код языка>",
+            es: "<código de idioma>",
+            pt: "<código do idioma>",
+            it: "<codice lingua>"
         }
     };
 }
 
+/// 外部翻译文件所在的目录名
+const TRANSLATIONS_DIR_NAME: &str = "translations";
+
+#[derive(Clone)]
 pub struct I18n {
     pub language: Language,
+    fallbacks: Vec<Language>, // 当前语言缺少翻译时依次尝试的语言
+    overrides: HashMap<Language, HashMap<String, String>>, // 从外部翻译文件加载的覆盖项
 }
 
 impl I18n {
-    pub fn new() -> Self {
-        I18n {
-            language: Language::default(),
+    /// 使用指定语言创建，回退链读取自PYWAND_LANG_FALLBACK环境变量（如"ja,en"），未设置时回退到英语
+    pub fn with_language(language: Language) -> Self {
+        let fallback = env::var("PYWAND_LANG_FALLBACK").unwrap_or_default();
+        let (fallbacks, invalid) = parse_fallback_chain(&fallback);
+        
+        let i18n = I18n::with_fallbacks(language, fallbacks);
+        for code in invalid {
+            log::warn!("{}", i18n.get_formatted("language_fallback_invalid", &[&code]));
         }
+        i18n
     }
     
-    pub fn with_language(language: Language) -> Self {
-        I18n {
+    /// 使用指定语言和回退链创建，并加载这些语言的外部翻译文件
+    ///
+    /// 查找翻译时依次尝试当前语言和回退链中的语言，最后总是尝试英语
+    pub fn with_fallbacks(language: Language, fallbacks: Vec<Language>) -> Self {
+        let mut i18n = I18n {
             language,
+            fallbacks,
+            overrides: HashMap::new(),
+        };
+        
+        let mut errors = Vec::new();
+        for language in i18n.chain() {
+            if i18n.overrides.contains_key(&language) {
+                continue;
+            }
+            let mut overrides = HashMap::new();
+            for dir in translation_dirs() {
+                let path = dir.join(format!("{}.json", language.code()));
+                if !path.exists() {
+                    continue;
+                }
+                match read_translation_file(&path) {
+                    Ok(entries) => overrides.extend(entries),
+                    Err(e) => errors.push((path, e.to_string())),
+                }
+            }
+            i18n.overrides.insert(language, overrides);
+        }
+        
+        for (path, error) in errors {
+            log::warn!("{}", i18n.get_formatted(
+                "translations_load_failed",
+                &[&path.display().to_string(), &error]
+            ));
+        }
+        
+        i18n
+    }
+    
+    /// 查找翻译的语言顺序：当前语言、回退链，最后是英语
+    fn chain(&self) -> Vec<Language> {
+        let mut chain = vec![self.language];
+        for language in self.fallbacks.iter().copied().chain([Language::English]) {
+            if !chain.contains(&language) {
+                chain.push(language);
+            }
         }
+        chain
     }
     
-    pub fn get<'a>(&self, key: &'a str) -> &'a str {
-        TRANSLATIONS
-            .get(key)
-            .and_then(|translations| translations.get(&self.language))
-            .copied()
-            .unwrap_or_else(move || {
-                // 回退到英语，如果找不到就返回键名
-                TRANSLATIONS
-                    .get(key)
-                    .and_then(|translations| translations.get(&Language::English))
-                    .copied()
-                    .unwrap_or(key)
-            })
+    /// 获取翻译：按回退链依次查找每种语言，同一语言中外部覆盖项优先于内置翻译，都找不到时返回键名
+    pub fn get<'a>(&'a self, key: &'a str) -> &'a str {
+        for language in self.chain() {
+            if let Some(value) = self.overrides.get(&language).and_then(|overrides| overrides.get(key)) {
+                return value;
+            }
+            if let Some(value) = TRANSLATIONS.get(key).and_then(|translations| translations.get(&language)) {
+                return value;
+            }
+        }
+        key
     }
     
+    /// 获取翻译并填充参数：{0}、{1}按序号替换，{}按出现顺序依次替换
     pub fn get_formatted(&self, key: &str, args: &[&str]) -> String {
-        let template = self.get(key);
-        args.iter().enumerate().fold(template.to_string(), |acc, (i, arg)| {
-            acc.replace(&format!("{{{}}}", i), arg)
-        })
+        format_template(self.get(key), args)
+    }
+    
+    /// 获取与数量匹配的单复数形式，并用数量填充占位符
+    pub fn get_plural(&self, key: &str, count: usize) -> String {
+        let variants: Vec<&str> = self.get(key).split('|').collect();
+        let index = plural_index(self.language, count).min(variants.len() - 1);
+        format_template(variants[index], &[&count.to_string()])
+    }
+}
+
+/// 解析逗号分隔的语言代码回退链，返回识别的语言和无法识别的代码
+fn parse_fallback_chain(value: &str) -> (Vec<Language>, Vec<String>) {
+    let mut languages = Vec::new();
+    let mut invalid = Vec::new();
+    for code in value.split(',').map(str::trim).filter(|code| !code.is_empty()) {
+        match Language::from_code(code) {
+            Some(language) => languages.push(language),
+            None => invalid.push(code.to_string()),
+        }
+    }
+    (languages, invalid)
+}
+
+/// 外部翻译文件的查找目录，后面的目录优先级更高：程序所在目录，然后是配置目录
+fn translation_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(exe_dir) = env::current_exe().ok().and_then(|exe| exe.parent().map(Path::to_path_buf)) {
+        dirs.push(exe_dir.join(TRANSLATIONS_DIR_NAME));
+    }
+    if let Some(config_dir) = dirs::config_dir() {
+        dirs.push(config_dir.join("pywand").join(TRANSLATIONS_DIR_NAME));
     }
+    dirs
+}
+
+/// 读取翻译文件：键为翻译键、值为译文的JSON对象
+fn read_translation_file(path: &Path) -> Result<HashMap<String, String>, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// 按语言的复数规则选择变体序号
+fn plural_index(language: Language, count: usize) -> usize {
+    match language {
+        // 中日韩没有单复数变化
+        Language::Chinese | Language::Japanese | Language::Korean => 0,
+        // 法语中0和1都使用单数
+        Language::French => usize::from(count > 1),
+        // 俄语区分1、2-4和其他
+        Language::Russian => {
+            let (last, last_two) = (count % 10, count % 100);
+            if last == 1 && last_two != 11 {
+                0
+            } else if (2..=4).contains(&last) && !(12..=14).contains(&last_two) {
+                1
+            } else {
+                2
+            }
+        }
+        _ => usize::from(count != 1),
+    }
+}
+
+/// 填充模板中的{n}和{}占位符，缺少参数的占位符保持原样
+fn format_template(template: &str, args: &[&str]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut next_positional = 0;
+    let mut rest = template;
+    
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let placeholder = after.find('}').map(|end| &after[..end]);
+        
+        let index = match placeholder {
+            Some("") => {
+                next_positional += 1;
+                Some(next_positional - 1)
+            }
+            Some(digits) => digits.parse::<usize>().ok(),
+            None => None,
+        };
+        
+        match (placeholder, index.and_then(|index| args.get(index))) {
+            (Some(placeholder), Some(arg)) => {
+                result.push_str(arg);
+                rest = &after[placeholder.len() + 1..];
+            }
+            _ => {
+                result.push('{');
+                rest = after;
+            }
+        }
+    }
+    result.push_str(rest);
     
-    pub fn current_language(&self) -> Language {
-        self.language
+    result
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// 不读取外部翻译文件，只使用给定的覆盖项
+    fn i18n_with_overrides(language: Language, fallbacks: Vec<Language>, overrides: &[(Language, &str, &str)]) -> I18n {
+        let mut i18n = I18n { language, fallbacks, overrides: HashMap::new() };
+        for (language, key, value) in overrides {
+            i18n.overrides.entry(*language).or_default().insert(key.to_string(), value.to_string());
+        }
+        i18n
     }
     
-    pub fn set_language(&mut self, language: Language) {
-        self.language = language;
+    #[test]
+    fn missing_key_follows_the_fallback_chain() {
+        let overrides = [
+            (Language::Japanese, "regional_greeting", "こんにちは"),
+            (Language::English, "regional_greeting", "Hello"),
+            (Language::English, "english_only", "Only in English"),
+        ];
+        
+        let i18n = i18n_with_overrides(Language::Chinese, vec![Language::Japanese], &overrides);
+        assert_eq!(i18n.get("regional_greeting"), "こんにちは");
+        assert_eq!(i18n.get("english_only"), "Only in English");
+        assert_eq!(i18n.get("unknown_key"), "unknown_key");
+        
+        // 默认的回退链是当前语言和英语
+        let i18n = i18n_with_overrides(Language::Chinese, Vec::new(), &overrides);
+        assert_eq!(i18n.get("regional_greeting"), "Hello");
+    }
+    
+    #[test]
+    fn fallback_chain_is_parsed_from_language_codes() {
+        let (languages, invalid) = parse_fallback_chain("ja, en,xx,");
+        assert_eq!(languages, vec![Language::Japanese, Language::English]);
+        assert_eq!(invalid, vec!["xx"]);
+        
+        let i18n = i18n_with_overrides(Language::Japanese, vec![Language::Chinese, Language::Japanese], &[]);
+        assert_eq!(i18n.chain(), vec![Language::Japanese, Language::Chinese, Language::English]);
     }
-} 
\ No newline at end of file
+    
+    #[test]
+    fn plural_forms_follow_the_language_rules() {
+        let english = i18n_with_overrides(Language::English, Vec::new(), &[]);
+        assert_eq!(english.get_plural("found_files", 1), "Found 1 Python file");
+        assert_eq!(english.get_plural("found_files", 3), "Found 3 Python files");
+        assert_eq!(english.get_plural("found_files", 0), "Found 0 Python files");
+        
+        let french = i18n_with_overrides(Language::French, Vec::new(), &[]);
+        assert_eq!(french.get_plural("found_files", 0), "0 fichier Python trouvé");
+        let russian = i18n_with_overrides(Language::Russian, Vec::new(), &[]);
+        assert_eq!(russian.get_plural("found_files", 22), "Найдено 22 файла Python");
+        assert_eq!(russian.get_plural("found_files", 11), "Найдено 11 файлов Python");
+        let chinese = i18n_with_overrides(Language::Chinese, Vec::new(), &[]);
+        assert_eq!(chinese.get_plural("found_files", 3), "找到3个Python文件");
+    }
+    
+    #[test]
+    fn placeholders_are_filled_by_index_and_in_order() {
+        assert_eq!(format_template("{} and {}", &["a", "b"]), "a and b");
+        assert_eq!(format_template("{1} before {0}", &["a", "b"]), "b before a");
+        assert_eq!(format_template("{} {} {2}", &["a"]), "a {} {2}");
+    }
+}
@@ -0,0 +1,1023 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use lazy_static::lazy_static;
+use log::debug;
+use rayon::prelude::*;
+use regex::Regex;
+use walkdir::WalkDir;
+
+/// 扫描Python文件的默认最大目录深度
+pub const DEFAULT_MAX_DEPTH: usize = 10;
+
+/// 默认的源文件大小上限（字节），更大的文件通常是生成的代码，默认跳过
+pub const DEFAULT_MAX_FILE_SIZE: u64 = 2 * 1024 * 1024;
+
+/// 扫描时默认排除的目录名
+pub const DEFAULT_EXCLUDED_DIRS: &[&str] = &[
+    ".git", ".venv", "venv", "env", "__pycache__", "node_modules",
+    ".idea", ".vscode", "dist", "build", "target", ".pytest_cache"
+];
+
+/// 扫描目录的设置
+#[derive(Debug, Clone)]
+pub struct ScanConfig {
+    /// 最大目录深度，0表示不限制
+    pub max_depth: usize,
+    /// 保留只在 if TYPE_CHECKING: 块中导入的包
+    pub include_type_checking: bool,
+    /// 同时扫描.pyi类型存根文件
+    pub include_stubs: bool,
+    /// 源文件大小上限（字节），0表示不限制
+    pub max_file_size: u64,
+    /// 超过大小上限的文件只读取开头的字节数，0表示跳过这些文件
+    pub oversized_head: u64,
+    /// 排除的目录名
+    pub excluded_dirs: Vec<String>,
+    /// 排除的路径glob，相对于扫描目录
+    pub exclude: Vec<String>,
+    /// 目标Python版本，用于判断标准库
+    pub python_version: Option<String>,
+    /// 导入名到PyPI包名的映射
+    pub package_mappings: HashMap<String, String>,
+}
+
+impl Default for ScanConfig {
+    fn default() -> Self {
+        ScanConfig {
+            max_depth: DEFAULT_MAX_DEPTH,
+            include_type_checking: false,
+            include_stubs: false,
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            oversized_head: 0,
+            excluded_dirs: DEFAULT_EXCLUDED_DIRS.iter().map(|dir| dir.to_string()).collect(),
+            exclude: Vec::new(),
+            python_version: None,
+            package_mappings: default_package_mappings(),
+        }
+    }
+}
+
+/// 目录的扫描结果
+#[derive(Debug, Clone, Default)]
+pub struct ScanResult {
+    /// 扫描到的.py文件（以及启用时的.pyi文件）
+    pub files: Vec<String>,
+    /// 推断出的PyPI包名，已排序去重
+    pub dependencies: Vec<String>,
+    /// 导入的标准库模块
+    pub stdlib: Vec<String>,
+    /// 项目自身的模块
+    pub local: Vec<String>,
+    /// 达到深度限制而没有展开的目录数量
+    pub truncated_dirs: usize,
+    /// 超过大小上限而被跳过（或只读取了开头）的文件
+    pub oversized: Vec<String>,
+    /// 无法对应到PyPI包名的导入
+    pub unresolved: Vec<String>,
+    /// 导入的所有模块（包括标准库和本地模块），已排序去重
+    pub modules: Vec<String>,
+    /// 模块到导入它的源文件的映射，路径相对于扫描目录，使用/分隔
+    pub module_sources: HashMap<String, Vec<String>>,
+}
+
+/// 读取的源文件内容
+#[derive(Debug, Clone, PartialEq)]
+pub enum SourceContent {
+    /// 完整的文件内容
+    Full(String),
+    /// 文件超过大小上限，只读取了开头的完整行
+    Head(String),
+    /// 文件超过大小上限，没有读取
+    Skipped,
+}
+
+impl SourceContent {
+    /// 文件是否超过了大小上限
+    pub fn is_oversized(&self) -> bool {
+        !matches!(self, SourceContent::Full(_))
+    }
+    
+    /// 可用于分析导入的内容，跳过的文件为空
+    pub fn text(&self) -> &str {
+        match self {
+            SourceContent::Full(content) | SourceContent::Head(content) => content,
+            SourceContent::Skipped => "",
+        }
+    }
+}
+
+/// 按大小上限读取源文件，非UTF-8的内容按有损方式转换
+pub fn read_source(path: &Path, config: &ScanConfig) -> io::Result<SourceContent> {
+    let size = fs::metadata(path)?.len();
+    read_source_from(File::open(path)?, size, config)
+}
+
+/// 按大小上限从reader读取源代码（如归档中的文件），`size`为内容的总字节数
+pub fn read_source_from(mut reader: impl Read, size: u64, config: &ScanConfig) -> io::Result<SourceContent> {
+    if config.max_file_size == 0 || size <= config.max_file_size {
+        let mut content = String::new();
+        reader.read_to_string(&mut content)?;
+        return Ok(SourceContent::Full(content));
+    }
+    if config.oversized_head == 0 {
+        return Ok(SourceContent::Skipped);
+    }
+    
+    let mut head = Vec::new();
+    reader.take(config.oversized_head).read_to_end(&mut head)?;
+    // 丢掉最后被截断的一行
+    if let Some(end) = head.iter().rposition(|&byte| byte == b'\n') {
+        head.truncate(end + 1);
+    }
+    Ok(SourceContent::Head(String::from_utf8_lossy(&head).into_owned()))
+}
+
+/// 扫描目录中的Python文件，推断项目依赖的PyPI包
+///
+/// 只根据导入语句推断，不读取requirements.txt等依赖声明文件
+pub fn scan_directory(path: impl AsRef<Path>, config: &ScanConfig) -> Result<ScanResult> {
+    scan_directory_with_progress(path, config, || {})
+}
+
+/// 与`scan_directory`相同，每读取完一个文件调用一次`on_file`（可能在多个线程中调用），用于显示进度
+pub fn scan_directory_with_progress(
+    path: impl AsRef<Path>,
+    config: &ScanConfig,
+    on_file: impl Fn() + Sync,
+) -> Result<ScanResult> {
+    let root = path.as_ref();
+    let (files, truncated_dirs) = find_python_files(root, config)?;
+    
+    // 并行读取和扫描文件，每个文件得到各自的模块列表
+    let per_file: Vec<(Vec<String>, bool)> = files
+        .par_iter()
+        .map(|file| {
+            let relative = Path::new(file).strip_prefix(root).unwrap_or(Path::new(file));
+            let result = match read_source(Path::new(file), config) {
+                Ok(content) => (
+                    source_modules(content.text(), config.include_type_checking, &config.package_mappings)
+                        .into_iter()
+                        .filter(|module| !is_build_script_import(relative, module))
+                        .collect(),
+                    content.is_oversized(),
+                ),
+                Err(_) => (Vec::new(), false),
+            };
+            on_file();
+            result
+        })
+        .collect();
+    let oversized: Vec<String> = files
+        .iter()
+        .zip(&per_file)
+        .filter(|(_, (_, oversized))| *oversized)
+        .map(|(file, _)| file.clone())
+        .collect();
+    
+    // 记录每个模块被哪些文件导入
+    let mut module_sources: HashMap<String, Vec<String>> = HashMap::new();
+    for (file, (modules, _)) in files.iter().zip(&per_file) {
+        let relative = Path::new(file).strip_prefix(root).unwrap_or(Path::new(file));
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        for module in modules {
+            let sources = module_sources.entry(module.clone()).or_default();
+            if !sources.contains(&relative) {
+                sources.push(relative.clone());
+            }
+        }
+    }
+    
+    // 合并后排序去重，保证结果与线程调度无关
+    let mut modules: Vec<String> = per_file.into_iter().flat_map(|(modules, _)| modules).collect();
+    modules.sort();
+    modules.dedup();
+    
+    let local_modules = local_module_names(root, &files);
+    let python_version = config.python_version.as_deref();
+    let mut result = ScanResult { files, truncated_dirs, oversized, module_sources, ..ScanResult::default() };
+    for module in &modules {
+        let root = import_root(module);
+        if local_modules.contains(root) {
+            result.local.push(root.to_string());
+        } else if is_standard_library(root, python_version) {
+            result.stdlib.push(root.to_string());
+        } else if let Some(package) = normalize_package_name(module, python_version, &config.package_mappings) {
+            result.dependencies.push(package);
+        } else {
+            result.unresolved.push(module.clone());
+        }
+    }
+    
+    result.dependencies.sort_by_key(|package| package.to_lowercase());
+    result.dependencies.dedup();
+    result.stdlib.dedup();
+    result.local.dedup();
+    result.modules = modules;
+    Ok(result)
+}
+
+/// 查找目录中的.py文件（`include_stubs`时也包括.pyi文件），返回文件路径和达到深度限制而没有展开的目录数量
+pub fn find_python_files(root: &Path, config: &ScanConfig) -> Result<(Vec<String>, usize)> {
+    let exclude = build_glob_set(&config.exclude)?;
+    
+    let mut walker = WalkDir::new(root).follow_links(false); // 不进入符号链接目录，避免循环
+    if config.max_depth > 0 {
+        walker = walker.max_depth(config.max_depth); // 限制递归深度
+    }
+    
+    let mut files = Vec::new();
+    let mut truncated_dirs = 0;
+    for entry in walker
+        .into_iter()
+        .filter_entry(|e| is_scanned_entry(e, root, &config.excluded_dirs, &exclude))
+        .filter_map(|e| e.ok())
+    {
+        // 达到深度限制的目录不会再被展开，只统计其中确实有未扫描内容的目录
+        if config.max_depth > 0 && entry.depth() == config.max_depth && entry.file_type().is_dir() {
+            if has_unscanned_children(entry.path(), config) {
+                truncated_dirs += 1;
+            }
+            continue;
+        }
+        
+        if is_source_path(entry.path(), config) {
+            debug!("{}", entry.path().display());
+            files.push(entry.path().display().to_string());
+        }
+    }
+    
+    Ok((files, truncated_dirs))
+}
+
+/// 是否为需要分析的源文件：.py文件，启用时还包括.pyi文件
+fn is_source_path(path: &Path, config: &ScanConfig) -> bool {
+    path.extension().is_some_and(|ext| ext == "py" || (config.include_stubs && ext == "pyi"))
+}
+
+/// 达到深度限制的目录中是否有本应扫描的内容：源文件或未被排除的子目录
+fn has_unscanned_children(dir: &Path, config: &ScanConfig) -> bool {
+    let Ok(children) = fs::read_dir(dir) else {
+        return false;
+    };
+    children.filter_map(|child| child.ok()).any(|child| {
+        let path = child.path();
+        if child.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            let name = child.file_name().to_string_lossy().to_string();
+            !config.excluded_dirs.contains(&name)
+        } else {
+            is_source_path(&path, config)
+        }
+    })
+}
+
+/// 编译--exclude、--include-data等选项指定的glob，* 不跨越目录分隔符，** 匹配任意层目录
+pub fn build_glob_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = GlobBuilder::new(pattern)
+            .literal_separator(true)
+            .build()
+            .with_context(|| format!("无效的路径模式: {}", pattern))?;
+        builder.add(glob);
+    }
+    builder.build().context("无法编译路径模式")
+}
+
+/// 判断扫描时是否进入该目录或保留该文件：排除--exclude匹配的路径、默认排除的目录和虚拟环境目录
+pub fn is_scanned_entry(entry: &walkdir::DirEntry, root: &Path, excluded_dirs: &[String], exclude: &GlobSet) -> bool {
+    // 排除--exclude匹配的文件和目录
+    if let Ok(relative) = entry.path().strip_prefix(root) {
+        if !relative.as_os_str().is_empty() && exclude.is_match(relative) {
+            return false;
+        }
+    }
+    // 排除特定目录
+    if entry.file_type().is_dir() {
+        let file_name = entry.file_name().to_string_lossy();
+        // 跳过任意名称的虚拟环境目录（包含pyvenv.cfg）
+        return !excluded_dirs.iter().any(|d| &file_name == d)
+            && !entry.path().join("pyvenv.cfg").exists();
+    }
+    true
+}
+
+lazy_static! {
+    // from语句同时捕获被导入的名称，以便识别 from google.cloud import storage 这类命名空间包；
+    // import语句取整行，以便处理 import a, b as c 形式；
+    // 任意缩进都可匹配，也包括写在同一行的复合语句之后的导入，如 try: import ujson
+    // 相对导入（from . import x）也会被匹配，以便整条语句被识别后跳过
+    static ref IMPORT_RE: Regex = Regex::new(
+        r"(?m)^\s*(?:(?:try|else|finally|except|if|elif|with|for|while)\b[^:\r\n]*:[ \t]*)?(?:from\s+(\.*[a-zA-Z0-9_][a-zA-Z0-9_.]*|\.+)\s+import\s+(\([^)]*\)|[^#;\r\n]+)|import\s+([^#;\r\n]+))"
+    ).unwrap();
+    // if TYPE_CHECKING: 或 if typing.TYPE_CHECKING:，捕获冒号后同一行的内容
+    static ref TYPE_CHECKING_RE: Regex = Regex::new(
+        r"^\s*(?:el)?if\s+(?:typing\.)?TYPE_CHECKING\s*:(.*)$"
+    ).unwrap();
+    // 动态导入：importlib.import_module("x") 和 __import__("x")
+    static ref DYNAMIC_IMPORT_RE: Regex = Regex::new(
+        r#"(?:import_module|__import__)\(\s*["']([a-zA-Z0-9_.]+)["']"#
+    ).unwrap();
+    // 忽略指令：行尾的 # pywand: ignore 跳过该行的导入，文件开头的 # pywand: ignore-file 跳过整个文件
+    static ref IGNORE_DIRECTIVE_RE: Regex = Regex::new(
+        r"#\s*pywand:\s*(ignore-file|ignore)\b"
+    ).unwrap();
+}
+
+/// 内置的导入名到PyPI包名映射，带点号的条目按最长前缀匹配
+pub const PACKAGE_MAPPINGS: &[(&str, &str)] = &[
+    ("yaml", "PyYAML"),
+    ("PIL", "Pillow"),
+    ("bs4", "beautifulsoup4"),
+    ("sklearn", "scikit-learn"),
+    ("google.cloud.storage", "google-cloud-storage"),
+    ("google.cloud.bigquery", "google-cloud-bigquery"),
+    ("google.cloud.pubsub", "google-cloud-pubsub"),
+    ("google.cloud.firestore", "google-cloud-firestore"),
+    ("google.protobuf", "protobuf"),
+    ("azure.storage.blob", "azure-storage-blob"),
+    ("azure.identity", "azure-identity"),
+    ("azure.keyvault.secrets", "azure-keyvault-secrets"),
+];
+
+/// 由多个PyPI包提供的导入名及候选包，无法确定应使用哪个包，需要在映射文件中指定
+const AMBIGUOUS_IMPORTS: &[(&str, &[&str])] = &[
+    ("cv2", &["opencv-python", "opencv-python-headless", "opencv-contrib-python"]),
+    ("Crypto", &["pycryptodome", "pycrypto"]),
+    ("jwt", &["PyJWT", "jwt"]),
+    ("magic", &["python-magic", "file-magic"]),
+    ("serial", &["pyserial", "serial"]),
+];
+
+/// 导入名对应多个候选包时返回这些包名
+pub fn ambiguous_import_candidates(module: &str) -> Option<&'static [&'static str]> {
+    let root = import_root(module);
+    AMBIGUOUS_IMPORTS
+        .iter()
+        .find(|(name, _)| *name == root)
+        .map(|(_, candidates)| *candidates)
+}
+
+/// 内置的包名映射
+pub fn default_package_mappings() -> HashMap<String, String> {
+    PACKAGE_MAPPINGS
+        .iter()
+        .map(|(module, package)| (module.to_string(), package.to_string()))
+        .collect()
+}
+
+/// 从Python源码中提取导入的模块，返回去重后的完整点号路径
+pub fn extract_imports_from_source(content: &str) -> Vec<String> {
+    let mut imports = Vec::new();
+
+    for cap in IMPORT_RE.captures_iter(content) {
+        if let (Some(module), Some(names)) = (cap.get(1), cap.get(2)) {
+            // 相对导入（from . import x、from ..pkg import y）总是指向项目自身的模块
+            if module.as_str().starts_with('.') {
+                continue;
+            }
+            let names = parse_import_names(names.as_str());
+            if names.is_empty() {
+                imports.push(module.as_str().to_string());
+            }
+            for name in names {
+                imports.push(format!("{}.{}", module.as_str(), name));
+            }
+        } else if let Some(names) = cap.get(3) {
+            imports.extend(parse_import_names(names.as_str()));
+        }
+    }
+
+    for cap in DYNAMIC_IMPORT_RE.captures_iter(content) {
+        imports.extend(parse_import_names(&cap[1]));
+    }
+
+    let mut unique: Vec<String> = Vec::new();
+    for import in imports {
+        if !unique.contains(&import) {
+            unique.push(import);
+        }
+    }
+
+    unique
+}
+
+/// 去掉 if TYPE_CHECKING: 块（按缩进判断块的范围），保留其他行
+pub fn strip_type_checking_blocks(content: &str) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut block_indent: Option<usize> = None;
+    
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        
+        if let Some(block) = block_indent {
+            // 块内的空行、注释和缩进更深的行都属于该块
+            if trimmed.is_empty() || trimmed.starts_with('#') || indent > block {
+                continue;
+            }
+            block_indent = None;
+        }
+        
+        if let Some(cap) = TYPE_CHECKING_RE.captures(line) {
+            let rest = cap[1].trim();
+            if rest.is_empty() || rest.starts_with('#') {
+                block_indent = Some(indent);
+            }
+            // 单行形式 if TYPE_CHECKING: import x 直接跳过
+            continue;
+        }
+        
+        result.push_str(line);
+        result.push('\n');
+    }
+    
+    result
+}
+
+/// 去掉带有 # pywand: ignore 注释的行；文件开头的注释中有 # pywand: ignore-file 时返回空字符串
+pub fn strip_ignored_imports(content: &str) -> String {
+    // 文件级指令只在第一行代码之前的注释中有效
+    let ignore_file = content
+        .lines()
+        .map(str::trim)
+        .take_while(|line| line.is_empty() || line.starts_with('#'))
+        .any(|line| IGNORE_DIRECTIVE_RE.captures(line).is_some_and(|cap| &cap[1] == "ignore-file"));
+    if ignore_file {
+        return String::new();
+    }
+    
+    let mut result = String::with_capacity(content.len());
+    for line in content.lines() {
+        let ignored = IGNORE_DIRECTIVE_RE.captures(line).is_some_and(|cap| &cap[1] == "ignore");
+        if !ignored {
+            result.push_str(line);
+        }
+        result.push('\n');
+    }
+    
+    result
+}
+
+/// 解析逗号分隔的导入名称列表（可带括号、别名和注释），返回完整的点号路径
+fn parse_import_names(names: &str) -> Vec<String> {
+    names
+        .lines()
+        .map(|line| line.split('#').next().unwrap_or(""))
+        .flat_map(|line| line.split(','))
+        .filter_map(|part| {
+            part.trim_matches(|c: char| c.is_whitespace() || c == '(' || c == ')' || c == '\\')
+                .split_whitespace()
+                .next()
+        })
+        .filter(|name| {
+            name.split('.').all(|segment| {
+                !segment.is_empty() && segment.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+            })
+        })
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// 将带点号的导入路径归约为依赖名：命中映射表时保留匹配的前缀，否则取顶层包名
+pub fn resolve_dotted_import(path: &str, mappings: &HashMap<String, String>) -> String {
+    mappings
+        .keys()
+        .filter(|prefix| prefix.contains('.'))
+        .filter(|prefix| path == prefix.as_str() || path.starts_with(&format!("{}.", prefix)))
+        .max_by_key(|prefix| prefix.len())
+        .map(|prefix| prefix.as_str())
+        .unwrap_or_else(|| import_root(path))
+        .to_string()
+}
+
+/// 源代码中导入的模块，点分导入按包名映射解析到对应的包，带有忽略指令的导入不计入
+pub fn source_modules(content: &str, include_type_checking: bool, package_mappings: &HashMap<String, String>) -> Vec<String> {
+    let content = strip_ignored_imports(content);
+    // 只用于类型检查的导入默认不算运行时依赖
+    let content = if include_type_checking {
+        content
+    } else {
+        strip_type_checking_blocks(&content)
+    };
+    extract_imports_from_source(&content)
+        .iter()
+        .map(|import| resolve_dotted_import(import, package_mappings))
+        .collect()
+}
+
+/// 判断导入是否只用于构建：项目根目录的setup.py导入的setuptools不是运行时依赖
+pub fn is_build_script_import(relative: &Path, module: &str) -> bool {
+    relative == Path::new("setup.py") && import_root(module) == "setuptools"
+}
+
+/// 获取导入路径的顶层包名
+pub fn import_root(path: &str) -> &str {
+    path.split('.').next().unwrap_or(path)
+}
+
+/// 根据扫描到的.py文件收集项目自身可以直接导入的顶层模块名
+///
+/// 只有位于源码根目录（扫描目录，以及src布局的src目录）中的模块才能直接导入：
+/// 根目录下的.py文件、包含__init__.py（或存根__init__.pyi）的包的最外层目录（mypkg/sub/x.py对应mypkg），
+/// 以及根目录下不含__init__.py的目录（PEP 420命名空间包）。
+/// 更深层目录中的文件名和目录名（如app/cache/redis.py中的redis）不会被当作本地模块，以免掩盖同名的第三方包
+pub fn local_module_names(root: &Path, python_files: &[String]) -> HashSet<String> {
+    let files: HashSet<&Path> = python_files.iter().map(Path::new).collect();
+    let is_package = |dir: &Path| {
+        files.contains(dir.join("__init__.py").as_path()) || files.contains(dir.join("__init__.pyi").as_path())
+    };
+    let src_root = root.join("src");
+    let is_source_root = |dir: &Path| dir == root || dir == src_root.as_path();
+    let mut names = HashSet::new();
+    
+    for file in python_files {
+        let path = Path::new(file);
+        let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        
+        // 沿着包含__init__.py的目录向上，找到最外层的包
+        let mut outermost_package = None;
+        let mut dir = path.parent();
+        while let Some(package) = dir.filter(|dir| is_package(dir)) {
+            outermost_package = package.file_name().and_then(|name| name.to_str());
+            dir = package.parent();
+        }
+        match outermost_package {
+            Some(package) => {
+                names.insert(package.to_string());
+            }
+            None if stem != "__init__" && dir.is_some_and(is_source_root) => {
+                names.insert(stem.to_string());
+            }
+            None => {}
+        }
+        
+        // 源码根目录下不是包的目录作为命名空间包
+        for source_root in [root, src_root.as_path()] {
+            let Some(relative) = path.parent().and_then(|parent| parent.strip_prefix(source_root).ok()) else {
+                continue;
+            };
+            if let Some(name) = relative.iter().next().and_then(|name| name.to_str()) {
+                if !is_package(&source_root.join(name)) {
+                    names.insert(name.to_string());
+                }
+            }
+        }
+    }
+    
+    names
+}
+
+/// Python的主版本和次版本，如(3, 11)
+type PythonVersion = (u32, u32);
+
+/// 只在部分Python版本中属于标准库的模块：(模块名, 加入版本, 移除版本)
+const VERSIONED_STDLIB: &[(&str, Option<PythonVersion>, Option<PythonVersion>)] = &[
+    ("graphlib", Some((3, 9)), None),
+    ("zoneinfo", Some((3, 9)), None),
+    ("tomllib", Some((3, 11)), None),
+    ("imp", None, Some((3, 12))),
+    ("distutils", None, Some((3, 12))),
+    ("asynchat", None, Some((3, 12))),
+    ("asyncore", None, Some((3, 12))),
+    ("smtpd", None, Some((3, 12))),
+    ("aifc", None, Some((3, 13))),
+    ("audioop", None, Some((3, 13))),
+    ("cgi", None, Some((3, 13))),
+    ("cgitb", None, Some((3, 13))),
+    ("chunk", None, Some((3, 13))),
+    ("crypt", None, Some((3, 13))),
+    ("imghdr", None, Some((3, 13))),
+    ("mailcap", None, Some((3, 13))),
+    ("nntplib", None, Some((3, 13))),
+    ("pipes", None, Some((3, 13))),
+    ("sndhdr", None, Some((3, 13))),
+    ("spwd", None, Some((3, 13))),
+    ("sunau", None, Some((3, 13))),
+    ("telnetlib", None, Some((3, 13))),
+    ("uu", None, Some((3, 13))),
+    ("xdrlib", None, Some((3, 13))),
+    ("lib2to3", None, Some((3, 13))),
+    ("msilib", None, Some((3, 13))),
+    ("nis", None, Some((3, 13))),
+    ("ossaudiodev", None, Some((3, 13))),
+];
+
+/// 已弃用的标准库模块：(模块名, 弃用版本, 替代方案)，移除的版本见VERSIONED_STDLIB
+const DEPRECATED_STDLIB: &[(&str, PythonVersion, &str)] = &[
+    ("imp", (3, 4), "importlib"),
+    ("distutils", (3, 10), "setuptools, packaging"),
+    ("asynchat", (3, 6), "asyncio"),
+    ("asyncore", (3, 6), "asyncio"),
+    ("smtpd", (3, 6), "aiosmtpd"),
+    ("aifc", (3, 11), "soundfile"),
+    ("audioop", (3, 11), "audioop-lts"),
+    ("cgi", (3, 11), "email.message, multipart"),
+    ("cgitb", (3, 11), "traceback"),
+    ("chunk", (3, 11), "struct"),
+    ("crypt", (3, 11), "hashlib, passlib"),
+    ("imghdr", (3, 11), "filetype, Pillow"),
+    ("mailcap", (3, 11), "mimetypes"),
+    ("nntplib", (3, 11), "pynntp"),
+    ("pipes", (3, 11), "subprocess, shlex"),
+    ("sndhdr", (3, 11), "filetype"),
+    ("spwd", (3, 11), "python-pam"),
+    ("sunau", (3, 11), "soundfile"),
+    ("telnetlib", (3, 11), "telnetlib3"),
+    ("uu", (3, 11), "base64, binascii"),
+    ("xdrlib", (3, 11), "struct"),
+    ("lib2to3", (3, 11), "libcst, parso"),
+    ("msilib", (3, 11), "WiX"),
+    ("nis", (3, 11), "subprocess (ypcat)"),
+    ("ossaudiodev", (3, 11), "sounddevice"),
+];
+
+/// 目标Python版本中已弃用或已移除的标准库模块
+#[derive(Debug, Clone, PartialEq)]
+pub struct StdlibDeprecation {
+    pub deprecated: String, // 开始弃用的版本，如"3.11"
+    pub removal: String, // 移除（或计划移除）的版本，如"3.13"
+    pub removed: bool, // 目标版本中是否已经移除
+    pub replacement: &'static str, // 建议的替代模块或包
+}
+
+/// 检查模块在目标Python版本中是否已弃用或已移除，版本号无法解析时返回None
+pub fn stdlib_deprecation(module: &str, python_version: &str) -> Option<StdlibDeprecation> {
+    let version = parse_python_version(python_version)?;
+    let &(_, deprecated, replacement) = DEPRECATED_STDLIB
+        .iter()
+        .find(|(name, deprecated, _)| *name == module && version >= *deprecated)?;
+    let removal = VERSIONED_STDLIB
+        .iter()
+        .find(|(name, _, _)| *name == module)
+        .and_then(|(_, _, removed)| *removed)?;
+    let format_version = |(major, minor): PythonVersion| format!("{}.{}", major, minor);
+    
+    Some(StdlibDeprecation {
+        deprecated: format_version(deprecated),
+        removal: format_version(removal),
+        removed: version >= removal,
+        replacement,
+    })
+}
+
+/// 解析Python版本号的主版本和次版本，如"3.11.7" -> (3, 11)
+fn parse_python_version(version: &str) -> Option<PythonVersion> {
+    let mut parts = version.trim().split('.');
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    Some((major, minor))
+}
+
+/// 检查模块是否是Python标准库的一部分
+///
+/// 指定目标版本时按该版本判断；未指定时，只要在任一版本中属于标准库即视为标准库
+pub fn is_standard_library(module: &str, python_version: Option<&str>) -> bool {
+    if let Some((_, added, removed)) = VERSIONED_STDLIB.iter().find(|(name, _, _)| *name == module) {
+        return match python_version.and_then(parse_python_version) {
+            Some(version) => {
+                added.is_none_or(|added| version >= added) && removed.is_none_or(|removed| version < removed)
+            }
+            None => true,
+        };
+    }
+    
+    // 扩展的Python标准库列表
+    let std_libs = [
+        "os", "sys", "re", "math", "json", "time", "datetime", "random", 
+        "collections", "itertools", "functools", "pathlib", "subprocess",
+        "typing", "abc", "argparse", "enum", "logging", "io", "csv",
+        "__future__", "site", "threading", "importlib", "runpy", 
+        "asyncio", "base64", "calendar", "concurrent", "contextlib", "copy", "dataclasses",
+        "decimal", "difflib", "email", "hashlib", "html", "http", "inspect",
+        "ipaddress", "multiprocessing", "operator", "platform", "pprint",
+        "queue", "shutil", "signal", "socket", "sqlite3", "ssl", "statistics",
+        "string", "struct", "tempfile", "textwrap", "unittest", "urllib",
+        "uuid", "warnings", "xml", "zipfile", "zlib", "builtins", "codecs",
+        "traceback", "pickle", "gzip", "array", "bisect", "configparser", 
+        "context", "ctypes", "fnmatch", "fractions", "ftplib",
+        "getpass", "gettext", "glob", "heapq", "keyword", "marshal",
+        "mimetypes", "numbers", "optparse", "posixpath", "profile", "pwd",
+        "shelve", "smtplib", "symtable", "sysconfig", "tarfile",
+        "token", "turtle", "weakref", "winreg"
+    ];
+    
+    std_libs.contains(&module)
+}
+
+/// 将模块名称转换为正确的PyPI包名或过滤掉无效的包名
+pub fn normalize_package_name(
+    module: &str,
+    python_version: Option<&str>,
+    mappings: &HashMap<String, String>,
+) -> Option<String> {
+    // 返回已知映射的包名
+    if let Some(package) = mappings.get(module) {
+        return Some(package.clone());
+    }
+    
+    // 检查是否是无效的包名（单个字符、下划线开头等）
+    if module.len() <= 1 || module.starts_with('_') || is_standard_library(module, python_version) ||
+       ["name", "the", "header", "REPL", "code", "types", "stat", "line", "inline", 
+        "another", "all", "values", "its", "regular", "each", "within", "working", 
+        "source", "on", "what", "an", "multiple", "being", "that", "this", "inside", 
+        "one", "floats", "those", "limited_api1", "limited_api_latest", "limited_api2", 
+        "array_interface_testing", "mem_policy", "checks", "1", "0", "left", "lowest", 
+        "pairs", "t2", "it", "outside", "running"].contains(&module) {
+        return None;
+    }
+    
+    // 由多个包提供的导入名没有映射时无法确定包名
+    if ambiguous_import_candidates(module).is_some() {
+        return None;
+    }
+    
+    // 返回原始模块名
+    Some(module.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// 在临时目录中创建文件，路径中的目录会自动创建
+    fn write_file(root: &Path, relative: &str, content: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    
+    #[test]
+    fn comma_separated_imports_yield_every_module() {
+        assert_eq!(extract_imports_from_source("import numpy, pandas as pd, requests\n"), vec!["numpy", "pandas", "requests"]);
+        assert_eq!(parse_import_names("(os.path as p,  # 注释\n    yaml)"), vec!["os.path", "yaml"]);
+    }
+    
+    #[test]
+    fn setup_py_setuptools_is_not_a_dependency() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "setup.py", "from setuptools import setup, find_packages\nimport numpy\n");
+        write_file(dir.path(), "tools/build.py", "import setuptools\n");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        // 只有根目录的setup.py是构建脚本
+        assert_eq!(result.dependencies, vec!["numpy", "setuptools"]);
+        assert_eq!(result.module_sources["setuptools"], vec!["tools/build.py"]);
+    }
+    
+    #[test]
+    fn oversized_files_are_skipped_or_read_from_the_head() {
+        let dir = tempfile::tempdir().unwrap();
+        let generated = format!("import yaml\n{}import toml\n", "x = 1\n".repeat(100));
+        write_file(dir.path(), "app.py", "import requests\n");
+        write_file(dir.path(), "generated.py", &generated);
+        
+        let config = ScanConfig { max_file_size: 100, ..ScanConfig::default() };
+        let result = scan_directory(dir.path(), &config).unwrap();
+        assert_eq!(result.dependencies, vec!["requests"]);
+        assert_eq!(result.oversized.len(), 1);
+        assert!(result.oversized[0].ends_with("generated.py"));
+        
+        // 只读取开头的完整行，文件末尾的导入不会被分析
+        let config = ScanConfig { max_file_size: 100, oversized_head: 20, ..ScanConfig::default() };
+        let result = scan_directory(dir.path(), &config).unwrap();
+        assert_eq!(result.dependencies, vec!["PyYAML", "requests"]);
+        assert_eq!(result.oversized.len(), 1);
+        
+        let content = read_source_from(generated.as_bytes(), generated.len() as u64, &config).unwrap();
+        assert_eq!(content, SourceContent::Head("import yaml\nx = 1\n".to_string()));
+    }
+    
+    #[test]
+    fn truncated_dirs_only_count_dirs_with_unscanned_content() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "app.py", "import requests\n");
+        write_file(dir.path(), "deep/module.py", "import yaml\n");
+        write_file(dir.path(), "nested/inner/module.py", "import toml\n");
+        write_file(dir.path(), "docs/README.md", "说明\n");
+        fs::create_dir(dir.path().join("empty")).unwrap();
+        
+        let config = ScanConfig { max_depth: 1, ..ScanConfig::default() };
+        let (files, truncated_dirs) = find_python_files(dir.path(), &config).unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(truncated_dirs, 2);
+    }
+    
+    #[test]
+    fn local_modules_are_not_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "main.py", "import helpers\nimport requests\n");
+        write_file(dir.path(), "helpers.py", "def greet():\n    pass\n");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["requests"]);
+        assert_eq!(result.local, vec!["helpers"]);
+    }
+    
+    #[test]
+    fn nested_module_does_not_hide_package() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "main.py", "import redis\nfrom app.cache import redis as cache\n");
+        write_file(dir.path(), "app/cache/redis.py", "import redis\n");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["redis"]);
+        assert_eq!(result.local, vec!["app"]);
+    }
+    
+    #[test]
+    fn package_directories_are_local() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "main.py", "from mypkg import thing\nfrom mypkg.sub import helper\nimport requests\n");
+        write_file(dir.path(), "mypkg/__init__.py", "thing = 1\n");
+        write_file(dir.path(), "mypkg/sub/__init__.py", "");
+        write_file(dir.path(), "mypkg/sub/helper.py", "import json\n");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["requests"]);
+        assert_eq!(result.local, vec!["mypkg"]);
+    }
+    
+    #[test]
+    fn module_sources_are_relative_to_scan_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "main.py", "import requests\n");
+        write_file(dir.path(), "app/client.py", "import requests\nimport os.path\n");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.modules, vec!["os", "requests"]);
+        let mut sources = result.module_sources["requests"].clone();
+        sources.sort();
+        assert_eq!(sources, vec!["app/client.py", "main.py"]);
+    }
+    
+    #[test]
+    fn nested_names_are_not_local() {
+        let dir = tempfile::tempdir().unwrap();
+        let files: Vec<String> = ["main.py", "app/cache/redis.py", "examples/yaml/demo.py", "lib/__init__.py", "lib/tools/cli.py"]
+            .iter()
+            .map(|file| dir.path().join(file).display().to_string())
+            .collect();
+        
+        let names = local_module_names(dir.path(), &files);
+        let mut names: Vec<&str> = names.iter().map(String::as_str).collect();
+        names.sort();
+        assert_eq!(names, vec!["app", "examples", "lib", "main"]);
+    }
+    
+    #[test]
+    fn dynamic_imports_are_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "plugins.py", "import importlib\n\ncache = importlib.import_module(\"redis\")\nworker = __import__('celery.app')\nloader = importlib.import_module(\"json\")\n");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["celery", "redis"]);
+        assert_eq!(result.stdlib, vec!["importlib", "json"]);
+    }
+    
+    #[test]
+    fn dotted_imports_map_to_distribution_names() {
+        let mappings = default_package_mappings();
+        let package = |import: &str| normalize_package_name(&resolve_dotted_import(import, &mappings), None, &mappings);
+        assert_eq!(package("google.cloud.storage").as_deref(), Some("google-cloud-storage"));
+        assert_eq!(package("google.cloud.storage.blob").as_deref(), Some("google-cloud-storage"));
+        assert_eq!(package("azure.storage.blob.BlobClient").as_deref(), Some("azure-storage-blob"));
+        assert_eq!(package("matplotlib.pyplot").as_deref(), Some("matplotlib"));
+        assert_eq!(package("concurrent.futures"), None);
+        
+        // from google.cloud import storage 按被导入的名称匹配命名空间包
+        let modules = source_modules("from google.cloud import storage\n", false, &mappings);
+        assert_eq!(modules, vec!["google.cloud.storage"]);
+    }
+    
+    #[test]
+    fn standard_library_depends_on_python_version() {
+        assert!(is_standard_library("tomllib", Some("3.11")));
+        assert!(is_standard_library("tomllib", Some("3.12.1")));
+        assert!(!is_standard_library("tomllib", Some("3.8")));
+        assert!(is_standard_library("imp", Some("3.11")));
+        assert!(!is_standard_library("imp", Some("3.12")));
+        // 未指定版本时，任一版本中的标准库模块都不算依赖
+        assert!(is_standard_library("tomllib", None));
+        
+        let mappings = default_package_mappings();
+        assert_eq!(normalize_package_name("tomllib", Some("3.8"), &mappings).as_deref(), Some("tomllib"));
+        assert_eq!(normalize_package_name("tomllib", Some("3.11"), &mappings), None);
+    }
+    
+    #[test]
+    fn deeply_indented_imports_are_detected() {
+        let source = "\
+class Serializer:
+    def dumps(self, data):
+        try:
+            import ujson as json
+        except ImportError:
+            import json
+        return json.dumps(data)
+    
+    def load_table(self, path):
+        if path.endswith(\".xlsx\"):
+            if path.startswith(\"remote://\"):
+                from openpyxl import load_workbook
+                return load_workbook(path)
+
+try: import orjson
+except ImportError: orjson = None
+";
+        let imports = extract_imports_from_source(source);
+        assert_eq!(imports, vec!["ujson", "json", "openpyxl.load_workbook", "orjson"]);
+        
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "serializer.py", source);
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["openpyxl", "orjson", "ujson"]);
+    }
+    
+    #[test]
+    fn type_checking_imports_are_excluded_by_default() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "client.py", "\
+from typing import TYPE_CHECKING
+import requests
+
+if TYPE_CHECKING:
+    from httpx import Client
+    
+    import boto3
+if typing.TYPE_CHECKING: import mypy_boto3_s3
+
+def make():
+    import yaml
+");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["PyYAML", "requests"]);
+        
+        let config = ScanConfig { include_type_checking: true, ..ScanConfig::default() };
+        let result = scan_directory(dir.path(), &config).unwrap();
+        assert_eq!(result.dependencies, vec!["boto3", "httpx", "mypy_boto3_s3", "PyYAML", "requests"]);
+    }
+    
+    #[test]
+    fn relative_imports_are_not_dependencies() {
+        let source = "from . import a\nfrom .sub import b\nfrom ..pkg import c\nfrom .. import (d,\n    e)\nimport requests\n";
+        assert_eq!(extract_imports_from_source(source), vec!["requests"]);
+        
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "app/settings/defaults.py", source);
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["requests"]);
+        assert!(result.unresolved.is_empty());
+    }
+    
+    #[test]
+    fn excluded_globs_are_not_scanned() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "app.py", "import requests\n");
+        write_file(dir.path(), "vendor/bundled/helper.py", "import vendored_only_pkg\n");
+        write_file(dir.path(), "examples/demo.py", "import matplotlib\n");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["matplotlib", "requests", "vendored_only_pkg"]);
+        
+        let config = ScanConfig { exclude: vec!["vendor/**".to_string(), "examples/*.py".to_string()], ..ScanConfig::default() };
+        let result = scan_directory(dir.path(), &config).unwrap();
+        assert_eq!(result.dependencies, vec!["requests"]);
+        assert_eq!(result.files.len(), 1);
+    }
+    
+    #[test]
+    fn ignore_directives_skip_imports() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "app.py", "import requests\nimport internal_tool  # pywand: ignore\nimport yaml  # noqa\n");
+        write_file(dir.path(), "legacy.py", "#!/usr/bin/env python\n# pywand: ignore-file\nimport old_sdk\n");
+        // 代码之后的ignore-file不是文件级指令
+        write_file(dir.path(), "late.py", "import toml\n# pywand: ignore-file\n");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["PyYAML", "requests", "toml"]);
+    }
+    
+    #[test]
+    fn stub_files_are_scanned_only_when_enabled() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "client.py", "import requests\n");
+        write_file(dir.path(), "client.pyi", "import requests\nfrom attrs import define\n");
+        
+        let result = scan_directory(dir.path(), &ScanConfig::default()).unwrap();
+        assert_eq!(result.dependencies, vec!["requests"]);
+        assert_eq!(result.files.len(), 1);
+        
+        let config = ScanConfig { include_stubs: true, ..ScanConfig::default() };
+        let result = scan_directory(dir.path(), &config).unwrap();
+        assert_eq!(result.dependencies, vec!["attrs", "requests"]);
+        assert_eq!(result.files.len(), 2);
+    }
+}
@@ -0,0 +1,106 @@
+//! Ctrl-C处理：中断时终止正在运行的子进程，删除未完成的输出后退出
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+use std::sync::{Mutex, MutexGuard};
+
+use lazy_static::lazy_static;
+use log::debug;
+
+/// 被中断时的退出码（128 + SIGINT）
+pub const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// 当前正在进行的操作
+#[derive(Default)]
+struct InProgress {
+    children: Vec<u32>, // 正在运行的子进程ID
+    artifacts: Vec<PathBuf>, // 尚未完成的输出文件或目录
+}
+
+lazy_static! {
+    static ref IN_PROGRESS: Mutex<InProgress> = Mutex::new(InProgress::default());
+}
+
+/// 获取登记表，持有锁的线程panic后仍可继续使用
+fn in_progress() -> MutexGuard<'static, InProgress> {
+    IN_PROGRESS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// 安装Ctrl-C处理程序，`message`为中断时显示的提示，`removed_message`为删除未完成的输出后的提示（{}替换为路径）；重复安装时忽略
+pub fn install_handler(message: String, removed_message: String) {
+    let result = ctrlc::set_handler(move || {
+        eprintln!("\n{}", message);
+        cleanup(&removed_message);
+        process::exit(INTERRUPTED_EXIT_CODE);
+    });
+    if let Err(e) = result {
+        debug!("无法安装Ctrl-C处理程序: {}", e);
+    }
+}
+
+/// 终止登记的子进程，按登记的相反顺序删除未完成的输出
+fn cleanup(removed_message: &str) {
+    let state = in_progress();
+    for pid in &state.children {
+        kill_process(*pid);
+    }
+    for path in state.artifacts.iter().rev() {
+        let result = if path.is_dir() {
+            fs::remove_dir_all(path)
+        } else {
+            fs::remove_file(path)
+        };
+        match result {
+            Ok(()) => eprintln!("{}", removed_message.replace("{}", &path.display().to_string())),
+            Err(e) => debug!("无法删除{}: {}", path.display(), e),
+        }
+    }
+}
+
+/// 终止子进程（Windows上同时终止它启动的进程），进程可能已经退出，忽略失败
+fn kill_process(pid: u32) {
+    let pid = pid.to_string();
+    let status = if cfg!(windows) {
+        Command::new("taskkill").args(["/PID", &pid, "/T", "/F"]).status()
+    } else {
+        Command::new("kill").args(["-TERM", &pid]).status()
+    };
+    if let Err(e) = status {
+        debug!("无法终止进程{}: {}", pid, e);
+    }
+}
+
+/// 登记的子进程，离开作用域时取消登记
+pub struct ChildGuard(u32);
+
+/// 登记正在运行的子进程，中断时终止它
+pub fn track_child(pid: u32) -> ChildGuard {
+    in_progress().children.push(pid);
+    ChildGuard(pid)
+}
+
+impl Drop for ChildGuard {
+    fn drop(&mut self) {
+        in_progress().children.retain(|pid| *pid != self.0);
+    }
+}
+
+/// 登记的未完成输出，离开作用域时取消登记（输出保留）
+pub struct ArtifactGuard(PathBuf);
+
+/// 登记正在写入的文件或目录，在离开作用域前被中断时删除它
+pub fn track_artifact(path: impl AsRef<Path>) -> ArtifactGuard {
+    let path = path.as_ref().to_path_buf();
+    in_progress().artifacts.push(path.clone());
+    ArtifactGuard(path)
+}
+
+impl Drop for ArtifactGuard {
+    fn drop(&mut self) {
+        let mut state = in_progress();
+        if let Some(index) = state.artifacts.iter().rposition(|path| *path == self.0) {
+            state.artifacts.remove(index);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! PyWand的依赖分析库：扫描Python项目中的导入语句，推断需要安装的PyPI包
+//!
+//! 命令行工具pywand基于这个库实现，编辑器插件和其他工具也可以直接使用：
+//!
+//! ```
+//! let imports = pywand::extract_imports_from_source("import requests\nfrom os import path");
+//! assert_eq!(imports, vec!["requests", "os.path"]);
+//!
+//! let mappings = pywand::default_package_mappings();
+//! assert_eq!(pywand::normalize_package_name("yaml", None, &mappings).as_deref(), Some("PyYAML"));
+//! assert!(pywand::is_standard_library("json", None));
+//! ```
+
+pub mod imports;
+
+pub use imports::{
+    ScanConfig, ScanResult, SourceContent, default_package_mappings, extract_imports_from_source,
+    is_standard_library, normalize_package_name, read_source, scan_directory, scan_directory_with_progress,
+};
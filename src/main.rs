@@ -1,32 +1,97 @@
 mod uv_tools;
 mod i18n;
+mod declared;
+mod pypi;
+mod error;
+mod interrupt;
+mod archive;
+mod progress;
+mod version;
 
 use std::path::Path;
+use std::env;
 use std::fs;
-use std::process::Command;
+use std::process::{Command, ExitCode};
 use std::path::PathBuf;
-use std::env;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::{IsTerminal, Read, Write};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result, anyhow};
-use clap::{Parser, Subcommand};
-use dialoguer::{Select, theme::ColorfulTheme};
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use clap::builder::FalseyValueParser;
+use dialoguer::{Confirm, MultiSelect, Select, theme::ColorfulTheme};
 use console::style;
 use walkdir::WalkDir;
+use globset::GlobSet;
 use regex::Regex;
-use indicatif::{ProgressBar, ProgressStyle};
 use tempfile::tempdir;
 use flate2::write::GzEncoder;
 use flate2::Compression;
 use tar::Builder;
+use zip::{CompressionMethod, ZipWriter};
+use zip::write::SimpleFileOptions;
+use lazy_static::lazy_static;
+use log::{debug, info, warn, Level, LevelFilter};
+use sha2::{Digest, Sha256};
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
 
-use crate::uv_tools::UvManager;
+use crate::uv_tools::{UvManager, UvSource, command_line, parse_checksum_file, redact_url_credentials, venv_python_path, verify_uv_checksum};
 use crate::i18n::{I18n, Language};
+use crate::declared::{DeclaredRequirement, GENERATED_MARKER, is_generated_requirements, parse_declared_dependencies, parse_declared_python_version, parse_requirements_txt, read_requirements_file, requirement_name};
+use crate::pypi::{PackageStatus, PackageVerifier, PypiIndex};
+use crate::version::{version_parts, version_satisfies};
+use crate::error::{PyWandError, exit_code};
+use crate::archive::read_archive_sources;
+use crate::progress::{ProgressEvent, ProgressMode};
+
+use pywand::imports::{
+    DEFAULT_EXCLUDED_DIRS, DEFAULT_MAX_DEPTH, DEFAULT_MAX_FILE_SIZE, ScanConfig, ambiguous_import_candidates, build_glob_set, default_package_mappings,
+    find_python_files, import_root, is_build_script_import, is_scanned_entry, is_standard_library, local_module_names,
+    normalize_package_name, scan_directory_with_progress, source_modules, stdlib_deprecation,
+};
+
+/// 默认的虚拟环境目录
+const DEFAULT_VENV_DIR: &str = ".venv";
+
+/// 带版本和哈希的锁定文件名
+const LOCK_FILE_NAME: &str = "requirements.lock";
+
+/// 导出包中记录各文件SHA256的清单文件名
+const MANIFEST_FILE_NAME: &str = "MANIFEST.sha256";
+
+/// 项目配置文件路径，相对于当前目录
+const CONFIG_FILE_PATH: &str = ".pywand/config.toml";
 
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    
+    /// 只显示将要执行的操作，不创建环境、不安装包、不写入文件
+    #[arg(long, global = true)]
+    dry_run: bool,
+    
+    /// 显示更详细的诊断信息，-vv会显示每条uv命令和扫描到的每个文件
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+    
+    /// 安静模式：不显示进度条、使用提示和警告
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    
+    /// 命令完成后不显示使用提示（也可以设置PYWAND_NO_TIPS环境变量）
+    #[arg(long, global = true, env = "PYWAND_NO_TIPS", value_parser = FalseyValueParser::new())]
+    no_tips: bool,
+    
+    /// 使用哪个UV：auto优先使用系统安装的UV，system只使用系统PATH中的UV，embedded只使用内置的UV（也可以设置PYWAND_UV_SOURCE环境变量）
+    #[arg(long, global = true, value_enum, env = "PYWAND_UV_SOURCE", default_value_t = UvSource::Auto)]
+    uv_source: UvSource,
+    
+    /// 进度的显示方式：bar显示进度条，json不显示进度条，改为向stderr逐行输出JSON事件（scan_start、file、dep_found、done等）
+    #[arg(long, global = true, value_enum, default_value_t = ProgressMode::Bar)]
+    progress: ProgressMode,
 }
 
 #[derive(Subcommand)]
@@ -35,27 +100,175 @@ enum Commands {
     Analyze {
         #[arg(short, long)]
         path: Option<String>,
+        
+        /// 以JSON格式输出分析结果，不显示进度条和其他提示
+        #[arg(long)]
+        json: bool,
+        
+        /// 从标准输入读取单个脚本的源代码进行分析，不扫描目录（如 cat app.py | pywand analyze --from-stdin）
+        #[arg(long, conflicts_with = "path")]
+        from_stdin: bool,
+        
+        /// 直接分析.zip或.tar.gz归档中的项目，不解压到磁盘（如 pywand analyze --archive project.zip）
+        #[arg(long, conflicts_with_all = ["path", "from_stdin"])]
+        archive: Option<String>,
+        
+        /// 存在无法对应到PyPI包名的导入时报错退出，用于CI检查包名映射是否完整
+        #[arg(long)]
+        strict: bool,
+        
+        #[command(flatten)]
+        scan: ScanOptions,
+    },
+    /// 对比导入推断出的依赖与requirements.txt，存在未声明的依赖时返回非零退出码
+    Diff {
+        #[arg(short, long, default_value = ".")]
+        path: String,
+        
+        /// 要对比的依赖文件，默认为扫描目录下的requirements.txt
+        #[arg(short, long)]
+        requirements: Option<String>,
+        
+        #[command(flatten)]
+        scan: ScanOptions,
     },
     /// 使用测试套件样本运行
     Test {
         #[arg(short, long, default_value = "test-suite")]
         path: String,
+        
+        #[command(flatten)]
+        scan: ScanOptions,
     },
     /// 直接执行本地开发流程
     LocalDev {
         #[arg(short, long, default_value = ".")]
         path: String,
+        
+        /// 虚拟环境目录
+        #[arg(long, default_value = DEFAULT_VENV_DIR)]
+        venv: String,
+        
+        /// Python版本，指定后不再读取项目声明或交互式询问
+        #[arg(long)]
+        python_version: Option<String>,
+        
+        /// 使用已有的Python解释器创建虚拟环境，不再选择版本，也不由uv下载Python
+        #[arg(long, value_name = "PATH", conflicts_with = "python_version")]
+        python_path: Option<PathBuf>,
+        
+        #[command(flatten)]
+        index: IndexOptions,
+        
+        /// 依赖安装失败时自动删除本次新建的虚拟环境，不再询问
+        #[arg(long)]
+        clean_on_failure: bool,
+        
+        /// 覆盖不是PyWand生成的requirements.txt时不再询问，原文件备份为requirements.txt.bak
+        #[arg(long)]
+        overwrite: bool,
+        
+        #[command(flatten)]
+        scan: ScanOptions,
+        
+        #[command(flatten)]
+        resolve: ResolveOptions,
     },
     /// 直接生成requirements.txt文件
     GenReq {
         #[arg(short, long, default_value = ".")]
         path: String,
         
+        /// 输出目录，或以.txt结尾的requirements文件路径（如 requirements-dev.txt），不存在的目录会自动创建
         #[arg(short, long, default_value = ".")]
         output: String,
+        
+        /// 输出格式：requirements（requirements.txt）或 pyproject（pyproject.toml）
+        #[arg(short, long, value_enum, alias = "output-format", default_value_t = OutputFormat::Requirements)]
+        format: OutputFormat,
+        
+        /// 在requirements.txt中为每个依赖注释引用它的源文件
+        #[arg(long)]
+        annotate: bool,
+        
+        /// 通过PyPI查询每个推断出的包是否存在，移除不存在的包名
+        #[arg(long)]
+        verify_pypi: bool,
+        
+        /// 写入前逐个确认依赖，取消勾选的包不会写入
+        #[arg(long)]
+        review: bool,
+        
+        /// 覆盖不是PyWand生成的requirements.txt时不再询问，原文件备份为.bak文件
+        #[arg(long)]
+        overwrite: bool,
+        
+        /// 只在测试代码（tests/、test/、conftest.py、test_*.py、*_test.py）中导入的包写入同目录下的requirements-dev.txt
+        #[arg(long)]
+        split_dev: bool,
+        
+        /// 覆盖已有的requirements.txt后显示新增和删除的行（在终端中运行时总会显示）
+        #[arg(long)]
+        show_diff: bool,
+        
+        /// 列出每个依赖的来源：inferred（从导入推断）、mapping（通过包名映射推断）、declared:<文件>（依赖文件中声明）
+        #[arg(long)]
+        explain: bool,
+        
+        /// requirements.txt第一行只写生成标记，不写PyWand版本和生成日期，便于按内容对比
+        #[arg(long)]
+        no_header: bool,
+        
+        /// 存在无法对应到PyPI包名的导入时报错退出，不写入依赖文件
+        #[arg(long)]
+        strict: bool,
+        
+        #[command(flatten)]
+        scan: ScanOptions,
+        
+        #[command(flatten)]
+        resolve: ResolveOptions,
+    },
+    /// 解析依赖并生成带版本和哈希的requirements.lock
+    Lock {
+        /// 项目目录，读取其中的requirements.txt（不存在时扫描生成）
+        #[arg(short, long, default_value = ".")]
+        path: String,
+        
+        /// 锁定文件路径，默认为项目目录下的requirements.lock
+        #[arg(short, long)]
+        output: Option<String>,
+        
+        /// 解析依赖时使用的Python版本
+        #[arg(long)]
+        python_version: Option<String>,
+        
+        #[command(flatten)]
+        index: IndexOptions,
+        
+        #[command(flatten)]
+        scan: ScanOptions,
+        
+        #[command(flatten)]
+        resolve: ResolveOptions,
     },
     /// 运行Python脚本
     Run {
+        /// 虚拟环境目录
+        #[arg(long, default_value = DEFAULT_VENV_DIR)]
+        venv: String,
+        
+        /// Python版本，指定后不再读取项目声明或交互式询问
+        #[arg(long)]
+        python_version: Option<String>,
+        
+        /// 使用已有的Python解释器创建虚拟环境，不再选择版本，也不由uv下载Python
+        #[arg(long, value_name = "PATH", conflicts_with = "python_version")]
+        python_path: Option<PathBuf>,
+        
+        #[command(flatten)]
+        index: IndexOptions,
+        
         /// Python脚本路径
         script: String,
         
@@ -69,26 +282,442 @@ enum Commands {
         #[arg(trailing_var_arg = true)]
         args: Vec<String>,
     },
+    /// 显示PyWand、uv、平台和语言等版本信息，便于报告问题
+    Version,
+    /// 显示当前使用的uv版本
+    UvVersion,
+    /// 下载最新的uv，替换缓存的版本
+    UpdateUv,
     /// 安装Python包
     Pip {
+        /// 虚拟环境目录
+        #[arg(long, default_value = DEFAULT_VENV_DIR)]
+        venv: String,
+        
+        /// Python版本，指定后不再读取项目声明或交互式询问
+        #[arg(long)]
+        python_version: Option<String>,
+        
+        /// 使用已有的Python解释器创建虚拟环境，不再选择版本，也不由uv下载Python
+        #[arg(long, value_name = "PATH", conflicts_with = "python_version")]
+        python_path: Option<PathBuf>,
+        
+        #[command(flatten)]
+        index: IndexOptions,
+        
+        /// 从依赖文件安装，可以与包名同时使用
+        #[arg(short, long)]
+        requirements: Option<String>,
+        
         /// 要安装的包名
         #[arg(trailing_var_arg = true)]
         packages: Vec<String>,
     },
+    /// 安装包到虚拟环境，并以安装的版本追加到requirements.txt
+    Add {
+        /// 虚拟环境目录
+        #[arg(long, default_value = DEFAULT_VENV_DIR)]
+        venv: String,
+        
+        /// Python版本，指定后不再读取项目声明或交互式询问
+        #[arg(long)]
+        python_version: Option<String>,
+        
+        /// 使用已有的Python解释器创建虚拟环境，不再选择版本，也不由uv下载Python
+        #[arg(long, value_name = "PATH", conflicts_with = "python_version")]
+        python_path: Option<PathBuf>,
+        
+        #[command(flatten)]
+        index: IndexOptions,
+        
+        /// 要更新的依赖文件
+        #[arg(short, long, default_value = "requirements.txt")]
+        requirements: String,
+        
+        /// 要添加的包，可以带版本约束或extras（如 requests>=2.0、uvicorn[standard]）
+        #[arg(required = true)]
+        packages: Vec<String>,
+    },
+    /// 从虚拟环境中卸载包，并从requirements.txt中删除对应的行
+    Remove {
+        /// 扫描导入的项目目录，用于检查要删除的包是否仍被导入
+        #[arg(short, long, default_value = ".")]
+        path: String,
+        
+        /// 虚拟环境目录
+        #[arg(long, default_value = DEFAULT_VENV_DIR)]
+        venv: String,
+        
+        /// 要更新的依赖文件
+        #[arg(short, long, default_value = "requirements.txt")]
+        requirements: String,
+        
+        /// 要删除的包名（不区分大小写，Flask和flask视为同一个包）
+        #[arg(required = true)]
+        packages: Vec<String>,
+        
+        #[command(flatten)]
+        scan: ScanOptions,
+    },
+    /// 导出用于离线开发的项目包
+    Export {
+        #[arg(short, long, default_value = ".")]
+        path: String,
+        
+        /// 归档保存路径：已存在的目录或归档文件名；批量导出时为保存所有归档的目录
+        #[arg(short, long)]
+        output: Option<String>,
+        
+        /// 覆盖已存在的归档文件
+        #[arg(long)]
+        force: bool,
+        
+        /// 归档格式，默认Windows目标使用zip，其他平台使用tar.gz
+        #[arg(long, value_enum)]
+        archive_format: Option<ArchiveFormat>,
+        
+        /// 压缩级别：0（最快）到9（最小），默认6
+        #[arg(long, value_parser = clap::value_parser!(u32).range(0..=9), conflicts_with = "no_compression")]
+        compression: Option<u32>,
+        
+        /// 不压缩：tar.gz改为生成.tar，zip中的文件只存储不压缩
+        #[arg(long)]
+        no_compression: bool,
+        
+        /// 目标操作系统（如windows10、linux、macos），指定后不再交互式询问
+        #[arg(long)]
+        os: Option<String>,
+        
+        /// 目标架构：x86、x64或arm64
+        #[arg(long, requires = "os", default_value = "x64")]
+        arch: String,
+        
+        /// 目标Python版本，默认使用目标平台支持的第一个版本
+        #[arg(long, requires = "os")]
+        python_version: Option<String>,
+        
+        /// 批量导出的目标平台，格式为 os:arch[:Python版本]（如 linux:x64:3.11.7），可多次指定，每个平台生成一个归档
+        #[arg(long, value_name = "OS:ARCH[:VERSION]", conflicts_with_all = ["os", "keep_export_dir"])]
+        target: Vec<String>,
+        
+        /// 为所有支持的平台各生成一个归档（使用各平台的默认Python版本）
+        #[arg(long, conflicts_with_all = ["os", "target", "keep_export_dir"])]
+        all_platforms: bool,
+        
+        /// 打包前不再确认导出内容的大小
+        #[arg(short, long)]
+        yes: bool,
+        
+        /// 一并导出匹配的非Python文件（相对于项目目录的glob，如 **/*.json），可多次指定
+        #[arg(long, value_name = "GLOB")]
+        include_data: Vec<String>,
+        
+        /// 额外生成conda使用的environment.yml
+        #[arg(long)]
+        export_conda: bool,
+        
+        /// 把依赖的wheel文件下载到导出包的wheels/目录，设置脚本从中离线安装，目标机器无需联网
+        #[arg(long)]
+        bundle_wheels: bool,
+        
+        /// 在指定目录（不存在或为空）中准备导出内容，打包后保留，便于检查生成的文件
+        #[arg(long, value_name = "PATH")]
+        keep_export_dir: Option<String>,
+        
+        #[command(flatten)]
+        scan: ScanOptions,
+        
+        #[command(flatten)]
+        resolve: ResolveOptions,
+    },
+    /// 初始化PyWand项目：创建项目配置文件和带生成标记的requirements.txt
+    Init {
+        #[arg(short, long, default_value = ".")]
+        path: String,
+        
+        /// 项目首选的Python版本，未指定时交互式选择（非交互式运行时不写入）
+        #[arg(long)]
+        python_version: Option<String>,
+        
+        /// 项目使用的界面语言代码，未指定时交互式选择（非交互式运行时使用当前语言）
+        #[arg(long, value_name = "CODE")]
+        lang: Option<String>,
+        
+        /// 同时创建示例main.py
+        #[arg(long)]
+        sample: bool,
+        
+        /// 覆盖已存在的配置文件
+        #[arg(long)]
+        force: bool,
+    },
     /// 设置界面语言
     Lang {
-        /// 语言代码：en, zh, ja, ko, fr, de, ru
-        #[arg(short, long)]
-        code: String,
+        /// 语言代码：en, zh, ja, ko, fr, de, ru, es, pt, it
+        #[arg(short, long, required_unless_present = "list")]
+        code: Option<String>,
+        
+        /// 列出可用语言并标记当前语言
+        #[arg(short, long, conflicts_with = "code")]
+        list: bool,
     },
 }
 
+/// 扫描Python文件的选项，供各个需要扫描项目的子命令共用
+#[derive(Args, Clone)]
+struct ScanOptions {
+    /// 扫描目录的最大深度，0表示不限制
+    #[arg(long, default_value_t = DEFAULT_MAX_DEPTH)]
+    max_depth: usize,
+    
+    /// 保留只在 if TYPE_CHECKING: 块中导入的包（默认排除）
+    #[arg(long)]
+    include_type_checking: bool,
+    
+    /// 同时扫描.pyi类型存根文件中的导入（默认只扫描.py文件）
+    #[arg(long)]
+    include_stubs: bool,
+    
+    /// 跳过超过此大小的源文件（MB），这类文件通常是生成的代码，0表示不限制
+    #[arg(long, value_name = "MB", default_value_t = DEFAULT_MAX_FILE_SIZE / MB)]
+    max_file_size: u64,
+    
+    /// 超过大小上限的文件不跳过，只分析文件开头的指定大小（KB），导入语句通常位于文件开头
+    #[arg(long, value_name = "KB")]
+    oversized_head: Option<u64>,
+    
+    /// 跳过匹配的路径（相对于扫描目录的glob，如 tests/** 或 examples/*.py），可多次指定
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        ScanOptions {
+            max_depth: DEFAULT_MAX_DEPTH,
+            include_type_checking: false,
+            include_stubs: false,
+            max_file_size: DEFAULT_MAX_FILE_SIZE / MB,
+            oversized_head: None,
+            exclude: Vec::new(),
+        }
+    }
+}
+
+/// 解析依赖的选项，供生成依赖文件的子命令（gen-req、lock、local-dev、export）共用
+#[derive(Args, Clone)]
+struct ResolveOptions {
+    /// 依赖文件中同一个包的版本约束冲突时的处理方式
+    #[arg(long, value_enum, default_value_t = ConflictStrategy::Error)]
+    prefer: ConflictStrategy,
+    
+    /// 选定Python版本后，通过PyPI查询各依赖声明的Python版本要求，不兼容时给出警告
+    #[arg(long)]
+    check_requires_python: bool,
+}
+
+impl Default for ResolveOptions {
+    fn default() -> Self {
+        ResolveOptions {
+            prefer: ConflictStrategy::Error,
+            check_requires_python: false,
+        }
+    }
+}
+
+/// 版本约束冲突的处理方式
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ConflictStrategy {
+    /// 选择允许最高版本的约束
+    Highest,
+    /// 选择最先声明的约束（按requirements.txt、setup.py、pyproject.toml的顺序）
+    First,
+    /// 报告冲突并停止
+    Error,
+    /// 只报告冲突，保留最先声明的约束，用于analyze等不写入依赖文件的子命令
+    #[value(skip)]
+    Warn,
+}
+
+/// 安装依赖时使用的包索引，用于私有PyPI镜像
+#[derive(Args, Clone, Default)]
+struct IndexOptions {
+    /// 包索引地址，替代默认的PyPI（也可通过PIP_INDEX_URL环境变量设置）
+    #[arg(long, env = "PIP_INDEX_URL", hide_env_values = true)]
+    index_url: Option<String>,
+    
+    /// 额外的包索引地址，可以多次指定
+    #[arg(long)]
+    extra_index_url: Vec<String>,
+}
+
+impl IndexOptions {
+    /// 传递给uv/pip的命令行参数
+    fn args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Some(url) = &self.index_url {
+            args.push("--index-url".to_string());
+            args.push(url.clone());
+        }
+        for url in &self.extra_index_url {
+            args.push("--extra-index-url".to_string());
+            args.push(url.clone());
+        }
+        args
+    }
+    
+    /// 用于显示的索引地址，已隐藏其中的用户名和密码
+    fn display_urls(&self) -> Vec<String> {
+        self.index_url
+            .iter()
+            .chain(&self.extra_index_url)
+            .map(|url| redact_url_credentials(url))
+            .collect()
+    }
+}
+
+/// 依赖文件的输出格式
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// requirements.txt
+    Requirements,
+    /// pyproject.toml中的[project] dependencies
+    Pyproject,
+}
+
+impl OutputFormat {
+    /// 输出到目录时使用的默认文件名
+    fn file_name(self) -> &'static str {
+        match self {
+            OutputFormat::Requirements => "requirements.txt",
+            OutputFormat::Pyproject => "pyproject.toml",
+        }
+    }
+}
+
+/// 离线导出的目标平台
+struct ExportTarget {
+    name: &'static str, // 显示名称
+    os_type: &'static str,
+    arch: &'static str,
+}
+
+/// 可选的导出目标平台
+const EXPORT_TARGETS: &[ExportTarget] = &[
+    ExportTarget { name: "Windows 7 (x86)", os_type: "windows7", arch: "x86" },
+    ExportTarget { name: "Windows 7 (x64)", os_type: "windows7", arch: "x64" },
+    ExportTarget { name: "Windows 10 (x86)", os_type: "windows10", arch: "x86" },
+    ExportTarget { name: "Windows 10 (x64)", os_type: "windows10", arch: "x64" },
+    ExportTarget { name: "Windows 11 (x64)", os_type: "windows11", arch: "x64" },
+    ExportTarget { name: "Windows 11 (arm64)", os_type: "windows11", arch: "arm64" },
+    ExportTarget { name: "Windows Server (x64)", os_type: "windowsserver", arch: "x64" },
+    ExportTarget { name: "Linux (x64)", os_type: "linux", arch: "x64" },
+    ExportTarget { name: "Linux (arm64)", os_type: "linux", arch: "arm64" },
+    ExportTarget { name: "macOS (x64)", os_type: "macos", arch: "x64" },
+    ExportTarget { name: "macOS (arm64)", os_type: "macos", arch: "arm64" },
+];
+
+/// 导出包的归档格式
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ArchiveFormat {
+    /// .tar.gz
+    Targz,
+    /// .tar（不压缩）
+    Tar,
+    /// .zip
+    Zip,
+}
+
+impl ArchiveFormat {
+    /// 目标平台的默认格式：Windows可以直接解压zip，其他平台使用tar.gz
+    fn default_for(os_type: &str) -> Self {
+        if os_type.starts_with("windows") {
+            ArchiveFormat::Zip
+        } else {
+            ArchiveFormat::Targz
+        }
+    }
+    
+    /// 归档文件扩展名
+    fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Targz => "tar.gz",
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::Zip => "zip",
+        }
+    }
+}
+
+/// 离线导出的选项
+#[derive(Default)]
+struct ExportOptions {
+    output: Option<String>, // 归档保存路径，未指定时交互式询问
+    force: bool, // 覆盖已存在的归档文件
+    archive_format: Option<ArchiveFormat>, // 归档格式，未指定时按目标平台选择
+    compression_level: Option<u32>, // 压缩级别，未指定时使用默认级别
+    no_compression: bool, // 生成不压缩的归档
+    target: Option<(String, String)>, // 目标操作系统和架构，指定时跳过所有交互
+    targets: Vec<String>, // 批量导出的目标平台（os:arch[:Python版本]）
+    all_platforms: bool, // 为所有支持的平台批量导出
+    python_version: Option<String>, // 目标Python版本
+    yes: bool, // 跳过打包前的确认
+    include_data: Vec<String>, // 一并导出的非Python文件的glob
+    conda: bool, // 额外生成environment.yml
+    bundle_wheels: bool, // 把依赖的wheel文件一并导出，离线安装
+    keep_export_dir: Option<String>, // 准备导出内容的目录，打包后保留；未指定时使用临时目录
+}
+
+/// 依赖分析的结果
+#[derive(Default)]
+struct DependencyReport {
+    external: Vec<String>, // 外部依赖（包括已声明的依赖）
+    stdlib: Vec<String>, // 导入的标准库模块
+    unresolved: Vec<String>, // 无法对应到PyPI包名的导入
+    local: Vec<String>, // 项目自身的模块
+    declared: usize, // 从依赖声明文件中读取的依赖数量
+}
+
+/// 导入推断的依赖与依赖文件的对比结果
+#[derive(Default)]
+struct DependencyDiff {
+    unused: Vec<String>, // 已声明但没有被导入
+    undeclared: Vec<String>, // 已导入但没有声明
+    matching: Vec<String>, // 已声明且被导入
+}
+
 /// Python依赖分析和管理
 struct PyWand {
     os_type: String,
     os_arch: String,
+    project_dir: PathBuf, // 最近一次扫描的项目目录
     python_files: Vec<String>,
+    scan: ScanOptions, // 扫描选项
+    resolve: ResolveOptions, // 版本约束冲突的处理方式和Python版本要求检查
+    dry_run: bool, // 演练模式：只打印计划的操作
+    quiet: bool, // 不显示进度条和扫描信息，用于机器可读的输出
+    no_tips: bool, // 命令完成后不显示使用提示
+    verify_pypi: bool, // 生成依赖文件前通过PyPI校验包名
+    review: bool, // 生成依赖文件前交互式确认每个依赖
+    index: IndexOptions, // 安装依赖时使用的包索引
+    clean_on_failure: bool, // 安装失败时自动删除本次新建的虚拟环境
+    overwrite: bool, // 覆盖用户维护的requirements.txt前不询问（先备份）
+    split_dev: bool, // 只在测试代码中使用的依赖单独写入requirements-dev.txt
+    show_diff: bool, // 覆盖requirements.txt后显示变化的行
+    explain: bool, // 生成依赖文件时列出每个依赖的来源
+    no_header: bool, // 生成的requirements.txt不写版本和日期
+    imported_modules: Vec<String>, // 导入的所有模块（未过滤标准库）
+    module_sources: HashMap<String, Vec<String>>, // 模块到导入它的源文件的映射
+    local_modules: HashSet<String>, // 项目自身的模块名（.py文件名和包含__init__.py的目录名）
+    declared_dependencies: Vec<String>, // 项目依赖文件中已声明的依赖
+    declared_sources: HashMap<String, Vec<String>>, // 规范化的包名到声明它的依赖文件的映射
     dependencies: Vec<String>,
+    python_version: Option<String>, // 目标Python版本，用于判断标准库
+    python_path: Option<PathBuf>, // 创建虚拟环境使用的Python解释器，指定后不再选择版本
+    stdlib_warnings: HashSet<String>, // 已显示的弃用标准库模块警告
+    pypi_verifier: Option<PackageVerifier<PypiIndex>>, // 查询依赖的Python版本要求，多次选择版本时复用查询结果
+    package_mappings: HashMap<String, String>, // 导入名到PyPI包名的映射
+    excluded_dirs: Vec<String>, // 扫描时排除的目录名，可在项目配置文件中修改
+    saved_python_version: Option<String>, // 项目配置文件中保存的Python版本
     uv_manager: UvManager,
     internal_uv_path: Option<PathBuf>, // 内置uv工具的路径
     i18n: I18n, // 国际化支持
@@ -98,65 +727,129 @@ impl PyWand {
     /// 创建新的PyWand应用
     pub fn new() -> Self {
         // 尝试加载保存的语言设置，如果没有则使用系统语言
-        let language = load_language_preference().unwrap_or_else(Language::default);
+        let language = load_language_preference().unwrap_or_default();
         let i18n = I18n::with_language(language);
         
         let os_type = determine_os_type();
         let os_arch = determine_os_arch();
-        let mut app = PyWand {
+        let config = load_project_config(&i18n);
+        // 项目配置中指定的语言优先于用户的语言设置
+        let i18n = match config.language {
+            Some(language) => I18n::with_language(language),
+            None => i18n,
+        };
+        let app = PyWand {
             os_type,
             os_arch,
+            project_dir: PathBuf::from("."),
             python_files: Vec::new(),
+            scan: ScanOptions::default(),
+            resolve: ResolveOptions::default(),
+            dry_run: false,
+            quiet: false,
+            no_tips: false,
+            verify_pypi: false,
+            review: false,
+            index: IndexOptions::default(),
+            clean_on_failure: false,
+            overwrite: false,
+            split_dev: false,
+            show_diff: false,
+            explain: false,
+            no_header: false,
+            imported_modules: Vec::new(),
+            module_sources: HashMap::new(),
+            local_modules: HashSet::new(),
+            declared_dependencies: Vec::new(),
+            declared_sources: HashMap::new(),
             dependencies: Vec::new(),
-            uv_manager: UvManager::new(),
+            python_version: None,
+            python_path: None,
+            stdlib_warnings: HashSet::new(),
+            pypi_verifier: None,
+            package_mappings: load_package_mappings(&i18n),
+            excluded_dirs: config.excluded_dirs,
+            saved_python_version: config.python_version,
+            uv_manager: UvManager::new(i18n.clone()),
             internal_uv_path: None,
             i18n,
         };
         
-        // 确保内置的uv可用
-        if let Err(e) = app.ensure_uv_available() {
-            eprintln!("警告: 无法设置内置的uv工具: {}", e);
-        }
+        interrupt::install_handler(
+            app.i18n.get("interrupted").to_string(),
+            app.i18n.get("interrupted_output_removed").to_string(),
+        );
         
         app
     }
     
     /// 确保内置的uv工具可用
+    ///
+    /// uv按版本解压到用户目录下的~/.pywand/bin/<版本>/，所有项目共用同一份；
+    /// 找不到用户目录时才复制到当前项目的.pywand目录。资源目录相对于程序所在的位置查找，与当前目录无关
     fn ensure_uv_available(&mut self) -> Result<()> {
-        // 创建.pywand目录
-        let pywand_dir = PathBuf::from(".pywand");
-        fs::create_dir_all(&pywand_dir)
-            .context("无法创建.pywand目录")?;
-        
         // 确定uv文件名
         let uv_filename = if self.os_type == "windows" { "uv.exe" } else { "uv" };
-        let uv_path = pywand_dir.join(uv_filename);
+        let resources_dir = uv_resources_dir();
+        let resource_path = resources_dir
+            .join(format!("{}-{}", self.os_type, self.os_arch))
+            .join(uv_filename);
+        
+        if !resource_path.exists() {
+            // 不在PyWand目录中运行时，使用已解压的共享uv，或之前版本复制到项目中的uv
+            let legacy_path = Path::new(".pywand").join(uv_filename);
+            let existing = cached_uv_path(uv_filename).or_else(|| legacy_path.exists().then_some(legacy_path));
+            if let Some(uv_path) = existing {
+                self.internal_uv_path = Some(uv_path);
+                return Ok(());
+            }
+            return Err(anyhow!(self.i18n.get_formatted("uv_binary_missing", &[&resource_path.display().to_string()])));
+        }
+        
+        // 复制前读取随二进制文件一起提供的校验和
+        let checksum_path = resource_path.with_file_name(format!("{}.sha256", uv_filename));
+        let checksum_content = fs::read_to_string(&checksum_path)
+            .context(format!("找不到uv工具的校验和文件: {}", checksum_path.display()))?;
+        let expected_sha256 = parse_checksum_file(&checksum_content)
+            .ok_or_else(|| anyhow!("校验和文件格式无效: {}", checksum_path.display()))?;
+        
+        let version = embedded_uv_version(&resources_dir)
+            .unwrap_or_else(|| expected_sha256[..16].to_lowercase());
+        let uv_dir = match dirs::home_dir() {
+            Some(home) => home.join(".pywand").join("bin").join(version),
+            None => PathBuf::from(".pywand"),
+        };
+        let uv_path = uv_dir.join(uv_filename);
         
         // 检查uv是否已存在
         if !uv_path.exists() {
-            println!("首次运行，正在设置内置uv工具...");
+            info!("{}", self.i18n.get("uv_first_run"));
+            fs::create_dir_all(&uv_dir)
+                .context(format!("无法创建目录: {}", uv_dir.display()))?;
             
-            // 从resources目录复制uv
-            let resource_path = format!("resources/uv/{}-{}/{}", 
-                self.os_type, self.os_arch, uv_filename);
-                
-            let resource_full_path = Path::new(&resource_path);
-            if resource_full_path.exists() {
-                fs::copy(resource_full_path, &uv_path)
-                    .context(format!("无法复制uv从 {} 到 {}", resource_path, uv_path.display()))?;
-                
-                // 设置可执行权限(非Windows)
-                if self.os_type != "windows" {
-                    Command::new("chmod")
-                        .args(["+x", uv_path.to_str().unwrap()])
-                        .status()
-                        .context("无法设置uv工具的执行权限")?;
-                }
-                
-                println!("内置uv工具已设置完成！");
-            } else {
-                return Err(anyhow!("找不到适用于当前平台的uv工具: {}", resource_path));
+            // 先复制到临时文件，校验通过后再重命名，避免多个进程同时解压时用到不完整的文件
+            let staging_path = uv_dir.join(format!("{}.{}.tmp", uv_filename, std::process::id()));
+            fs::copy(&resource_path, &staging_path)
+                .context(format!("无法复制uv从 {} 到 {}", resource_path.display(), staging_path.display()))?;
+            
+            if let Err(e) = verify_uv_checksum(&staging_path, expected_sha256) {
+                let _ = fs::remove_file(&staging_path);
+                return Err(e);
+            }
+            
+            // 设置可执行权限(非Windows)
+            if self.os_type != "windows" {
+                Command::new("chmod")
+                    .arg("+x")
+                    .arg(&staging_path)
+                    .status()
+                    .context("无法设置uv工具的执行权限")?;
             }
+            
+            fs::rename(&staging_path, &uv_path)
+                .context(format!("无法移动uv到 {}", uv_path.display()))?;
+            
+            info!("{}", self.i18n.get("uv_setup_done"));
         }
         
         self.internal_uv_path = Some(uv_path);
@@ -164,17 +857,100 @@ impl PyWand {
         Ok(())
     }
     
-    /// 获取内置uv工具的路径
-    fn get_internal_uv_path(&self) -> Option<&Path> {
-        self.internal_uv_path.as_ref().map(|p| p.as_path())
+    /// 获取内置uv工具的路径，第一次使用时才解压
+    ///
+    /// 演练模式下不解压，返回None时调用方使用PATH中的uv
+    fn get_internal_uv_path(&mut self) -> Option<&Path> {
+        if self.internal_uv_path.is_none() && !self.dry_run {
+            if let Err(e) = self.ensure_uv_available() {
+                warn!("{}", self.i18n.get_formatted("uv_setup_failed", &[&e.to_string()]));
+            }
+        }
+        self.internal_uv_path.as_deref()
+    }
+    
+    /// 无法显示交互式提示时返回错误，并提示可以改用的命令或参数
+    fn require_interactive(&self, hint: &str) -> Result<()> {
+        if is_interactive() {
+            return Ok(());
+        }
+        Err(anyhow!(self.i18n.get_formatted("not_interactive", &[hint])))
+    }
+    
+    /// 初始化项目目录：创建配置文件、带生成标记的空requirements.txt，以及可选的示例main.py
+    ///
+    /// 未通过参数指定的Python版本和语言会交互式询问；已存在的requirements.txt和main.py保持不变
+    fn init_project(&mut self, dir: &Path, python_version: Option<&str>, language: Option<&str>, sample: bool, force: bool) -> Result<()> {
+        let config_path = dir.join(CONFIG_FILE_PATH);
+        if config_path.exists() && !force {
+            return Err(anyhow!(self.i18n.get_formatted("file_exists_use_force", &[&config_path.display().to_string()])));
+        }
+        
+        let python_version = match python_version {
+            Some(version) => Some(version.to_string()),
+            None if is_interactive() => {
+                let versions = self.available_python_versions();
+                let selection = Select::with_theme(&ColorfulTheme::default())
+                    .with_prompt(self.i18n.get("select_python_version"))
+                    .default(0)
+                    .items(&versions)
+                    .interact()?;
+                Some(versions[selection].clone())
+            }
+            None => None,
+        };
+        
+        let language = match language {
+            Some(code) => Language::from_code(code)
+                .ok_or_else(|| anyhow!(self.i18n.get_formatted("unsupported_language", &[code])))?,
+            None if is_interactive() => {
+                let names: Vec<&str> = Language::ALL.iter().map(|language| language.native_name()).collect();
+                let current = Language::ALL.iter().position(|language| *language == self.i18n.language).unwrap_or(0);
+                let selection = Select::with_theme(&ColorfulTheme::default())
+                    .with_prompt(self.i18n.get("select_project_language"))
+                    .default(current)
+                    .items(&names)
+                    .interact()?;
+                Language::ALL[selection]
+            }
+            None => self.i18n.language,
+        };
+        
+        let mut files = vec![(config_path, project_config_template(python_version.as_deref(), language), true)];
+        files.push((dir.join("requirements.txt"), format!("{}\n", GENERATED_MARKER), false));
+        if sample {
+            files.push((dir.join("main.py"), SAMPLE_MAIN_PY.to_string(), false));
+        }
+        
+        for (path, content, overwrite) in files {
+            let display = path.display().to_string();
+            if path.exists() && !overwrite {
+                println!("{}", self.i18n.get_formatted("init_file_kept", &[&display]));
+                continue;
+            }
+            if self.dry_run {
+                println!("{}", self.i18n.get_formatted("dry_run_write_file", &[&display]));
+                for line in content.lines() {
+                    println!("  {}", line);
+                }
+                continue;
+            }
+            create_parent_dirs(&path)?;
+            fs::write(&path, content)
+                .context(format!("无法写入{}", display))?;
+            println!("{}", self.i18n.get_formatted("requirements_created", &[&display]));
+        }
+        
+        Ok(())
     }
     
     /// 应用程序主菜单
     fn show_main_menu(&mut self) -> Result<()> {
+        self.require_interactive("pywand --help")?;
         println!("\n{}", style(self.i18n.get("app_name")).bold().cyan());
         println!("{}", style("=============================").bold().cyan());
         
-        let options = vec![
+        let options = [
             self.i18n.get("local_development"),
             self.i18n.get("export_offline"),
             self.i18n.get("exit")
@@ -187,8 +963,8 @@ impl PyWand {
             .interact()?;
             
         match selection {
-            0 => self.local_development_flow()?,
-            1 => self.export_development_flow()?,
+            0 => self.local_development_flow(DEFAULT_VENV_DIR)?,
+            1 => self.export_development_flow(&ExportOptions::default())?,
             2 => return Ok(()),
             _ => unreachable!(),
         }
@@ -197,13 +973,14 @@ impl PyWand {
     }
     
     /// 本地开发设置
-    fn local_development_flow(&mut self) -> Result<()> {
+    fn local_development_flow(&mut self, venv_dir: &str) -> Result<()> {
         println!("\n{}", style(self.i18n.get("local_dev_title")).bold().green());
         
         // 如果没有找到Python文件，提供选项
         if self.python_files.is_empty() {
             println!("{}", style(self.i18n.get("no_python_files")).bold().yellow());
-            let options = vec![
+            self.require_interactive("pywand local-dev --path <dir>")?;
+            let options = [
                 self.i18n.get("use_test_suite"),
                 self.i18n.get("specify_directory"),
                 self.i18n.get("cancel")
@@ -218,35 +995,35 @@ impl PyWand {
             match selection {
                 0 => {
                     // 使用测试套件
-                    println!("使用测试套件中的示例文件...");
+                    println!("{}", self.i18n.get("using_test_suite_files"));
                     self.find_python_files("test-suite")?;
                     if self.python_files.is_empty() {
-                        println!("{}", style("测试套件中也未找到Python文件！").bold().red());
-                        println!("请先创建一些Python文件，或使用'pywand test'命令运行测试套件。");
+                        println!("{}", style(self.i18n.get("test_suite_empty")).bold().red());
+                        println!("{}", self.i18n.get("create_python_files_hint"));
                         return Ok(());
                     }
                 },
                 1 => {
                     // 手动指定目录
                     let input = dialoguer::Input::<String>::new()
-                        .with_prompt("请输入Python文件所在的目录路径")
+                        .with_prompt(self.i18n.get("enter_python_dir"))
                         .interact_text()?;
                     
                     self.find_python_files(&input)?;
                     if self.python_files.is_empty() {
-                        println!("{}", style("指定目录中未找到Python文件！").bold().red());
+                        println!("{}", style(self.i18n.get("dir_no_python_files")).bold().red());
                         return Ok(());
                     }
                 },
-                2 | _ => {
-                    println!("操作已取消。");
+                _ => {
+                    println!("{}", self.i18n.get("operation_cancelled"));
                     return Ok(());
                 }
             }
         }
         
-        // 基于操作系统和UV支持选择Python版本
-        let python_version = self.select_python_version()?;
+        // 基于操作系统和UV支持选择Python版本，或使用指定的解释器
+        let python_version = self.venv_python()?;
         
         let creating_venv_msg = self.i18n.get_formatted(
             "creating_venv", 
@@ -254,22 +1031,32 @@ impl PyWand {
         );
         println!("\n{}", creating_venv_msg);
         
-        // 确保UV可用
-        self.uv_manager.ensure_available()?;
-        
-        // 创建虚拟环境
-        let venv_dir = ".venv";
-        self.uv_manager.create_venv(venv_dir, &python_version)?;
+        // 创建虚拟环境，记录是否为本次新建，失败回滚时不删除已有环境；安装完成前被中断时删除新建的环境
+        let venv_created = !Path::new(venv_dir).exists();
+        let _partial_venv = (venv_created && !self.dry_run).then(|| interrupt::track_artifact(venv_dir));
+        self.create_venv(venv_dir, &python_version)?;
         
         // 生成requirements.txt文件到当前目录
-        self.generate_requirements_file(".")?;
+        self.generate_requirements_file(Path::new("requirements.txt"), OutputFormat::Requirements, false)?;
         
         // 安装依赖
         println!("{}", self.i18n.get("installing_dependencies"));
-        self.uv_manager.install_dependencies("requirements.txt", venv_dir)?;
+        if let Err(e) = self.install_dependencies("requirements.txt", venv_dir) {
+            if venv_created && !self.dry_run {
+                self.rollback_venv(venv_dir)?;
+            }
+            return Err(e);
+        }
+        
+        // 用实际安装的版本锁定requirements.txt
+        self.freeze_requirements("requirements.txt", venv_dir)?;
         
         // 创建激活脚本
-        create_activation_scripts(venv_dir)?;
+        self.create_activation_scripts(venv_dir)?;
+        
+        if self.dry_run {
+            return Ok(());
+        }
         
         println!("\n{}", style(self.i18n.get("setup_complete")).bold().green());
         println!("{}", self.i18n.get("to_activate_venv"));
@@ -280,240 +1067,1802 @@ impl PyWand {
         }
         
         // 添加使用提示
-        show_usage_tips_with_language(self.i18n.language);
+        self.show_usage_tips();
         
         Ok(())
     }
     
-    /// 导出用于离线开发的设置
-    fn export_development_flow(&mut self) -> Result<()> {
-        println!("\n{}", style("导出用于离线开发").bold().green());
-        
-        // 操作系统选择
-        let os_options = vec![
-            "Windows 7 (32位)",
-            "Windows 7 (64位)",
-            "Windows 10 (32位)",
-            "Windows 10 (64位)",
-            "Windows 11 (64位)",
-            "Windows Server (64位)"
-        ];
-        
-        let os_selection = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt("选择目标操作系统")
-            .default(3) // Windows 10 64位作为默认值
-            .items(&os_options)
-            .interact()?;
-            
-        // 基于所选操作系统选择Python版本
-        let os_type = match os_selection {
-            0 | 1 => "windows7",
-            2 | 3 => "windows10", 
-            4 => "windows11",
-            5 => "windowsserver",
-            _ => "windows10", // 默认
-        };
+    /// 确保uv可用并创建虚拟环境，演练模式下只打印计划
+    fn create_venv(&mut self, venv_dir: &str, python_version: &str) -> Result<()> {
+        if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_create_venv", &[python_version, venv_dir]));
+            return Ok(());
+        }
         
-        let arch = match os_selection {
-            0 | 2 => "x86",
-            _ => "x64",
-        };
+        self.uv_manager.ensure_available()?;
+        self.uv_manager.create_venv(venv_dir, python_version)
+    }
+    
+    /// 虚拟环境不存在时创建，并安装当前目录的依赖：已有requirements.txt时先合并新增的导入，否则扫描生成
+    fn ensure_project_venv(&mut self, venv_dir: &str) -> Result<()> {
+        if Path::new(venv_dir).exists() {
+            return Ok(());
+        }
         
-        let python_version = self.select_python_version_for_export(os_selection)?;
+        println!("{}", self.i18n.get("venv_missing_creating"));
+        let python_version = self.venv_python()?;
+        let _partial_venv = (!self.dry_run).then(|| interrupt::track_artifact(venv_dir));
+        self.create_venv(venv_dir, &python_version)?;
         
-        println!("\n正在为{}和Python {}准备包...", 
-                 os_options[os_selection], python_version);
-                 
-        // 如果self.python_files为空，那么我们需要扫描文件
-        if self.python_files.is_empty() {
+        // 如果当前目录存在requirements.txt，先合并新增的导入再安装依赖
+        if Path::new("requirements.txt").exists() {
             self.find_python_files(".")?;
-            self.extract_dependencies()?;
+            let report = self.extract_dependencies()?;
+            self.show_dependency_report(&report);
+            self.merge_requirements_file(Path::new("."))?;
+            
+            println!("{}", self.i18n.get("requirements_found_installing"));
+            self.install_dependencies("requirements.txt", venv_dir)?;
+        } else {
+            // 扫描并生成requirements.txt
+            println!("{}", self.i18n.get("requirements_missing_generating"));
+            self.find_python_files(".")?;
+            let report = self.extract_dependencies()?;
+            self.show_dependency_report(&report);
+            if !self.dependencies.is_empty() {
+                self.generate_requirements_file(Path::new("requirements.txt"), OutputFormat::Requirements, false)?;
+                self.install_dependencies("requirements.txt", venv_dir)?;
+            }
         }
         
-        // 创建导出包
-        let export_dir = tempdir()?;
-        let export_path = export_dir.path();
-        
-        // 复制Python文件
-        copy_python_files(&self.python_files, export_path)?;
-        
-        // 生成requirements.txt文件到导出目录
-        self.generate_requirements_file(export_path.to_str().unwrap())?;
-        
-        // 为目标操作系统创建设置脚本
-        create_setup_scripts(export_path, &python_version, os_type, arch)?;
-        
-        // 创建README文件
-        create_readme(export_path, &python_version, &os_options[os_selection])?;
-        
-        // 创建zip存档
-        let output_file = format!("pywand_export_{}_{}_{}.tar.gz", 
-                                 os_type, arch, python_version.replace(".", "_"));
-        create_archive(export_path, &output_file)?;
-        
-        println!("\n{}", style("导出成功完成！").bold().green());
-        println!("包已保存到: ./{}", output_file);
-        
-        // 添加使用提示
-        show_usage_tips_with_language(self.i18n.language);
-        
         Ok(())
     }
     
-    /// 在给定目录中查找所有Python文件
-    fn find_python_files(&mut self, dir: &str) -> Result<()> {
-        let pb = ProgressBar::new_spinner();
-        pb.set_style(ProgressStyle::default_spinner()
-            .tick_chars("⠁⠂⠄⡀⢀⠠⠐⠈ ")
-            .template("{spinner:.green} {msg}")?);
-        pb.set_message("正在扫描Python文件...");
-        
-        self.python_files.clear(); // 清空之前的文件列表
-        
-        // 需要排除的目录名
-        let excluded_dirs = [
-            ".git", ".venv", "venv", "env", "__pycache__", "node_modules",
-            ".idea", ".vscode", "dist", "build", "target", ".pytest_cache"
-        ];
+    /// 安装包并以安装的版本追加到依赖文件，依赖文件中已有的包保持不变
+    fn add_packages(&mut self, venv_dir: &str, requirements_file: &str, packages: &[String]) -> Result<()> {
+        self.ensure_project_venv(venv_dir)?;
         
-        for entry in WalkDir::new(dir)
-            .max_depth(10) // 限制递归深度
-            .into_iter()
-            .filter_entry(|e| {
-                // 排除特定目录
-                if e.file_type().is_dir() {
-                    let file_name = e.file_name().to_string_lossy();
-                    return !excluded_dirs.iter().any(|d| &file_name == d);
-                }
-                true
-            })
+        self.show_package_index();
+        if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_install_packages", &[venv_dir, &packages.join(" ")]));
+            println!("{}", self.i18n.get_formatted("dry_run_write_file", &[requirements_file]));
+            return Ok(());
+        }
+        
+        println!("{}", style(self.i18n.get("installing_dependencies")).bold().green());
+        self.uv_manager.ensure_available()?;
+        let mut pip_args = self.index.args();
+        pip_args.extend(packages.iter().cloned());
+        if let Err(e) = self.uv_manager.install_packages(venv_dir, &pip_args) {
+            println!("{}", style(self.i18n.get("packages_install_failed")).bold().red());
+            return Err(e.context(PyWandError::InstallFailed));
+        }
+        let installed = self.uv_manager.installed_versions(venv_dir)?;
+        
+        let requirements_path = Path::new(requirements_file);
+        let mut content = if requirements_path.exists() {
+            fs::read_to_string(requirements_path)
+                .context(format!("无法读取{}文件", requirements_file))?
+        } else {
+            format!("{}\n", GENERATED_MARKER)
+        };
+        
+        let mut added = false;
+        for package in packages {
+            let name = requirement_name(package);
+            let existing: Vec<String> = parse_requirements_txt(&content)
+                .iter()
+                .map(|requirement| requirement_name(requirement))
+                .collect();
+            if existing.contains(&name) {
+                println!("{}", self.i18n.get_formatted("requirement_already_present", &[package, requirements_file]));
+                continue;
+            }
+            
+            // 以安装的版本固定，保留包名中的extras
+            let requirement = match installed.get(&name) {
+                Some(version) => format!("{}=={}", requirement_with_extras(package), version),
+                None => package.trim().to_string(),
+            };
+            content = insert_requirement(&content, &requirement);
+            println!("  + {}", requirement);
+            added = true;
+        }
+        if !added {
+            println!("{}", style(self.i18n.get("packages_installed")).bold().green());
+            return Ok(());
+        }
+        
+        fs::write(requirements_path, content)
+            .context(format!("无法写入{}文件", requirements_file))?;
+        println!("{}", style(self.i18n.get_formatted("packages_added", &[requirements_file])).bold().green());
+        
+        Ok(())
+    }
+    
+    /// 卸载包并从依赖文件中删除对应的行（按规范化的包名匹配），仍在源代码中导入的包给出警告
+    fn remove_packages(&mut self, path: &str, venv_dir: &str, requirements_file: &str, packages: &[String]) -> Result<()> {
+        let names: Vec<String> = packages.iter().map(|package| requirement_name(package)).collect();
+        
+        // 删除前检查源代码中是否仍导入这些包
+        self.find_python_files(path)?;
+        self.extract_dependencies()?;
+        let sources = self.package_sources();
+        for (package, name) in packages.iter().zip(&names) {
+            if let Some(files) = sources.get(name) {
+                warn!("{}", style(self.i18n.get_formatted("package_still_imported", &[package, &format_sources(files, &self.i18n)])).yellow());
+            }
+        }
+        
+        if !Path::new(venv_dir).exists() {
+            println!("{}", self.i18n.get_formatted("venv_missing_skip_uninstall", &[venv_dir]));
+        } else if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_command", &[&format!("uv pip uninstall {}", packages.join(" "))]));
+        } else {
+            self.uv_manager.ensure_available()?;
+            self.uv_manager.uninstall_packages(venv_dir, packages)?;
+        }
+        
+        let requirements_path = Path::new(requirements_file);
+        if !requirements_path.exists() {
+            println!("{}", self.i18n.get_formatted("requirements_file_missing", &[requirements_file]));
+            return Ok(());
+        }
+        let content = fs::read_to_string(requirements_path)
+            .context(format!("无法读取{}文件", requirements_file))?;
+        
+        // 只删除依赖行，注释和-r等选项行保持不变
+        let mut removed = Vec::new();
+        let mut kept = Vec::new();
+        for line in content.lines() {
+            let trimmed = line.trim();
+            let is_requirement = !trimmed.is_empty() && !trimmed.starts_with('#') && !trimmed.starts_with('-');
+            if is_requirement && names.contains(&requirement_name(trimmed)) {
+                removed.push(trimmed);
+            } else {
+                kept.push(line);
+            }
+        }
+        
+        if removed.is_empty() {
+            println!("{}", self.i18n.get_formatted("requirements_nothing_removed", &[requirements_file]));
+            return Ok(());
+        }
+        for requirement in &removed {
+            println!("  - {}", requirement);
+        }
+        if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_write_file", &[requirements_file]));
+            return Ok(());
+        }
+        
+        let mut content = kept.join("\n");
+        content.push('\n');
+        fs::write(requirements_path, content)
+            .context(format!("无法写入{}文件", requirements_file))?;
+        println!("{}", style(self.i18n.get_formatted("packages_removed", &[requirements_file])).bold().green());
+        
+        Ok(())
+    }
+    
+    /// 依赖安装失败后清理本次新建的虚拟环境
+    ///
+    /// 指定--clean-on-failure时直接删除，否则在交互式终端中询问，非交互时保留并提示
+    fn rollback_venv(&self, venv_dir: &str) -> Result<()> {
+        let remove = if self.clean_on_failure {
+            true
+        } else if is_interactive() {
+            Confirm::with_theme(&ColorfulTheme::default())
+                .with_prompt(self.i18n.get_formatted("remove_partial_venv", &[venv_dir]))
+                .default(true)
+                .interact()?
+        } else {
+            false
+        };
+        
+        if remove {
+            fs::remove_dir_all(venv_dir)
+                .with_context(|| format!("无法删除虚拟环境: {}", venv_dir))?;
+            println!("{}", self.i18n.get_formatted("partial_venv_removed", &[venv_dir]));
+        } else {
+            println!("{}", self.i18n.get_formatted("partial_venv_kept", &[venv_dir]));
+        }
+        
+        Ok(())
+    }
+    
+    /// 从依赖文件安装依赖，演练模式下只打印计划
+    fn install_dependencies(&self, requirements_file: &str, venv_dir: &str) -> Result<()> {
+        self.show_package_index();
+        if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_install_requirements", &[requirements_file, venv_dir]));
+            return Ok(());
+        }
+        
+        self.uv_manager.install_dependencies(requirements_file, venv_dir, &self.index.args())
+            .context(PyWandError::InstallFailed)
+    }
+    
+    /// 通过uv pip compile解析依赖，生成带版本和哈希的锁定文件
+    ///
+    /// uv不支持pip compile时给出提示并跳过，不视为错误
+    fn lock_requirements(&mut self, requirements_file: &str, output_file: &str) -> Result<()> {
+        self.show_package_index();
+        if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_write_file", &[output_file]));
+            return Ok(());
+        }
+        
+        self.uv_manager.ensure_available()?;
+        if !self.uv_manager.supports_compile() {
+            warn!("{}", style(self.i18n.get("lock_unsupported")).yellow());
+            return Ok(());
+        }
+        
+        self.uv_manager.compile_requirements(
+            requirements_file,
+            output_file,
+            self.python_version.as_deref(),
+            &self.index.args(),
+        )?;
+        println!("{}", style(self.i18n.get_formatted("lock_written", &[output_file])).bold().green());
+        
+        Ok(())
+    }
+    
+    /// 显示使用的自定义包索引（不显示地址中的凭据）
+    fn show_package_index(&self) {
+        for url in self.index.display_urls() {
+            println!("{}", self.i18n.get_formatted("using_package_index", &[&url]));
+        }
+    }
+    
+    /// 用实际安装的版本锁定依赖文件，演练模式下只打印计划
+    fn freeze_requirements(&self, requirements_file: &str, venv_dir: &str) -> Result<()> {
+        if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_pin_requirements", &[requirements_file]));
+            return Ok(());
+        }
+        // 保留的用户维护文件不改写为锁定版本
+        let content = fs::read_to_string(requirements_file).unwrap_or_default();
+        if !is_generated_requirements(&content) {
+            println!("{}", self.i18n.get_formatted("pin_skipped_user_file", &[requirements_file]));
+            return Ok(());
+        }
+        
+        self.uv_manager.freeze_requirements(requirements_file, venv_dir)
+    }
+    
+    /// 创建激活脚本，演练模式下只打印计划
+    fn create_activation_scripts(&self, venv_dir: &str) -> Result<()> {
+        if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_activation_scripts", &[venv_dir]));
+            return Ok(());
+        }
+        
+        create_activation_scripts(venv_dir, &self.i18n)
+    }
+    
+    /// 导出用于离线开发的设置
+    fn export_development_flow(&mut self, options: &ExportOptions) -> Result<()> {
+        println!("\n{}", style(self.i18n.get("exporting_offline")).bold().green());
+        if options.all_platforms || !options.targets.is_empty() {
+            return self.export_batch(options);
+        }
+        
+        // 操作系统选择，命令行已指定目标时不再询问
+        let interactive = options.target.is_none();
+        let target = match &options.target {
+            Some((os_type, arch)) => find_export_target(os_type, arch)?,
+            None => {
+                self.require_interactive("pywand export --os linux --arch x64")?;
+                let os_options: Vec<&str> = EXPORT_TARGETS.iter().map(|target| target.name).collect();
+                
+                let os_selection = Select::with_theme(&ColorfulTheme::default())
+                    .with_prompt(self.i18n.get("select_os"))
+                    .default(3) // Windows 10 x64作为默认值
+                    .items(&os_options)
+                    .interact()?;
+                
+                &EXPORT_TARGETS[os_selection]
+            }
+        };
+        let (os_type, arch) = (target.os_type, target.arch);
+        
+        // 基于所选操作系统选择Python版本
+        let python_version = if interactive {
+            self.select_python_version_for_export(os_type, arch)?
+        } else {
+            validate_python_version(options.python_version.as_deref(), os_type, arch)?
+        };
+        self.set_python_version(&python_version);
+        
+        // 确定归档保存路径，在开始打包前检查是否会覆盖已有文件
+        let default_file_name = export_file_name(target, &python_version, export_archive_format(options, os_type));
+        let output = match &options.output {
+            Some(output) => output.clone(),
+            None if !interactive => default_file_name.clone(),
+            None => dialoguer::Input::<String>::new()
+                .with_prompt(self.i18n.get("archive_path_prompt"))
+                .default(default_file_name.clone())
+                .interact_text()?,
+        };
+        let output_file = resolve_export_path(&output, &default_file_name);
+        if output_file.exists() && !options.force {
+            return Err(anyhow!(self.i18n.get_formatted("file_exists_use_force", &[&output_file.display().to_string()])));
+        }
+        
+        if !self.package_export(options, target, &python_version, &output_file, interactive)? {
+            return Ok(());
+        }
+        
+        println!("\n{}", style(self.i18n.get("export_complete")).bold().green());
+        println!("{}", self.i18n.get_formatted("package_saved", &[&output_file.display().to_string()]));
+        
+        // 添加使用提示
+        self.show_usage_tips();
+        
+        Ok(())
+    }
+    
+    /// 批量导出：为--target指定的每个平台（--all-platforms时为所有平台）各生成一个归档，不进行任何交互
+    fn export_batch(&mut self, options: &ExportOptions) -> Result<()> {
+        let mut targets = Vec::new();
+        if options.all_platforms {
+            for target in EXPORT_TARGETS {
+                targets.push((target, validate_python_version(None, target.os_type, target.arch)?));
+            }
+        } else {
+            for spec in &options.targets {
+                let (target, python_version) = parse_target_spec(spec)?;
+                targets.push((target, validate_python_version(python_version, target.os_type, target.arch)?));
+            }
+        }
+        
+        // 所有归档都保存到输出目录，在开始打包前检查重复的目标和已有文件
+        let output_dir = PathBuf::from(options.output.as_deref().unwrap_or("."));
+        let mut jobs: Vec<(&ExportTarget, String, PathBuf)> = Vec::new();
+        for (target, python_version) in targets {
+            let output_file = output_dir.join(export_file_name(target, &python_version, export_archive_format(options, target.os_type)));
+            if jobs.iter().any(|(_, _, file)| *file == output_file) {
+                return Err(anyhow!(self.i18n.get_formatted(
+                    "export_duplicate_target",
+                    &[target.os_type, target.arch, &python_version]
+                )));
+            }
+            if output_file.exists() && !options.force {
+                return Err(anyhow!(self.i18n.get_formatted("file_exists_use_force", &[&output_file.display().to_string()])));
+            }
+            jobs.push((target, python_version, output_file));
+        }
+        
+        let mut archives = Vec::new();
+        for (target, python_version, output_file) in &jobs {
+            // 依赖按每个平台的Python版本重新筛选
+            self.set_python_version(python_version);
+            if self.package_export(options, target, python_version, output_file, false)? {
+                archives.push((target, python_version, output_file));
+            }
+        }
+        if self.dry_run {
+            return Ok(());
+        }
+        
+        println!("\n{}", style(self.i18n.get("export_complete")).bold().green());
+        println!("{}", self.i18n.get_plural("batch_export_summary", archives.len()));
+        for (target, python_version, output_file) in archives {
+            println!("  {} / Python {}: {}", target.name, python_version, output_file.display());
+        }
+        
+        self.show_usage_tips();
+        
+        Ok(())
+    }
+    
+    /// 把requirements.txt中依赖的目标平台wheel文件下载到导出目录的wheels/中
+    fn bundle_wheels(&mut self, export_path: &Path, python_version: &str, target: &ExportTarget, requirements: &[String]) -> Result<()> {
+        let wheels_dir = export_path.join(WHEELS_DIR);
+        fs::create_dir_all(&wheels_dir)
+            .context(format!("无法创建目录: {}", wheels_dir.display()))?;
+        if requirements.is_empty() {
+            return Ok(());
+        }
+        
+        // pip download只需要主版本和次版本，如3.11
+        let minor_version: Vec<&str> = python_version.split('.').take(2).collect();
+        self.uv_manager.ensure_available()?;
+        self.uv_manager.download_wheels(
+            &export_path.join("requirements.txt").to_string_lossy(),
+            &wheels_dir.to_string_lossy(),
+            &minor_version.join("."),
+            wheel_platforms(target.os_type, target.arch),
+        ).context(self.i18n.get_formatted("wheel_download_failed", &[target.name]))?;
+        
+        let wheel_count = fs::read_dir(&wheels_dir)
+            .context(format!("无法读取目录: {}", wheels_dir.display()))?
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "whl"))
+            .count();
+        println!("{}", self.i18n.get_plural("wheels_bundled", wheel_count));
+        Ok(())
+    }
+    
+    /// 在导出目录中准备项目文件、依赖文件和设置脚本，打包到`output_file`
+    ///
+    /// 演练模式或交互确认时取消返回false
+    fn package_export(&mut self, options: &ExportOptions, target: &ExportTarget, python_version: &str, output_file: &Path, interactive: bool) -> Result<bool> {
+        let (os_type, arch) = (target.os_type, target.arch);
+        let archive_format = export_archive_format(options, os_type);
+        let compression = options.compression_level.map(Compression::new).unwrap_or_default();
+        
+        println!("\n{}", self.i18n.get_formatted("preparing_package", &[target.name, python_version]));
+                 
+        // 如果self.python_files为空，那么我们需要扫描文件
+        if self.python_files.is_empty() {
+            self.find_python_files(".")?;
+            let report = self.extract_dependencies()?;
+            self.show_dependency_report(&report);
+        }
+        
+        if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_archive", &[&output_file.display().to_string()]));
+            return Ok(false);
+        }
+        
+        // 创建导出包，默认在临时目录中准备，返回时自动删除
+        let temp_dir;
+        let export_path = match &options.keep_export_dir {
+            Some(dir) => prepare_export_dir(Path::new(dir))?,
+            None => {
+                temp_dir = tempdir()?;
+                temp_dir.path()
+            }
+        };
+        
+        // 复制Python文件和--include-data匹配的数据文件
+        let data_files = self.find_data_files(&options.include_data)?;
+        if !options.include_data.is_empty() {
+            println!("{}", self.i18n.get_plural("data_files_included", data_files.len()));
+        }
+        let project_files: Vec<String> = self.python_files.iter().chain(&data_files).cloned().collect();
+        copy_project_files(&project_files, export_path, &self.i18n)?;
+        
+        // 生成requirements.txt文件到导出目录
+        let requirements = self.generate_requirements_file(&export_path.join("requirements.txt"), OutputFormat::Requirements, false)?;
+        
+        // 使用同一组依赖生成conda的environment.yml
+        if options.conda {
+            let name = project_name(&self.project_dir);
+            write_conda_environment(&export_path.join(CONDA_ENVIRONMENT_FILE), &name, python_version, &requirements, &self.i18n)?;
+        }
+        
+        // 项目中已有锁定文件时一并导出
+        let lock_file = self.project_dir.join(LOCK_FILE_NAME);
+        if lock_file.is_file() {
+            fs::copy(&lock_file, export_path.join(LOCK_FILE_NAME))
+                .context(format!("无法复制锁定文件: {}", lock_file.display()))?;
+            println!("{}", self.i18n.get("lock_included"));
+        }
+        
+        // 下载目标平台的wheel文件，设置脚本改为从中离线安装
+        if options.bundle_wheels {
+            self.bundle_wheels(export_path, python_version, target, &requirements)?;
+        }
+        
+        // 为目标操作系统创建设置脚本
+        create_setup_scripts(export_path, python_version, os_type, arch, options.bundle_wheels, &self.i18n)?;
+        
+        // 创建README文件
+        create_readme(export_path, python_version, target.name, &self.i18n)?;
+        
+        // 导出目录中残留的__pycache__、虚拟环境和--exclude匹配的文件不会写入清单和归档
+        let exclude = build_glob_set(&self.scan.exclude)?;
+        
+        // 最后生成校验清单，覆盖以上所有文件
+        create_manifest(export_path, &export_files(export_path, &self.excluded_dirs, &exclude)?, &self.i18n)?;
+        let files = export_files(export_path, &self.excluded_dirs, &exclude)?;
+        
+        // 显示导出内容的大小，交互模式下确认后再打包，避免意外生成过大的归档
+        let (file_count, total_size) = export_size(&files)?;
+        println!("{}", self.i18n.get_formatted("export_size", &[&file_count.to_string(), &format_size(total_size)]));
+        if options.keep_export_dir.is_some() {
+            println!("{}", self.i18n.get_formatted("export_dir_kept", &[&export_path.display().to_string()]));
+        }
+        if interactive && !options.yes && is_interactive() {
+            let proceed = Confirm::with_theme(&ColorfulTheme::default())
+                .with_prompt(self.i18n.get("confirm_archive"))
+                .default(true)
+                .interact()?;
+            if !proceed {
+                println!("{}", self.i18n.get("operation_cancelled"));
+                return Ok(false);
+            }
+        }
+        
+        // 创建存档
+        if let Some(parent) = output_file.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .context(format!("无法创建目录: {}", parent.display()))?;
+        }
+        match archive_format {
+            ArchiveFormat::Targz => create_archive(export_path, &files, output_file, Some(compression), &self.i18n)?,
+            ArchiveFormat::Tar => create_archive(export_path, &files, output_file, None, &self.i18n)?,
+            ArchiveFormat::Zip => {
+                let compression = (!options.no_compression).then_some(compression);
+                create_zip_archive(export_path, &files, output_file, compression, &self.i18n)?
+            }
+        }
+        
+        Ok(true)
+    }
+    
+    /// 在最近扫描的项目目录中查找与--include-data匹配的非Python文件，排除规则与扫描Python文件相同
+    fn find_data_files(&self, patterns: &[String]) -> Result<Vec<String>> {
+        if patterns.is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        let include = build_glob_set(patterns)?;
+        let exclude = build_glob_set(&self.scan.exclude)?;
+        let root = self.project_dir.as_path();
+        
+        let mut walker = WalkDir::new(root).follow_links(false);
+        if self.scan.max_depth > 0 {
+            walker = walker.max_depth(self.scan.max_depth);
+        }
+        
+        let data_files = walker
+            .into_iter()
+            .filter_entry(|e| is_scanned_entry(e, root, &self.excluded_dirs, &exclude))
             .filter_map(|e| e.ok())
-            .filter(|e| {
-                if let Some(ext) = e.path().extension() {
-                    ext == "py"
-                } else {
-                    false
-                }
-            }) 
+            .filter(|e| e.file_type().is_file())
+            .filter(|e| e.path().extension().is_none_or(|ext| ext != "py"))
+            .filter(|e| e.path().strip_prefix(root).is_ok_and(|relative| include.is_match(relative)))
+            .map(|e| e.path().display().to_string())
+            .collect();
+        
+        Ok(data_files)
+    }
+    
+    /// 输出报告问题时需要的版本信息，键名固定为英文，便于直接复制
+    fn show_version_info(&mut self) {
+        let uv_version = match self.uv_manager.ensure_available()
+            .and_then(|_| self.uv_manager.run_command_output(&["--version"]))
         {
-            self.python_files.push(entry.path().display().to_string());
-            pb.tick();
+            Ok(output) => output.trim().to_string(),
+            Err(e) => format!("unavailable ({})", e),
+        };
+        
+        println!("pywand:   {}", env!("CARGO_PKG_VERSION"));
+        println!("uv:       {}", uv_version);
+        println!("embedded: {}", self.embedded_uv_summary());
+        println!("os:       {}", self.os_type);
+        println!("arch:     {}", self.os_arch);
+        println!("language: {} ({})", self.i18n.language.code(), self.i18n.language.native_name());
+    }
+    
+    /// 内置UV的平台列表，当前平台没有内置UV时说明每次都会从网络下载
+    fn embedded_uv_summary(&self) -> String {
+        let platforms = self.uv_manager.embedded_platforms();
+        let current = format!("{}-{}", self.os_type, self.os_arch);
+        if platforms.is_empty() {
+            "none (no uv binaries were embedded in this build; uv is downloaded when needed)".to_string()
+        } else if !platforms.contains(&current) {
+            format!("{} (none for {}; uv is downloaded when needed)", platforms.join(", "), current)
+        } else {
+            platforms.join(", ")
+        }
+    }
+    
+    /// 在给定目录中查找所有Python文件
+    fn find_python_files(&mut self, dir: &str) -> Result<()> {
+        progress::emit(ProgressEvent::ScanStart { path: dir });
+        let pb = progress::spinner(self.i18n.get("scanning_files"), self.quiet);
+        
+        self.project_dir = PathBuf::from(dir);
+        let (python_files, truncated_dirs) = find_python_files(Path::new(dir), &self.scan_config())?;
+        self.python_files = python_files;
+        for file in &self.python_files {
+            progress::emit(ProgressEvent::File { path: file });
         }
+        let max_depth = self.scan.max_depth;
         
-        let found_files_msg = format!("找到{}个Python文件", self.python_files.len());
+        let found_files_msg = self.i18n.get_plural("found_files", self.python_files.len());
         pb.finish_with_message(found_files_msg);
         
-        println!("\n扫描目录: {}", dir);
-        println!("找到Python文件数量: {}", self.python_files.len());
+        if truncated_dirs > 0 {
+            warn!("{}", style(self.i18n.get_formatted(
+                "depth_limit_reached",
+                &[&truncated_dirs.to_string(), &max_depth.to_string()]
+            )).yellow());
+        }
+        
+        if !self.quiet {
+            println!("\n{}", self.i18n.get_formatted("scanning_dir", &[dir]));
+            println!("{}", self.i18n.get_formatted("python_file_count", &[&self.python_files.len().to_string()]));
+        }
         
         Ok(())
     }
     
+    /// 显示使用提示，安静模式或--no-tips时不显示
+    fn show_usage_tips(&self) {
+        if !self.quiet && !self.no_tips {
+            show_usage_tips_with_language(self.i18n.language);
+        }
+    }
+    
+    /// 没有扫描到Python文件时返回错误（退出码2），用于只分析依赖、没有文件就无事可做的命令
+    fn require_python_files(&self, dir: &str) -> Result<()> {
+        if self.python_files.is_empty() {
+            return Err(PyWandError::NoPythonFiles(dir.to_string()).into());
+        }
+        Ok(())
+    }
+    
+    /// 按命令行选项和项目配置生成扫描设置
+    fn scan_config(&self) -> ScanConfig {
+        ScanConfig {
+            max_depth: self.scan.max_depth,
+            include_type_checking: self.scan.include_type_checking,
+            include_stubs: self.scan.include_stubs,
+            // 过大的数值按不限制处理，避免乘法溢出
+            max_file_size: self.scan.max_file_size.saturating_mul(MB),
+            oversized_head: self.scan.oversized_head.unwrap_or(0).saturating_mul(KB),
+            excluded_dirs: self.excluded_dirs.clone(),
+            exclude: self.scan.exclude.clone(),
+            python_version: self.python_version.clone(),
+            package_mappings: self.package_mappings.clone(),
+        }
+    }
+    
     /// 从Python文件中提取依赖
-    fn extract_dependencies(&mut self) -> Result<()> {
+    ///
+    /// 同时更新self.dependencies，返回的报告用于显示或进一步处理
+    fn extract_dependencies(&mut self) -> Result<DependencyReport> {
         if self.python_files.is_empty() {
-            println!("没有找到Python文件，无法提取依赖。");
-            return Ok(());
+            return Ok(DependencyReport::default());
         }
         
-        let pb = ProgressBar::new(self.python_files.len() as u64);
-        pb.set_style(ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")?
-            .progress_chars("#>-"));
+        let pb = progress::bar(self.python_files.len() as u64, self.quiet);
         
         // 清空之前的依赖
-        self.dependencies.clear();
+        self.imported_modules.clear();
+        
+        // 读取项目中已声明的依赖，声明中的版本约束优先于推断出的包名
+        let declared = match parse_declared_dependencies(&self.project_dir) {
+            Ok(declared) => declared,
+            Err(e) => {
+                warn!("{}", self.i18n.get_formatted("declared_deps_failed", &[&format!("{:#}", e)]));
+                Vec::new()
+            }
+        };
+        self.declared_sources.clear();
+        for entry in &declared {
+            let sources = self.declared_sources.entry(requirement_name(&entry.requirement)).or_default();
+            if !sources.iter().any(|source| source == entry.source) {
+                sources.push(entry.source.to_string());
+            }
+        }
+        self.declared_dependencies = resolve_declared_dependencies(declared, self.resolve.prefer, &self.i18n)?;
         
-        let import_re = Regex::new(r"(?m)^\s*(?:import|from)\s+([a-zA-Z0-9_]+)")?;
+        // 扫描源文件，得到导入的模块、导入它们的文件和项目自身的模块
+        let scan = scan_directory_with_progress(&self.project_dir, &self.scan_config(), || pb.inc(1))?;
+        self.python_files = scan.files;
+        self.imported_modules = scan.modules;
+        self.module_sources = scan.module_sources;
+        self.local_modules = scan.local.into_iter().collect();
+        self.filter_dependencies();
+        self.emit_found_dependencies();
         
-        for file in &self.python_files {
-            if let Ok(content) = fs::read_to_string(file) {
-                for cap in import_re.captures_iter(&content) {
-                    let module = cap[1].to_string();
-                    if !self.dependencies.contains(&module) 
-                       && !is_standard_library(&module) {
-                        self.dependencies.push(module);
-                    }
+        pb.finish_with_message(self.i18n.get_plural("found_dependencies", self.dependencies.len()));
+        self.warn_deprecated_stdlib();
+        
+        let oversized_files: Vec<&String> = scan.oversized.iter().collect();
+        self.warn_oversized_files(&oversized_files);
+        
+        Ok(self.dependency_report())
+    }
+    
+    /// 超过大小上限的文件不会被完整分析，逐个给出警告
+    fn warn_oversized_files(&self, files: &[&String]) {
+        let size_limit = self.scan.max_file_size.to_string();
+        for file in files {
+            let message = match self.scan.oversized_head {
+                Some(head) => self.i18n.get_formatted("oversized_file_head", &[file, &size_limit, &head.to_string()]),
+                None => self.i18n.get_formatted("oversized_file_skipped", &[file, &size_limit]),
+            };
+            warn!("{}", style(message).yellow());
+        }
+    }
+    
+    /// 按当前的目标Python版本对导入的模块分类
+    fn dependency_report(&self) -> DependencyReport {
+        let python_version = self.python_version.as_deref();
+        // 与生成的requirements.txt相同的PyPI包名，如yaml对应PyYAML
+        let mut report = DependencyReport {
+            external: self.requirements(),
+            declared: self.declared_dependencies.len(),
+            ..Default::default()
+        };
+        
+        for module in &self.imported_modules {
+            let root = import_root(module);
+            if self.local_modules.contains(root) {
+                if !report.local.iter().any(|local| local == root) {
+                    report.local.push(root.to_string());
                 }
+            } else if is_standard_library(root, python_version) {
+                if !report.stdlib.iter().any(|stdlib| stdlib == root) {
+                    report.stdlib.push(root.to_string());
+                }
+            } else if normalize_package_name(module, python_version, &self.package_mappings).is_none() {
+                report.unresolved.push(module.clone());
+            }
+        }
+        
+        report
+    }
+    
+    /// 显示依赖分析结果
+    ///
+    /// 安静模式下只逐行输出依赖，没有依赖时不输出任何内容
+    fn show_dependency_report(&self, report: &DependencyReport) {
+        if self.quiet {
+            for dep in &report.external {
+                println!("{}", dep);
             }
-            pb.inc(1);
+            return;
         }
         
-        pb.finish_with_message(format!("找到{}个依赖", self.dependencies.len()));
+        if self.python_files.is_empty() {
+            println!("{}", self.i18n.get("no_files_to_extract"));
+            return;
+        }
+        
+        if report.declared > 0 {
+            println!("\n{}", self.i18n.get_plural("declared_deps_count", report.declared));
+        }
         
-        // 显示依赖
-        if !self.dependencies.is_empty() {
-            println!("\n找到以下外部依赖：");
-            for dep in &self.dependencies {
+        if !report.external.is_empty() {
+            println!("\n{}", self.i18n.get("external_dependencies"));
+            for dep in &report.external {
                 println!("  - {}", dep);
             }
         } else {
-            println!("\n未找到外部依赖。");
+            println!("\n{}", self.i18n.get("no_dependencies"));
+        }
+        
+        if !report.unresolved.is_empty() {
+            println!("\n{}", self.i18n.get("unresolved_imports"));
+            for module in &report.unresolved {
+                match ambiguous_import_candidates(module) {
+                    Some(candidates) => println!(
+                        "  - {}",
+                        self.i18n.get_formatted("ambiguous_import", &[module, &candidates.join(", ")])
+                    ),
+                    None => println!("  - {}", module),
+                }
+            }
+            println!("{}", style(self.i18n.get_formatted("unresolved_mapping_hint", &[MAPPINGS_FILE_NAME])).dim());
+        }
+    }
+    
+    /// --strict模式下的检查：存在无法对应到PyPI包名的导入时返回错误
+    fn require_resolved_imports(&self, report: &DependencyReport) -> Result<()> {
+        if report.unresolved.is_empty() {
+            return Ok(());
+        }
+        Err(anyhow!(self.i18n.get_formatted(
+            "strict_unresolved_imports",
+            &[&report.unresolved.len().to_string(), &report.unresolved.join(", "), MAPPINGS_FILE_NAME]
+        )))
+    }
+    
+    /// 只根据导入推断出的PyPI包名，不包含依赖文件中声明的依赖
+    fn inferred_packages(&self) -> Vec<String> {
+        let python_version = self.python_version.as_deref();
+        let mut packages: Vec<String> = self.imported_modules
+            .iter()
+            .filter(|module| !self.local_modules.contains(import_root(module)))
+            .filter(|module| !is_standard_library(import_root(module), python_version))
+            .filter_map(|module| normalize_package_name(module, python_version, &self.package_mappings))
+            .collect();
+        packages.sort();
+        packages.dedup();
+        packages
+    }
+    
+    /// 分三部分显示依赖对比结果
+    fn show_dependency_diff(&self, requirements_file: &str, diff: &DependencyDiff) {
+        println!("\n{}", style(self.i18n.get_formatted("diff_title", &[requirements_file])).bold());
+        
+        let sections = [
+            ("diff_unused", &diff.unused),
+            ("diff_undeclared", &diff.undeclared),
+            ("diff_matching", &diff.matching),
+        ];
+        for (key, packages) in sections {
+            println!("\n{}", self.i18n.get_formatted(key, &[&packages.len().to_string()]));
+            for package in packages {
+                println!("  - {}", package);
+            }
+        }
+    }
+    
+    /// 根据目标Python版本从导入的模块中筛选外部依赖，并合并已声明的依赖
+    ///
+    /// 导入项目自身模块的语句不算外部依赖
+    fn filter_dependencies(&mut self) {
+        let python_version = self.python_version.as_deref();
+        self.dependencies = self.imported_modules
+            .iter()
+            .filter(|module| !self.local_modules.contains(import_root(module)))
+            .filter(|module| !is_standard_library(import_root(module), python_version))
+            .cloned()
+            .collect();
+        
+        for requirement in &self.declared_dependencies {
+            let name = requirement_name(requirement);
+            let inferred = self.dependencies.iter().position(|dep| {
+                normalize_package_name(dep, python_version, &self.package_mappings)
+                    .is_some_and(|package| requirement_name(&package) == name)
+            });
+            
+            // 已推断出的包用带版本约束的声明替换，否则追加
+            match inferred {
+                Some(index) => self.dependencies[index] = requirement.clone(),
+                None => self.dependencies.push(requirement.clone()),
+            }
+        }
+    }
+    
+    /// 以JSON事件输出进度时，为推断出的每个依赖输出dep_found事件
+    fn emit_found_dependencies(&self) {
+        if !progress::json_events() {
+            return;
+        }
+        for requirement in self.requirements() {
+            progress::emit(ProgressEvent::DepFound { name: &requirement_name(&requirement), requirement: &requirement });
+        }
+    }
+    
+    /// 创建虚拟环境使用的Python：指定了--python-path时使用该解释器（按其版本判断标准库），否则选择Python版本
+    fn venv_python(&mut self) -> Result<String> {
+        let Some(python_path) = self.python_path.clone() else {
+            let python_version = self.select_python_version()?;
+            self.set_python_version(&python_version);
+            return Ok(python_version);
+        };
+        
+        validate_python_path(&python_path)?;
+        match interpreter_version(&python_path) {
+            Some(version) => self.set_python_version(&version),
+            None => warn!("{}", style(self.i18n.get_formatted(
+                "python_path_version_unknown",
+                &[&python_path.display().to_string()]
+            )).yellow()),
+        }
+        Ok(python_path.display().to_string())
+    }
+    
+    /// 设置目标Python版本，并据此重新筛选依赖和检查已弃用的标准库模块
+    fn set_python_version(&mut self, python_version: &str) {
+        self.python_version = Some(python_version.to_string());
+        self.filter_dependencies();
+        self.warn_deprecated_stdlib();
+        self.check_requires_python();
+    }
+    
+    /// 目标Python版本已知时，对导入的已弃用或已移除的标准库模块给出警告和替代建议，相同的警告只显示一次
+    fn warn_deprecated_stdlib(&mut self) {
+        let Some(python_version) = self.python_version.as_deref() else {
+            return;
+        };
+        let roots: BTreeSet<&str> = self.imported_modules
+            .iter()
+            .map(|module| import_root(module))
+            .filter(|root| !self.local_modules.contains(*root))
+            .collect();
+        
+        for root in roots {
+            let Some(deprecation) = stdlib_deprecation(root, python_version) else {
+                continue;
+            };
+            let message = if deprecation.removed {
+                self.i18n.get_formatted(
+                    "stdlib_module_removed",
+                    &[root, &deprecation.removal, deprecation.replacement]
+                )
+            } else {
+                self.i18n.get_formatted(
+                    "stdlib_module_deprecated",
+                    &[root, &deprecation.deprecated, &deprecation.removal, deprecation.replacement]
+                )
+            };
+            if self.stdlib_warnings.insert(message.clone()) {
+                warn!("{}", style(message).yellow());
+            }
+        }
+    }
+    
+    /// 指定--check-requires-python且目标Python版本已知时，通过PyPI检查各依赖声明的Python版本要求
+    ///
+    /// 无法访问PyPI时给出一次警告并跳过检查；无法解析的版本要求视为兼容
+    fn check_requires_python(&mut self) {
+        if !self.resolve.check_requires_python {
+            return;
+        }
+        let Some(python_version) = self.python_version.clone() else {
+            return;
+        };
+        let requirements = self.requirements();
+        if requirements.is_empty() {
+            return;
+        }
+        if self.pypi_verifier.is_none() {
+            match PypiIndex::new() {
+                Ok(index) => self.pypi_verifier = Some(PackageVerifier::new(index)),
+                Err(e) => {
+                    warn!("{}", style(self.i18n.get_formatted("requires_python_unchecked", &[&e.to_string()])).yellow());
+                    return;
+                }
+            }
+        }
+        let Some(verifier) = self.pypi_verifier.as_mut() else {
+            return;
+        };
+        let was_offline = verifier.offline_error().is_some();
+        
+        let pb = progress::spinner(self.i18n.get("checking_requires_python"), self.quiet);
+        
+        let names: Vec<String> = requirements.iter().map(|requirement| requirement_name(requirement)).collect();
+        let incompatible = verifier.incompatible_packages(&names, &python_version);
+        pb.finish_and_clear();
+        
+        if let Some(error) = verifier.offline_error().filter(|_| !was_offline) {
+            warn!("{}", style(self.i18n.get_formatted("requires_python_unchecked", &[error])).yellow());
+        }
+        if !incompatible.is_empty() {
+            warn!("{}", style(self.i18n.get_formatted("requires_python_incompatible", &[&python_version])).yellow());
+            for (name, requires_python) in &incompatible {
+                warn!("  - {} (requires-python {})", name, requires_python);
+            }
+        }
+    }
+    
+    /// 当前平台可用的Python版本，优先使用uv查询到的版本，查询失败时使用内置列表
+    fn available_python_versions(&mut self) -> Vec<String> {
+        // 演练模式下不为了查询版本而解压或下载uv
+        if !self.dry_run {
+            match self.uv_manager.list_python_versions() {
+                Ok(versions) => return versions,
+                Err(e) => info!("{}", self.i18n.get_formatted("python_versions_fallback", &[&format!("{:#}", e)])),
+            }
+        }
+        get_supported_python_versions(&self.os_type, &self.os_arch)
+    }
+    
+    /// 基于操作系统和UV支持选择Python版本
+    ///
+    /// 依次使用：命令行指定的版本、项目中声明的版本（.python-version或requires-python），最后交互式询问
+    fn select_python_version(&mut self) -> Result<String> {
+        let versions = self.available_python_versions();
+        
+        if let Some(requested) = &self.python_version {
+            let version = resolve_declared_python_version(requested, &versions).ok_or_else(|| anyhow!(
+                self.i18n.get_formatted("python_version_unsupported", &[requested, &versions.join(", ")])
+            ))?;
+            self.save_python_version(&version);
+            return Ok(version);
+        }
+        
+        // 之前为该项目选择过的版本，不再询问
+        if let Some(saved) = &self.saved_python_version {
+            match resolve_declared_python_version(saved, &versions) {
+                Some(version) => {
+                    println!("{}", self.i18n.get_formatted("using_saved_python", &[&version, CONFIG_FILE_PATH]));
+                    return Ok(version);
+                }
+                None => warn!("{}", self.i18n.get_formatted("saved_python_unsupported", &[saved, CONFIG_FILE_PATH])),
+            }
+        }
+        
+        match parse_declared_python_version(&self.project_dir) {
+            Ok(Some(declared)) => match resolve_declared_python_version(&declared, &versions) {
+                Some(version) => {
+                    println!("{}", self.i18n.get_formatted("using_declared_python", &[&version, &declared]));
+                    return Ok(version);
+                }
+                None => warn!("{}", self.i18n.get_formatted("declared_python_unsupported", &[&declared])),
+            },
+            Ok(None) => {}
+            Err(e) => warn!("{}", self.i18n.get_formatted("declared_python_failed", &[&format!("{:#}", e)])),
+        }
+        
+        self.require_interactive("--python-version 3.11")?;
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(self.i18n.get("select_python_version"))
+            .default(0)
+            .items(&versions)
+            .interact()?;
+        
+        let version = versions[selection].to_string();
+        self.save_python_version(&version);
+        Ok(version)
+    }
+    
+    /// 把用户选择的Python版本保存到项目配置文件，版本未变化或演练模式下不写入，写入失败时只给出警告
+    fn save_python_version(&mut self, version: &str) {
+        if self.dry_run || self.saved_python_version.as_deref() == Some(version) {
+            return;
+        }
+        
+        match write_config_python_version(Path::new(CONFIG_FILE_PATH), version) {
+            Ok(()) => {
+                println!("{}", self.i18n.get_formatted("python_version_saved", &[version, CONFIG_FILE_PATH]));
+                self.saved_python_version = Some(version.to_string());
+            }
+            Err(e) => warn!("{}", self.i18n.get_formatted("python_version_save_failed", &[CONFIG_FILE_PATH, &format!("{:#}", e)])),
+        }
+    }
+    
+    /// 基于所选操作系统为导出选择Python版本
+    fn select_python_version_for_export(&self, os_type: &str, arch: &str) -> Result<String> {
+        let versions = get_supported_python_versions(os_type, arch);
+        
+        let selection = Select::with_theme(&ColorfulTheme::default())
+            .with_prompt(self.i18n.get("select_python_version"))
+            .default(0)
+            .items(&versions)
+            .interact()?;
+            
+        Ok(versions[selection].to_string())
+    }
+    
+    /// 分析已读入内存的源代码（如从标准输入读取的脚本或归档中的文件）的导入，不读取项目的依赖声明文件
+    ///
+    /// `sources`为（文件名, 源代码）列表，文件名用于识别本地模块和记录导入来源
+    fn extract_dependencies_from_sources(&mut self, sources: &[(String, &str)]) -> Result<DependencyReport> {
+        self.python_files = sources.iter().map(|(file, _)| file.clone()).collect();
+        for file in &self.python_files {
+            progress::emit(ProgressEvent::File { path: file });
+        }
+        self.module_sources.clear();
+        self.imported_modules.clear();
+        for (file, source) in sources {
+            for module in source_modules(source, self.scan.include_type_checking, &self.package_mappings) {
+                if is_build_script_import(Path::new(file), &module) {
+                    continue;
+                }
+                let files = self.module_sources.entry(module.clone()).or_default();
+                if !files.contains(file) {
+                    files.push(file.clone());
+                }
+                self.imported_modules.push(module);
+            }
+        }
+        self.imported_modules.sort();
+        self.imported_modules.dedup();
+        
+        self.local_modules = local_module_names(Path::new(""), &self.python_files);
+        self.declared_dependencies.clear();
+        self.filter_dependencies();
+        self.emit_found_dependencies();
+        self.warn_deprecated_stdlib();
+        
+        Ok(self.dependency_report())
+    }
+    
+    /// 读取归档中的Python文件并分析导入，不解压到磁盘
+    fn extract_dependencies_from_archive(&mut self, archive: &str) -> Result<DependencyReport> {
+        progress::emit(ProgressEvent::ScanStart { path: archive });
+        let sources = read_archive_sources(Path::new(archive), &self.scan_config())?;
+        if !self.quiet {
+            println!("\n{}", self.i18n.get_formatted("scanning_dir", &[archive]));
+            println!("{}", self.i18n.get_formatted("python_file_count", &[&sources.len().to_string()]));
+        }
+        
+        let texts: Vec<(String, &str)> = sources
+            .iter()
+            .map(|source| (source.path.clone(), source.content.text()))
+            .collect();
+        let report = self.extract_dependencies_from_sources(&texts)?;
+        
+        let oversized: Vec<&String> = sources
+            .iter()
+            .filter(|source| source.content.is_oversized())
+            .map(|source| &source.path)
+            .collect();
+        self.warn_oversized_files(&oversized);
+        Ok(report)
+    }
+    
+    /// 从提取的依赖生成依赖文件，按格式写入requirements.txt或pyproject.toml
+    ///
+    /// `output_file`为完整的文件路径，`annotate`为true时，requirements.txt中每个依赖后会注释引用它的源文件。
+    /// 返回写入的依赖（经过PyPI校验和审查之后）
+    fn generate_requirements_file(&self, output_file: &Path, format: OutputFormat, annotate: bool) -> Result<Vec<String>> {
+        let mut requirements = self.requirements();
+        if self.verify_pypi {
+            requirements = self.verify_requirements(requirements)?;
+        }
+        if self.review && !requirements.is_empty() {
+            requirements = self.review_requirements(requirements)?;
+        }
+        
+        let outputs = if self.split_dev {
+            self.split_dev_requirements(output_file, &requirements)
+        } else {
+            vec![(output_file.to_path_buf(), requirements.clone())]
+        };
+        if self.explain {
+            self.show_provenance(&requirements);
+        }
+        
+        if self.dry_run {
+            for (file, file_requirements) in &outputs {
+                println!("{}", self.i18n.get_formatted("dry_run_write_file", &[&file.display().to_string()]));
+                for requirement in file_requirements {
+                    println!("  {}", requirement);
+                }
+            }
+            return Ok(requirements);
+        }
+        
+        match format {
+            OutputFormat::Requirements => {
+                let sources = annotate.then(|| self.package_sources());
+                for (file, file_requirements) in &outputs {
+                    if !self.confirm_overwrite(file)? {
+                        println!("{}", self.i18n.get_formatted("requirements_kept", &[&file.display().to_string()]));
+                        continue;
+                    }
+                    let previous = fs::read_to_string(file).ok();
+                    write_requirements_txt(file, file_requirements, sources.as_ref(), !self.no_header, &self.i18n)?;
+                    
+                    // 覆盖已有文件时显示变化，在终端中运行时默认显示
+                    if let Some(previous) = previous.filter(|_| self.show_diff || (is_interactive() && !self.quiet)) {
+                        let current = fs::read_to_string(file)
+                            .context(format!("无法读取{}文件", file.display()))?;
+                        self.print_requirements_diff(file, &previous, &current);
+                    }
+                }
+            },
+            OutputFormat::Pyproject => write_pyproject_dependencies(output_file, &requirements, &self.i18n)?,
+        }
+        Ok(requirements)
+    }
+    
+    /// 按行对比依赖文件的新旧内容，删除的行显示为红色，新增的行显示为绿色
+    ///
+    /// 生成标记所在的行（包含版本和日期）不参与对比
+    fn print_requirements_diff(&self, file: &Path, previous: &str, current: &str) {
+        let previous_lines = requirements_diff_lines(previous);
+        let current_lines = requirements_diff_lines(current);
+        let removed: Vec<&str> = previous_lines.iter().copied().filter(|line| !current_lines.contains(line)).collect();
+        let added: Vec<&str> = current_lines.iter().copied().filter(|line| !previous_lines.contains(line)).collect();
+        
+        if removed.is_empty() && added.is_empty() {
+            println!("{}", self.i18n.get_formatted("requirements_unchanged", &[&file.display().to_string()]));
+            return;
+        }
+        
+        println!("{}", self.i18n.get_formatted("requirements_diff", &[&file.display().to_string()]));
+        for line in removed {
+            println!("{}", style(format!("-{}", line)).red());
+        }
+        for line in added {
+            println!("{}", style(format!("+{}", line)).green());
+        }
+    }
+    
+    /// 把依赖分为运行时依赖和开发依赖，返回要写入的文件及其依赖
+    ///
+    /// 只在测试代码中导入的包写入同目录下的requirements-dev.txt；同时在其他代码中导入的包和依赖文件中声明的包写入`output_file`
+    fn split_dev_requirements(&self, output_file: &Path, requirements: &[String]) -> Vec<(PathBuf, Vec<String>)> {
+        let sources = self.package_sources();
+        let (dev, prod): (Vec<String>, Vec<String>) = requirements
+            .iter()
+            .cloned()
+            .partition(|requirement| {
+                sources
+                    .get(&requirement_name(requirement))
+                    .is_some_and(|files| !files.is_empty() && files.iter().all(|file| is_test_file(file)))
+            });
+        
+        vec![
+            (output_file.to_path_buf(), prod),
+            (output_file.with_file_name(DEV_REQUIREMENTS_FILE), dev),
+        ]
+    }
+    
+    /// 要覆盖的requirements.txt不是PyWand生成时，先确认再把原文件备份为.bak，返回false表示保留原文件
+    ///
+    /// 使用--overwrite时不询问；非交互环境中没有--overwrite时返回错误
+    fn confirm_overwrite(&self, path: &Path) -> Result<bool> {
+        if !path.exists() {
+            return Ok(true);
+        }
+        let content = fs::read_to_string(path)
+            .context(format!("无法读取{}文件", path.display()))?;
+        if content.trim().is_empty() || is_generated_requirements(&content) {
+            return Ok(true);
+        }
+        
+        let mut backup = path.as_os_str().to_owned();
+        backup.push(".bak");
+        let backup = PathBuf::from(backup);
+        if !self.overwrite {
+            self.require_interactive("--overwrite")?;
+            let prompt = self.i18n.get_formatted(
+                "confirm_overwrite_requirements",
+                &[&path.display().to_string(), &backup.display().to_string()]
+            );
+            let proceed = Confirm::with_theme(&ColorfulTheme::default())
+                .with_prompt(prompt)
+                .default(false)
+                .interact()?;
+            if !proceed {
+                return Ok(false);
+            }
+        }
+        
+        fs::copy(path, &backup)
+            .context(format!("无法备份{}文件", path.display()))?;
+        println!("{}", self.i18n.get_formatted("requirements_backed_up", &[&backup.display().to_string()]));
+        Ok(true)
+    }
+    
+    /// 依赖对应的PyPI包名，已排序去重
+    fn requirements(&self) -> Vec<String> {
+        let requirements: Vec<String> = self.dependencies
+            .iter()
+            .filter_map(|dep| {
+                normalize_package_name(dep, self.python_version.as_deref(), &self.package_mappings)
+            })
+            .collect();
+        sort_requirements(requirements)
+    }
+    
+    /// 将新检测到的依赖追加到已有的requirements.txt，已有的条目（包括固定的版本）保持不变
+    fn merge_requirements_file(&self, target_dir: &Path) -> Result<()> {
+        let requirements_path = target_dir.join("requirements.txt");
+        let mut content = fs::read_to_string(&requirements_path)
+            .context(format!("无法读取{}文件", requirements_path.display()))?;
+        
+        // 包括-r引用的文件中已声明的依赖
+        let existing: Vec<String> = read_requirements_file(&requirements_path)?
+            .iter()
+            .map(|requirement| requirement_name(requirement))
+            .collect();
+        let added: Vec<String> = self.requirements()
+            .into_iter()
+            .filter(|requirement| !existing.contains(&requirement_name(requirement)))
+            .collect();
+        
+        if added.is_empty() {
+            return Ok(());
+        }
+        
+        println!("{}", self.i18n.get("requirements_added"));
+        for requirement in &added {
+            println!("  + {}", requirement);
+        }
+        
+        if self.dry_run {
+            println!("{}", self.i18n.get_formatted("dry_run_write_file", &[&requirements_path.display().to_string()]));
+            return Ok(());
+        }
+        
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        for requirement in &added {
+            content.push_str(requirement);
+            content.push('\n');
+        }
+        fs::write(&requirements_path, content)
+            .context(format!("无法写入{}文件", requirements_path.display()))?;
+        
+        Ok(())
+    }
+    
+    /// 通过多选列表让用户确认依赖，默认全部勾选，只保留勾选的包
+    fn review_requirements(&self, requirements: Vec<String>) -> Result<Vec<String>> {
+        let selected = MultiSelect::with_theme(&ColorfulTheme::default())
+            .with_prompt(self.i18n.get("review_dependencies"))
+            .items(&requirements)
+            .defaults(&vec![true; requirements.len()])
+            .interact()?;
+        
+        let (approved, rejected) = partition_selected(requirements, &selected);
+        for requirement in &rejected {
+            println!("  - {}", style(requirement).dim());
+        }
+        Ok(approved)
+    }
+    
+    /// 通过PyPI校验推断出的包名，移除不存在的包；项目中已声明的依赖不做校验
+    ///
+    /// 无法访问PyPI时保留所有包名并给出警告
+    fn verify_requirements(&self, requirements: Vec<String>) -> Result<Vec<String>> {
+        let declared: Vec<String> = self.declared_dependencies.iter().map(|dep| requirement_name(dep)).collect();
+        let mut verifier = PackageVerifier::new(PypiIndex::new()?);
+        
+        let pb = progress::spinner(self.i18n.get("verifying_pypi"), self.quiet);
+        
+        let mut verified = Vec::new();
+        let mut missing = Vec::new();
+        for requirement in requirements {
+            let name = requirement_name(&requirement);
+            if declared.contains(&name) {
+                verified.push(requirement);
+                continue;
+            }
+            
+            pb.tick();
+            match verifier.check(&name) {
+                PackageStatus::Missing => missing.push(requirement),
+                PackageStatus::Exists | PackageStatus::Unknown => verified.push(requirement),
+            }
+        }
+        pb.finish_and_clear();
+        
+        if let Some(error) = verifier.offline_error() {
+            warn!("{}", style(self.i18n.get_formatted("pypi_unreachable", &[error])).yellow());
+        }
+        if !missing.is_empty() {
+            warn!("{}", style(self.i18n.get("pypi_packages_missing")).yellow());
+            for requirement in &missing {
+                warn!("  - {}", requirement);
+            }
+        }
+        
+        Ok(verified)
+    }
+    
+    /// 每个依赖（规范化的包名）的来源标记：inferred、mapping或declared:<文件>
+    fn requirement_provenance(&self) -> HashMap<String, Vec<String>> {
+        let python_version = self.python_version.as_deref();
+        let mut provenance: HashMap<String, Vec<String>> = HashMap::new();
+        let mut add = |name: String, tag: String| {
+            let tags = provenance.entry(name).or_default();
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        };
+        
+        for module in &self.imported_modules {
+            let root = import_root(module);
+            if self.local_modules.contains(root) || is_standard_library(root, python_version) {
+                continue;
+            }
+            let Some(package) = normalize_package_name(module, python_version, &self.package_mappings) else {
+                continue;
+            };
+            let tag = if self.package_mappings.contains_key(module) { "mapping" } else { "inferred" };
+            add(requirement_name(&package), tag.to_string());
+        }
+        for (name, sources) in &self.declared_sources {
+            for source in sources {
+                add(name.clone(), format!("declared:{}", source));
+            }
+        }
+        
+        provenance
+    }
+    
+    /// 列出每个依赖及其来源
+    fn show_provenance(&self, requirements: &[String]) {
+        let provenance = self.requirement_provenance();
+        let width = requirements.iter().map(|requirement| requirement.len()).max().unwrap_or(0);
+        
+        println!("\n{}", style(self.i18n.get("requirement_provenance")).bold());
+        for requirement in requirements {
+            let tags = provenance
+                .get(&requirement_name(requirement))
+                .map(|tags| tags.join(", "))
+                .unwrap_or_default();
+            println!("  {:<width$}  [{}]", requirement, tags, width = width);
+        }
+    }
+    
+    /// 按包名（小写）汇总导入该包的源文件，文件列表已排序
+    fn package_sources(&self) -> HashMap<String, Vec<String>> {
+        let mut package_sources: HashMap<String, Vec<String>> = HashMap::new();
+        
+        for (module, files) in &self.module_sources {
+            let Some(package) = normalize_package_name(module, self.python_version.as_deref(), &self.package_mappings) else {
+                continue;
+            };
+            let sources = package_sources.entry(requirement_name(&package)).or_default();
+            for file in files {
+                if !sources.contains(file) {
+                    sources.push(file.clone());
+                }
+            }
+        }
+        
+        for sources in package_sources.values_mut() {
+            sources.sort();
+        }
+        
+        package_sources
+    }
+}
+
+/// 依赖声明中的包名和extras，去掉版本约束和环境标记，如"uvicorn[standard]>=0.30" -> "uvicorn[standard]"
+fn requirement_with_extras(requirement: &str) -> &str {
+    let end = requirement
+        .find(|c: char| "<>=!~;@ ".contains(c))
+        .unwrap_or(requirement.len());
+    requirement[..end].trim()
+}
+
+/// 在requirements.txt内容中加入一行依赖：已有的依赖按包名排序时插入到对应位置，否则追加到末尾
+fn insert_requirement(content: &str, requirement: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let is_requirement = |line: &str| {
+        let line = line.trim();
+        !line.is_empty() && !line.starts_with('#') && !line.starts_with('-')
+    };
+    let positions: Vec<usize> = (0..lines.len()).filter(|&i| is_requirement(lines[i])).collect();
+    let names: Vec<String> = positions.iter().map(|&i| requirement_name(lines[i])).collect();
+    
+    let name = requirement_name(requirement);
+    let sorted = names.windows(2).all(|pair| pair[0] <= pair[1]);
+    let index = if sorted {
+        positions
+            .iter()
+            .zip(&names)
+            .find(|(_, existing)| **existing > name)
+            .map(|(&i, _)| i)
+    } else {
+        None
+    };
+    let index = index.unwrap_or_else(|| positions.last().map(|&i| i + 1).unwrap_or(lines.len()));
+    lines.insert(index, requirement);
+    
+    let mut result = lines.join("\n");
+    result.push('\n');
+    result
+}
+
+/// 按规范化的包名排序并去重（Flask、flask和FLASK视为同一个包），同名时保留带版本约束的声明
+fn sort_requirements(mut requirements: Vec<String>) -> Vec<String> {
+    requirements.sort_by_cached_key(|requirement| (requirement_name(requirement), requirement.clone()));
+    requirements.dedup_by(|later, kept| {
+        if requirement_name(later) != requirement_name(kept) {
+            return false;
+        }
+        let is_bare = |requirement: &str| !requirement.contains(|c: char| "<>=!~;[@".contains(c));
+        if is_bare(kept) && !is_bare(later) {
+            std::mem::swap(later, kept);
+        }
+        true
+    });
+    requirements
+}
+
+/// 1KB和1MB的字节数
+const KB: u64 = 1024;
+const MB: u64 = 1024 * KB;
+
+/// 注释中最多列出的源文件数量
+const MAX_ANNOTATED_SOURCES: usize = 3;
+
+/// 使用--split-dev时开发依赖的文件名
+const DEV_REQUIREMENTS_FILE: &str = "requirements-dev.txt";
+
+/// 存放测试代码的目录名
+const TEST_DIRS: &[&str] = &["tests", "test"];
+
+/// 将依赖写入requirements.txt，提供sources时在每行后注释引用该包的源文件
+///
+/// header为true时第一行在生成标记后附上PyWand版本和生成日期，否则只写生成标记
+fn write_requirements_txt(
+    requirements_path: &Path,
+    requirements: &[String],
+    sources: Option<&HashMap<String, Vec<String>>>,
+    header: bool,
+    i18n: &I18n,
+) -> Result<()> {
+    // 统一使用\n换行，每行（包括最后一行）都以换行结尾；第一行以生成标记开头，用于区分用户维护的文件
+    let mut content = if header {
+        format!("{} v{} on {}\n", GENERATED_MARKER, env!("CARGO_PKG_VERSION"), today_utc())
+    } else {
+        format!("{}\n", GENERATED_MARKER)
+    };
+    
+    for requirement in requirements {
+        let files = sources.and_then(|sources| sources.get(&requirement_name(requirement)));
+        match files {
+            Some(files) if !files.is_empty() => {
+                content.push_str(&format!("{}  # used in: {}\n", requirement.trim(), format_sources(files, i18n)));
+            }
+            _ => content.push_str(&format!("{}\n", requirement.trim())),
+        }
+    }
+    
+    create_parent_dirs(requirements_path)?;
+    fs::write(requirements_path, content)
+        .context(format!("无法写入{}文件", requirements_path.display()))?;
+        
+    let req_created_msg = i18n.get_formatted("requirements_created", &[&requirements_path.display().to_string()]);
+    println!("{}", style(req_created_msg).bold().green());
+    
+    Ok(())
+}
+
+/// 依赖文件中参与对比的行：跳过空行和生成标记所在的行
+fn requirements_diff_lines(content: &str) -> Vec<&str> {
+    content
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty() && !line.starts_with(GENERATED_MARKER))
+        .collect()
+}
+
+/// 当前的UTC日期（YYYY-MM-DD）
+fn today_utc() -> String {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+    
+    date_from_unix_days((seconds / 86400) as i64)
+}
+
+/// 把1970-01-01起的天数换算为公历日期（YYYY-MM-DD）
+fn date_from_unix_days(days: i64) -> String {
+    // 以3月为一年的开始，闰日落在一年的最后，每400年为一个周期
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// conda环境文件名
+const CONDA_ENVIRONMENT_FILE: &str = "environment.yml";
+
+/// PyPI包名与conda-forge包名不同的常见包，这些包由conda安装，其余依赖通过pip安装
+const CONDA_PACKAGE_NAMES: &[(&str, &str)] = &[
+    ("torch", "pytorch"),
+    ("opencv-python", "opencv"),
+    ("opencv-python-headless", "opencv"),
+    ("tables", "pytables"),
+    ("msgpack", "msgpack-python"),
+    ("pyqt5", "pyqt"),
+    ("psycopg2-binary", "psycopg2"),
+];
+
+/// 把依赖转换为conda的包规格，只有存在名称映射且不带extras、环境标记或URL的依赖才能转换
+fn conda_package_spec(requirement: &str) -> Option<String> {
+    let requirement = requirement.trim();
+    if requirement.contains(|c| ";[@".contains(c)) {
+        return None;
+    }
+    
+    let name = requirement_name(requirement);
+    let (_, conda_name) = CONDA_PACKAGE_NAMES.iter().find(|(pypi_name, _)| *pypi_name == name)?;
+    let version_start = requirement.find(|c| "<>=!~ ".contains(c)).unwrap_or(requirement.len());
+    Some(format!("{}{}", conda_name, requirement[version_start..].trim()))
+}
+
+/// YAML中的字符串值，含有特殊字符时使用单引号
+fn yaml_string(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || "._-<>=!~,+ ".contains(c))
+        && !value.starts_with(|c: char| "-<>=!~,+ ".contains(c));
+    
+    if plain {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+}
+
+/// 生成conda的environment.yml：Python版本和有conda包名映射的依赖放在dependencies下，其余依赖放在pip:下
+fn write_conda_environment(
+    environment_path: &Path,
+    name: &str,
+    python_version: &str,
+    requirements: &[String],
+    i18n: &I18n,
+) -> Result<()> {
+    let mut conda_packages = Vec::new();
+    let mut pip_packages = Vec::new();
+    for requirement in requirements {
+        match conda_package_spec(requirement) {
+            Some(spec) => conda_packages.push(spec),
+            None => pip_packages.push(requirement.trim().to_string()),
+        }
+    }
+    
+    let mut content = format!("name: {}\nchannels:\n  - conda-forge\ndependencies:\n", yaml_string(name));
+    content.push_str(&format!("  - python={}\n", python_version));
+    for package in &conda_packages {
+        content.push_str(&format!("  - {}\n", yaml_string(package)));
+    }
+    if !pip_packages.is_empty() {
+        content.push_str("  - pip\n  - pip:\n");
+        for package in &pip_packages {
+            content.push_str(&format!("      - {}\n", yaml_string(package)));
+        }
+    }
+    
+    create_parent_dirs(environment_path)?;
+    fs::write(environment_path, content)
+        .context(format!("无法写入{}文件", environment_path.display()))?;
+    
+    println!("{}", i18n.get("conda_environment_created"));
+    Ok(())
+}
+
+/// 项目名称，取项目目录名，无法获取时使用pywand
+fn project_name(project_dir: &Path) -> String {
+    fs::canonicalize(project_dir)
+        .ok()
+        .and_then(|dir| dir.file_name().map(|name| name.to_string_lossy().into_owned()))
+        .unwrap_or_else(|| "pywand".to_string())
+}
+
+/// 从标准输入读取的源代码在输出中使用的文件名
+const STDIN_SOURCE_NAME: &str = "<stdin>";
+
+/// 按选中的下标把依赖分为保留和移除两部分，均保持原有顺序
+fn partition_selected(requirements: Vec<String>, selected: &[usize]) -> (Vec<String>, Vec<String>) {
+    let (approved, rejected): (Vec<_>, Vec<_>) = requirements
+        .into_iter()
+        .enumerate()
+        .partition(|(index, _)| selected.contains(index));
+    
+    (
+        approved.into_iter().map(|(_, requirement)| requirement).collect(),
+        rejected.into_iter().map(|(_, requirement)| requirement).collect(),
+    )
+}
+
+/// 标准输入和标准输出都连接到终端时才能显示交互式提示（管道和CI中不能）
+fn is_interactive() -> bool {
+    std::io::stdin().is_terminal() && console::user_attended()
+}
+
+/// 检查--python-path指定的解释器存在且可以执行
+fn validate_python_path(path: &Path) -> Result<()> {
+    if !path.is_file() {
+        return Err(anyhow!("找不到Python解释器: {}", path.display()));
+    }
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(path)
+            .context(format!("无法读取Python解释器信息: {}", path.display()))?
+            .permissions()
+            .mode();
+        if mode & 0o111 == 0 {
+            return Err(anyhow!("Python解释器没有执行权限: {}", path.display()));
         }
-        
-        Ok(())
     }
+    Ok(())
+}
+
+/// 运行解释器查询其版本号（python --version的输出，如Python 3.11.4），失败时返回None
+fn interpreter_version(path: &Path) -> Option<String> {
+    let output = Command::new(path).arg("--version").output().ok()?;
+    // Python 3.4之前的版本把版本号输出到stderr
+    let text = format!("{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+    text.trim()
+        .strip_prefix("Python ")
+        .map(|version| version.trim().to_string())
+        .filter(|version| version.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+/// 创建文件所在的目录（不存在时）
+fn create_parent_dirs(path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .context(format!("无法创建目录: {}", parent.display()))?;
+    }
+    Ok(())
+}
+
+/// 解析gen-req的--output：requirements格式下以.txt结尾时视为文件路径，否则视为目录并使用默认文件名
+fn requirements_output_path(output: &str, format: OutputFormat) -> PathBuf {
+    let path = PathBuf::from(output);
+    let is_file = match format {
+        OutputFormat::Requirements => path.extension().is_some_and(|ext| ext == "txt"),
+        OutputFormat::Pyproject => false,
+    };
     
-    /// 基于操作系统和UV支持选择Python版本
-    fn select_python_version(&self) -> Result<String> {
-        let versions = get_supported_python_versions(&self.os_type, &self.os_arch);
-        
-        let selection = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt(self.i18n.get("select_python_version"))
-            .default(0)
-            .items(&versions)
-            .interact()?;
-            
-        Ok(versions[selection].to_string())
+    if is_file {
+        path
+    } else {
+        path.join(format.file_name())
     }
+}
+
+/// 判断源文件（相对于扫描目录，以/分隔）是否为测试代码：位于tests/或test/目录中，或者是conftest.py、test_*.py、*_test.py
+fn is_test_file(path: &str) -> bool {
+    let mut components: Vec<&str> = path.split('/').collect();
+    let file_name = components.pop().unwrap_or(path);
     
-    /// 基于所选操作系统为导出选择Python版本
-    fn select_python_version_for_export(&self, os_index: usize) -> Result<String> {
-        let os_type = match os_index {
-            0 | 1 => "windows7",
-            2 | 3 => "windows10", 
-            4 => "windows11",
-            5 => "windowsserver",
-            _ => "windows10", // 默认
-        };
-        
-        let arch = match os_index {
-            0 | 2 => "x86",
-            _ => "x64",
-        };
-        
-        let versions = get_supported_python_versions(os_type, arch);
-        
-        let selection = Select::with_theme(&ColorfulTheme::default())
-            .with_prompt(self.i18n.get("select_python_version"))
-            .default(0)
-            .items(&versions)
-            .interact()?;
-            
-        Ok(versions[selection].to_string())
+    components.iter().any(|dir| TEST_DIRS.contains(dir))
+        || file_name == "conftest.py"
+        || file_name.starts_with("test_")
+        || file_name.ends_with("_test.py")
+}
+
+/// 格式化源文件列表，文件过多时只列出前几个
+fn format_sources(files: &[String], i18n: &I18n) -> String {
+    if files.len() <= MAX_ANNOTATED_SOURCES {
+        return files.join(", ");
     }
     
-    /// 从提取的依赖生成requirements.txt文件
-    fn generate_requirements_file(&self, target_dir: &str) -> Result<()> {
-        let mut content = String::new();
-        
-        for dep in &self.dependencies {
-            if let Some(normalized_dep) = normalize_package_name(dep) {
-                content.push_str(&format!("{}\n", normalized_dep));
-            }
+    i18n.get_formatted(
+        "more_sources",
+        &[&files[..MAX_ANNOTATED_SOURCES].join(", "), &(files.len() - MAX_ANNOTATED_SOURCES).to_string()]
+    )
+}
+
+/// 将依赖写入pyproject.toml的[project] dependencies，已存在的文件会合并而不是覆盖
+fn write_pyproject_dependencies(pyproject_path: &Path, requirements: &[String], i18n: &I18n) -> Result<()> {
+    let existing = if pyproject_path.exists() {
+        fs::read_to_string(pyproject_path)
+            .context(format!("无法读取{}文件", pyproject_path.display()))?
+    } else {
+        String::new()
+    };
+    
+    let mut document = existing.parse::<DocumentMut>()
+        .context(format!("无法解析{}文件", pyproject_path.display()))?;
+    
+    let project = document
+        .entry("project")
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_mut()
+        .ok_or_else(|| anyhow!("{}中的project不是表", pyproject_path.display()))?;
+    
+    let dependencies = project
+        .entry("dependencies")
+        .or_insert_with(|| Item::Value(Value::Array(Array::new())))
+        .as_array_mut()
+        .ok_or_else(|| anyhow!("{}中的project.dependencies不是数组", pyproject_path.display()))?;
+    
+    // 已声明的包保留原样（包括版本约束），只追加新发现的包
+    let declared: Vec<String> = dependencies
+        .iter()
+        .filter_map(|dep| dep.as_str())
+        .map(requirement_name)
+        .collect();
+    
+    for requirement in requirements {
+        if !declared.contains(&requirement_name(requirement)) {
+            dependencies.push(requirement.as_str());
         }
-        
-        let requirements_path = format!("{}/requirements.txt", target_dir.trim_end_matches('/'));
-        
-        fs::write(&requirements_path, content)
-            .context(format!("无法写入{}文件", requirements_path))?;
-            
-        // 直接使用字符串格式化而不是i18n.get_formatted
-        let req_created_msg = format!("创建了requirements.txt文件在 {}", target_dir);
-        println!("{}", style(req_created_msg).bold().green());
-        
-        Ok(())
     }
+    
+    // 每个依赖单独一行，便于阅读和比较
+    for dep in dependencies.iter_mut() {
+        dep.decor_mut().set_prefix("\n    ");
+    }
+    dependencies.set_trailing("\n");
+    dependencies.set_trailing_comma(true);
+    
+    create_parent_dirs(pyproject_path)?;
+    fs::write(pyproject_path, document.to_string())
+        .context(format!("无法写入{}文件", pyproject_path.display()))?;
+    
+    println!("{}", style(i18n.get_formatted("pyproject_updated", &[&pyproject_path.display().to_string()])).bold().green());
+    
+    Ok(())
 }
 
 /// 确定操作系统类型
@@ -540,61 +2889,465 @@ fn determine_os_arch() -> String {
     }
 }
 
-/// 检查模块是否是Python标准库的一部分
-fn is_standard_library(module: &str) -> bool {
-    // 扩展的Python标准库列表
-    let std_libs = vec![
-        "os", "sys", "re", "math", "json", "time", "datetime", "random", 
-        "collections", "itertools", "functools", "pathlib", "subprocess",
-        "typing", "abc", "argparse", "enum", "logging", "io", "csv",
-        "__future__", "site", "threading", "importlib", "runpy", 
-        "asyncio", "base64", "calendar", "contextlib", "copy", "dataclasses",
-        "decimal", "difflib", "email", "hashlib", "html", "http", "inspect",
-        "ipaddress", "multiprocessing", "operator", "platform", "pprint",
-        "queue", "shutil", "signal", "socket", "sqlite3", "ssl", "statistics",
-        "string", "struct", "tempfile", "textwrap", "unittest", "urllib",
-        "uuid", "warnings", "xml", "zipfile", "zlib", "builtins", "codecs",
-        "traceback", "pickle", "gzip", "array", "bisect", "configparser", 
-        "context", "ctypes", "distutils", "fnmatch", "fractions", "ftplib",
-        "getpass", "gettext", "glob", "heapq", "imp", "keyword", "marshal",
-        "mimetypes", "numbers", "optparse", "posixpath", "profile", "pwd",
-        "shelve", "smtplib", "symtable", "sysconfig", "tarfile", "telnetlib",
-        "token", "turtle", "uu", "weakref", "winreg"
-    ];
-    
-    std_libs.contains(&module)
-}
-
-/// 将模块名称转换为正确的PyPI包名或过滤掉无效的包名
-fn normalize_package_name(module: &str) -> Option<String> {
-    // 已知的PyPI包名映射
-    let package_mappings = [
-        ("yaml", "PyYAML"),
-        ("PIL", "Pillow"),
-        ("bs4", "beautifulsoup4"),
-        ("sklearn", "scikit-learn"),
-    ];
-    
-    // 返回已知映射的包名
-    for (mod_name, pkg_name) in &package_mappings {
-        if module == *mod_name {
-            return Some(pkg_name.to_string());
-        }
-    }
-    
-    // 检查是否是无效的包名（单个字符、下划线开头等）
-    if module.len() <= 1 || module.starts_with('_') || is_standard_library(module) ||
-       ["name", "the", "header", "REPL", "code", "types", "stat", "line", "inline", 
-        "another", "all", "values", "its", "regular", "each", "within", "working", 
-        "source", "on", "what", "an", "multiple", "being", "that", "this", "inside", 
-        "one", "floats", "those", "limited_api1", "limited_api_latest", "limited_api2", 
-        "array_interface_testing", "mem_policy", "checks", "1", "0", "left", "lowest", 
-        "pairs", "t2", "it", "outside", "running"].contains(&module) {
+lazy_static! {
+    // 映射中的包名，可以带extras，如 uvicorn[standard] 或 celery[redis,msgpack]
+    static ref PACKAGE_SPEC_RE: Regex = Regex::new(
+        r"^[A-Za-z0-9](?:[A-Za-z0-9._-]*[A-Za-z0-9])?(?:\[\s*[A-Za-z0-9][A-Za-z0-9._-]*(?:\s*,\s*[A-Za-z0-9][A-Za-z0-9._-]*)*\s*\])?$"
+    ).unwrap();
+}
+
+/// 用户自定义包名映射文件的文件名
+const MAPPINGS_FILE_NAME: &str = "pywand-mappings.json";
+
+/// 加载包名映射：以内置映射为默认值，依次用配置目录和当前目录中的pywand-mappings.json覆盖
+fn load_package_mappings(i18n: &I18n) -> HashMap<String, String> {
+    let mut mappings = default_package_mappings();
+    
+    let mut candidates = Vec::new();
+    if let Some(config_dir) = dirs::config_dir() {
+        candidates.push(config_dir.join("pywand").join(MAPPINGS_FILE_NAME));
+    }
+    candidates.push(PathBuf::from(MAPPINGS_FILE_NAME));
+    
+    for path in candidates.iter().filter(|path| path.exists()) {
+        match read_mappings_file(path, i18n) {
+            Ok(user_mappings) => mappings.extend(user_mappings),
+            Err(e) => warn!("{}", i18n.get_formatted(
+                "mappings_load_failed",
+                &[&path.display().to_string(), &format!("{:#}", e)]
+            )),
+        }
+    }
+    
+    mappings
+}
+
+/// 项目配置文件中的设置
+struct ProjectConfig {
+    excluded_dirs: Vec<String>, // 合并默认值后的排除目录
+    python_version: Option<String>, // 之前为项目选择的Python版本
+    language: Option<Language>, // 项目使用的界面语言
+    unknown_keys: Vec<String>, // 无法识别的配置项
+}
+
+impl Default for ProjectConfig {
+    fn default() -> Self {
+        ProjectConfig {
+            excluded_dirs: DEFAULT_EXCLUDED_DIRS.iter().map(|dir| dir.to_string()).collect(),
+            python_version: None,
+            language: None,
+            unknown_keys: Vec::new(),
+        }
+    }
+}
+
+/// 加载项目配置文件，配置文件无效时给出警告并使用默认值
+fn load_project_config(i18n: &I18n) -> ProjectConfig {
+    let path = Path::new(CONFIG_FILE_PATH);
+    if !path.exists() {
+        return ProjectConfig::default();
+    }
+    
+    match read_config_file(path, i18n) {
+        Ok(config) => {
+            for key in &config.unknown_keys {
+                warn!("{}", i18n.get_formatted("config_unknown_key", &[key, CONFIG_FILE_PATH]));
+            }
+            config
+        }
+        Err(e) => {
+            warn!("{}", i18n.get_formatted("config_load_failed", &[CONFIG_FILE_PATH, &format!("{:#}", e)]));
+            ProjectConfig::default()
+        }
+    }
+}
+
+/// 配置文件中可以识别的配置项
+const CONFIG_KEYS: &[&str] = &["exclude_dirs", "exclude_dirs_mode", "python_version", "language"];
+
+/// 读取TOML格式的项目配置文件
+///
+/// exclude_dirs为目录名列表，exclude_dirs_mode为extend（默认，追加到默认值）或override（替换默认值），
+/// python_version为之前选择的Python版本，language为项目使用的界面语言代码
+fn read_config_file(path: &Path, i18n: &I18n) -> Result<ProjectConfig> {
+    let content = fs::read_to_string(path)
+        .context(i18n.get("file_read_failed").to_string())?;
+    let document = content.parse::<DocumentMut>()
+        .context(i18n.get("file_format_invalid").to_string())?;
+    
+    let mut dirs = Vec::new();
+    if let Some(item) = document.get("exclude_dirs") {
+        let not_string_list = || anyhow!(i18n.get_formatted("config_value_not_string_list", &["exclude_dirs"]));
+        let array = item.as_array().ok_or_else(not_string_list)?;
+        for value in array.iter() {
+            let dir = value.as_str().ok_or_else(not_string_list)?;
+            dirs.push(dir.to_string());
+        }
+    }
+    
+    let override_defaults = match document.get("exclude_dirs_mode").map(|item| item.as_str()) {
+        None | Some(Some("extend")) => false,
+        Some(Some("override")) => true,
+        Some(_) => return Err(anyhow!(i18n.get("config_invalid_exclude_mode").to_string())),
+    };
+    
+    let mut excluded_dirs: Vec<String> = if override_defaults {
+        Vec::new()
+    } else {
+        DEFAULT_EXCLUDED_DIRS.iter().map(|dir| dir.to_string()).collect()
+    };
+    for dir in dirs {
+        if !excluded_dirs.contains(&dir) {
+            excluded_dirs.push(dir);
+        }
+    }
+    
+    let python_version = match document.get("python_version") {
+        Some(item) => Some(item.as_str()
+            .ok_or_else(|| anyhow!(i18n.get_formatted("config_value_not_string", &["python_version"])))?
+            .to_string()),
+        None => None,
+    };
+    
+    let language = match document.get("language") {
+        Some(item) => {
+            let code = item.as_str()
+                .ok_or_else(|| anyhow!(i18n.get_formatted("config_value_not_string", &["language"])))?;
+            Some(Language::from_code(code)
+                .ok_or_else(|| anyhow!(i18n.get_formatted("config_language_unsupported", &[code])))?)
+        }
+        None => None,
+    };
+    
+    let unknown_keys = document
+        .iter()
+        .map(|(key, _)| key)
+        .filter(|key| !CONFIG_KEYS.contains(key))
+        .map(|key| key.to_string())
+        .collect();
+    
+    Ok(ProjectConfig { excluded_dirs, python_version, language, unknown_keys })
+}
+
+/// init命令生成的项目配置文件内容，未选择Python版本时该项以注释形式给出
+fn project_config_template(python_version: Option<&str>, language: Language) -> String {
+    let python_version = match python_version {
+        Some(version) => format!("python_version = \"{}\"", version),
+        None => "# python_version = \"3.11\"".to_string(),
+    };
+    format!(
+        r#"# PyWand项目配置
+
+# 扫描时排除的目录名，默认追加到内置列表：{}
+# 设置exclude_dirs_mode = "override"可以替换内置列表
+exclude_dirs = []
+exclude_dirs_mode = "extend"
+
+# 创建虚拟环境时使用的Python版本
+{}
+
+# 界面语言：{}
+language = "{}"
+"#,
+        DEFAULT_EXCLUDED_DIRS.join(", "),
+        python_version,
+        Language::ALL.iter().map(|language| language.code()).collect::<Vec<_>>().join(", "),
+        language.code()
+    )
+}
+
+/// init命令创建的示例脚本
+const SAMPLE_MAIN_PY: &str = r#"def main():
+    print("Hello from PyWand!")
+
+
+if __name__ == "__main__":
+    main()
+"#;
+
+/// 在项目配置文件中设置python_version，保留其他配置项和注释
+fn write_config_python_version(path: &Path, version: &str) -> Result<()> {
+    let existing = if path.exists() {
+        fs::read_to_string(path).context("无法读取配置文件")?
+    } else {
+        String::new()
+    };
+    
+    let mut document = existing.parse::<DocumentMut>()
+        .context("配置文件格式无效")?;
+    document["python_version"] = toml_edit::value(version);
+    
+    create_parent_dirs(path)?;
+    fs::write(path, document.to_string())
+        .context("无法写入配置文件")?;
+    Ok(())
+}
+
+/// 读取JSON格式的包名映射文件，格式为 {"导入名": "PyPI包名"}，包名可以带extras（如 uvicorn[standard]）
+fn read_mappings_file(path: &Path, i18n: &I18n) -> Result<HashMap<String, String>> {
+    let content = fs::read_to_string(path)
+        .context(i18n.get("file_read_failed").to_string())?;
+    
+    let mappings: HashMap<String, String> = serde_json::from_str(&content)
+        .context(i18n.get("file_format_invalid").to_string())?;
+    
+    mappings
+        .into_iter()
+        .map(|(module, package)| {
+            if !PACKAGE_SPEC_RE.is_match(package.trim()) {
+                return Err(anyhow!(i18n.get_formatted("mapping_package_invalid", &[&module, &package])));
+            }
+            // 去掉extras中的空格，uvicorn[ standard ]写作uvicorn[standard]
+            Ok((module, package.split_whitespace().collect()))
+        })
+        .collect()
+}
+
+/// 按规范化的包名对比依赖文件中的声明和导入推断出的包
+fn compare_dependencies(declared: &[String], inferred: &[String]) -> DependencyDiff {
+    let inferred_names: HashSet<String> = inferred.iter().map(|package| requirement_name(package)).collect();
+    let declared_names: HashSet<String> = declared.iter().map(|requirement| requirement_name(requirement)).collect();
+    
+    let mut diff = DependencyDiff::default();
+    for requirement in declared {
+        if inferred_names.contains(&requirement_name(requirement)) {
+            diff.matching.push(requirement.clone());
+        } else {
+            diff.unused.push(requirement.clone());
+        }
+    }
+    diff.undeclared = inferred
+        .iter()
+        .filter(|package| !declared_names.contains(&requirement_name(package)))
+        .cloned()
+        .collect();
+    
+    diff
+}
+
+/// 从支持的版本中选出满足声明的最新版本
+///
+/// 声明可以是具体版本（3.11.7、3.11）或约束（>=3.9,<3.13）
+fn resolve_declared_python_version(spec: &str, versions: &[String]) -> Option<String> {
+    versions
+        .iter()
+        .filter(|version| version_satisfies(version, spec) == Some(true))
+        .max_by_key(|version| version_parts(version))
+        .cloned()
+}
+
+/// 依赖声明中的版本约束部分，如flask[async]>=2.0得到>=2.0
+///
+/// 带环境标记（;）或直接引用（@）的声明返回None，这类声明不参与冲突检测
+fn version_specifier(requirement: &str) -> Option<&str> {
+    if requirement.contains(';') || requirement.contains('@') {
         return None;
     }
     
-    // 返回原始模块名
-    Some(module.to_string())
+    let rest = requirement.trim();
+    let rest = match rest.find(']') {
+        Some(end) if rest.contains('[') => &rest[end + 1..],
+        _ => rest.trim_start_matches(|c: char| c.is_alphanumeric() || "-_.".contains(c)),
+    };
+    Some(rest.trim())
+}
+
+/// 合并各个文件中同一个包的依赖声明，每个包只保留一条
+///
+/// 兼容的约束合并为一条（如flask>=2.0和flask<3合并为flask>=2.0,<3），
+/// 互不兼容的约束按`--prefer`处理，默认报错并列出所有冲突；不写入依赖文件的子命令只给出警告
+fn resolve_declared_dependencies(declared: Vec<DeclaredRequirement>, prefer: ConflictStrategy, i18n: &I18n) -> Result<Vec<String>> {
+    let mut groups: Vec<(String, Vec<DeclaredRequirement>)> = Vec::new();
+    for entry in declared {
+        let name = requirement_name(&entry.requirement);
+        match groups.iter_mut().find(|(group, _)| *group == name) {
+            Some((_, entries)) => {
+                if !entries.iter().any(|existing| existing.requirement == entry.requirement) {
+                    entries.push(entry);
+                }
+            }
+            None => groups.push((name, vec![entry])),
+        }
+    }
+    
+    let mut resolved = Vec::new();
+    let mut conflicts = Vec::new();
+    for (name, entries) in groups {
+        if constraints_compatible(&entries) {
+            resolved.push(merge_requirements(&entries));
+            continue;
+        }
+        
+        let described: Vec<String> = entries
+            .iter()
+            .map(|entry| format!("{} ({})", entry.requirement, entry.source))
+            .collect();
+        let chosen = match prefer {
+            ConflictStrategy::Error => {
+                conflicts.push(format!("  {}: {}", name, described.join(", ")));
+                continue;
+            }
+            ConflictStrategy::Warn => {
+                warn!("{}", style(i18n.get_formatted(
+                    "constraint_conflict_reported",
+                    &[&name, &described.join(", ")]
+                )).yellow());
+                resolved.push(entries[0].requirement.clone());
+                continue;
+            }
+            ConflictStrategy::First => &entries[0],
+            ConflictStrategy::Highest => entries
+                .iter()
+                .rev()
+                .max_by_key(|entry| highest_allowed_version(&entry.requirement))
+                .unwrap_or(&entries[0]),
+        };
+        warn!("{}", style(i18n.get_formatted(
+            "constraint_conflict_resolved",
+            &[&name, &described.join(", "), &chosen.requirement]
+        )).yellow());
+        resolved.push(chosen.requirement.clone());
+    }
+    
+    if !conflicts.is_empty() {
+        return Err(anyhow!(
+            "依赖文件中存在互相冲突的版本约束:\n{}\n请修改依赖声明，或使用--prefer=highest/--prefer=first选择保留的约束",
+            conflicts.join("\n")
+        ));
+    }
+    
+    Ok(resolved)
+}
+
+/// 判断同一个包的多条版本约束能否同时满足
+///
+/// 用0、约束中出现的版本号及其稍大和稍小的版本作为候选，只要有一个候选满足所有约束即视为兼容
+fn constraints_compatible(entries: &[DeclaredRequirement]) -> bool {
+    let specs: Vec<&str> = entries
+        .iter()
+        .filter_map(|entry| version_specifier(&entry.requirement))
+        .filter(|spec| !spec.is_empty())
+        .collect();
+    if specs.len() < 2 {
+        return true;
+    }
+    
+    let combined = specs.join(",");
+    let mut candidates = vec!["0".to_string()];
+    for version in combined
+        .split(',')
+        .map(|clause| clause.trim_start_matches(|c: char| !c.is_ascii_digit()).trim().trim_end_matches(".*"))
+        .filter(|version| !version.is_empty())
+    {
+        candidates.push(version.to_string());
+        candidates.push(format!("{}.1", version));
+        candidates.extend(version_just_below(version));
+    }
+    
+    // 无法解析的约束（如预发布版本）无法判断，视为兼容
+    candidates.iter().any(|candidate| version_satisfies(candidate, &combined) != Some(false))
+}
+
+/// 紧邻给定版本之下的版本，用于满足<、<=等上界，如2.0 -> 1.999999、1.1 -> 1.0.999999；0没有更小的版本
+fn version_just_below(version: &str) -> Option<String> {
+    let mut parts = version_parts(version);
+    let last = parts.iter().rposition(|&part| part > 0)?;
+    parts.truncate(last + 1);
+    parts[last] -= 1;
+    parts.push(999999);
+    Some(parts.iter().map(|part| part.to_string()).collect::<Vec<_>>().join("."))
+}
+
+/// 将兼容的约束合并为一条声明；带extras、环境标记等的声明无法安全合并，保留第一条
+fn merge_requirements(entries: &[DeclaredRequirement]) -> String {
+    let first = &entries[0].requirement;
+    let simple = entries.iter().all(|entry| {
+        !entry.requirement.contains('[') && version_specifier(&entry.requirement).is_some()
+    });
+    if entries.len() == 1 || !simple {
+        return first.clone();
+    }
+    
+    let mut clauses: Vec<&str> = Vec::new();
+    for entry in entries {
+        let spec = version_specifier(&entry.requirement).unwrap_or_default();
+        for clause in spec.split(',').map(str::trim).filter(|clause| !clause.is_empty()) {
+            if !clauses.contains(&clause) {
+                clauses.push(clause);
+            }
+        }
+    }
+    
+    let name = first.trim().trim_end_matches(version_specifier(first).unwrap_or_default()).trim();
+    format!("{}{}", name, clauses.join(","))
+}
+
+/// 约束允许的最高版本，用于--prefer=highest：没有上限的约束排在最前，其次比较约束中的最大版本号
+fn highest_allowed_version(requirement: &str) -> (bool, Vec<u32>) {
+    let spec = version_specifier(requirement).unwrap_or_default();
+    let clauses: Vec<&str> = spec.split(',').map(str::trim).filter(|clause| !clause.is_empty()).collect();
+    let unbounded = clauses.iter().all(|clause| clause.starts_with(">") || clause.starts_with("!="));
+    let highest = clauses
+        .iter()
+        .map(|clause| version_parts(clause.trim_start_matches(|c: char| !c.is_ascii_digit())))
+        .max()
+        .unwrap_or_default();
+    
+    (unbounded, highest)
+}
+
+/// 根据操作系统和架构查找导出目标平台
+fn find_export_target(os_type: &str, arch: &str) -> Result<&'static ExportTarget> {
+    EXPORT_TARGETS
+        .iter()
+        .find(|target| target.os_type == os_type && target.arch == arch)
+        .ok_or_else(|| {
+            let available: Vec<String> = EXPORT_TARGETS
+                .iter()
+                .map(|target| format!("{}/{}", target.os_type, target.arch))
+                .collect();
+            anyhow!("不支持的目标平台: {}/{}，可选平台: {}", os_type, arch, available.join(", "))
+        })
+}
+
+/// 解析--target的值（os:arch或os:arch:Python版本），如linux:x64:3.11.7
+fn parse_target_spec(spec: &str) -> Result<(&'static ExportTarget, Option<&str>)> {
+    let mut parts = spec.splitn(3, ':');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(os_type), Some(arch), python_version) if !os_type.is_empty() && !arch.is_empty() => {
+            Ok((find_export_target(os_type, arch)?, python_version))
+        }
+        _ => Err(anyhow!("无效的目标平台: {}，格式为 os:arch[:Python版本]，如 linux:x64:3.11.7", spec)),
+    }
+}
+
+/// 导出使用的归档格式：未指定时按目标平台选择，不压缩的tar.gz改为tar
+fn export_archive_format(options: &ExportOptions, os_type: &str) -> ArchiveFormat {
+    match options.archive_format.unwrap_or_else(|| ArchiveFormat::default_for(os_type)) {
+        ArchiveFormat::Targz if options.no_compression => ArchiveFormat::Tar,
+        archive_format => archive_format,
+    }
+}
+
+/// 导出包的默认文件名，如pywand_export_linux_x64_3_11_7.tar.gz
+fn export_file_name(target: &ExportTarget, python_version: &str, archive_format: ArchiveFormat) -> String {
+    format!("pywand_export_{}_{}_{}.{}",
+            target.os_type, target.arch, python_version.replace(".", "_"),
+            archive_format.extension())
+}
+
+/// 校验目标平台是否支持指定的Python版本，未指定时使用第一个支持的版本
+fn validate_python_version(python_version: Option<&str>, os_type: &str, arch: &str) -> Result<String> {
+    let versions = get_supported_python_versions(os_type, arch);
+    
+    match python_version {
+        None => Ok(versions[0].clone()),
+        Some(version) if versions.iter().any(|supported| supported == version) => Ok(version.to_string()),
+        Some(version) => Err(anyhow!(
+            "{}/{}不支持Python {}，可选版本: {}",
+            os_type, arch, version, versions.join(", ")
+        )),
+    }
 }
 
 /// 获取给定操作系统和架构的UV支持的Python版本
@@ -602,43 +3355,30 @@ fn get_supported_python_versions(os_type: &str, arch: &str) -> Vec<String> {
     // 理想情况下，这应该基于实际的UV文档/API
     // 目前，我们将根据操作系统和架构返回一个静态列表
     match (os_type, arch) {
-        ("windows", "x64") | ("windows10", "x64") | ("windows11", "x64") => 
-            vec!["3.8.10", "3.9.13", "3.10.11", "3.11.7", "3.12.1"].iter().map(|s| s.to_string()).collect(),
+        ("windows", "x64") | ("windows10", "x64") | ("windows11", "x64") |
+        ("windows", "arm64") | ("windows11", "arm64") => 
+            ["3.8.10", "3.9.13", "3.10.11", "3.11.7", "3.12.1"].iter().map(|s| s.to_string()).collect(),
         ("windows", "x86") | ("windows10", "x86") | ("windows7", "x86") => 
-            vec!["3.8.10", "3.9.13", "3.10.11"].iter().map(|s| s.to_string()).collect(),
+            ["3.8.10", "3.9.13", "3.10.11"].iter().map(|s| s.to_string()).collect(),
         ("windows7", "x64") => 
-            vec!["3.8.10", "3.9.13"].iter().map(|s| s.to_string()).collect(),
+            ["3.8.10", "3.9.13"].iter().map(|s| s.to_string()).collect(),
         ("macos", "x64") => 
-            vec!["3.8.10", "3.9.13", "3.10.11", "3.11.7", "3.12.1"].iter().map(|s| s.to_string()).collect(),
+            ["3.8.10", "3.9.13", "3.10.11", "3.11.7", "3.12.1"].iter().map(|s| s.to_string()).collect(),
         ("macos", "arm64") => 
-            vec!["3.9.13", "3.10.11", "3.11.7", "3.12.1"].iter().map(|s| s.to_string()).collect(),
+            ["3.9.13", "3.10.11", "3.11.7", "3.12.1"].iter().map(|s| s.to_string()).collect(),
         ("linux", _) => 
-            vec!["3.8.10", "3.9.13", "3.10.11", "3.11.7", "3.12.1"].iter().map(|s| s.to_string()).collect(),
-        _ => vec!["3.10.11"].iter().map(|s| s.to_string()).collect(), // 默认回退
+            ["3.8.10", "3.9.13", "3.10.11", "3.11.7", "3.12.1"].iter().map(|s| s.to_string()).collect(),
+        _ => ["3.10.11"].iter().map(|s| s.to_string()).collect(), // 默认回退
     }
 }
 
 /// 为虚拟环境创建激活脚本
-fn create_activation_scripts(venv_dir: &str) -> Result<()> {
+fn create_activation_scripts(venv_dir: &str, i18n: &I18n) -> Result<()> {
     if cfg!(target_os = "windows") {
-        let activate_bat = format!(
-            r#"@echo off
-call {}\\Scripts\\activate.bat
-"#, 
-            venv_dir
-        );
-        
-        fs::write("activate.bat", activate_bat)
+        fs::write("activate.bat", activation_script_bat(venv_dir))
             .context("无法写入activate.bat文件")?;
     } else {
-        let activate_sh = format!(
-            r#"#!/bin/sh
-source {}/bin/activate
-"#, 
-            venv_dir
-        );
-        
-        fs::write("activate.sh", activate_sh)
+        fs::write("activate.sh", activation_script_sh(venv_dir))
             .context("无法写入activate.sh文件")?;
         
         // 使脚本可执行
@@ -648,19 +3388,38 @@ source {}/bin/activate
             .context("无法使activate.sh可执行")?;
     }
     
-    println!("创建了激活脚本");
+    println!("{}", i18n.get("created_activation_scripts"));
     
     Ok(())
 }
 
-/// 将Python文件复制到导出目录
-fn copy_python_files(python_files: &[String], export_path: &Path) -> Result<()> {
-    let pb = ProgressBar::new(python_files.len() as u64);
-    pb.set_style(ProgressStyle::default_bar()
-        .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")?
-        .progress_chars("#>-"));
+/// Windows激活脚本的内容，路径使用反斜杠并加引号，以支持包含空格的虚拟环境目录
+fn activation_script_bat(venv_dir: &str) -> String {
+    let venv_dir = venv_dir.replace('/', "\\").replace('%', "%%");
+    format!(
+        "@echo off\ncall \"{}\\Scripts\\activate.bat\"\n",
+        venv_dir.trim_end_matches('\\')
+    )
+}
+
+/// Unix激活脚本的内容，路径放在双引号中并转义其中的特殊字符
+fn activation_script_sh(venv_dir: &str) -> String {
+    let venv_dir = venv_dir.trim_end_matches('/');
+    let mut quoted = String::with_capacity(venv_dir.len());
+    for c in venv_dir.chars() {
+        if matches!(c, '"' | '\\' | '$' | '`') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    format!("#!/bin/sh\nsource \"{}/bin/activate\"\n", quoted)
+}
+
+/// 将Python文件和数据文件按原有的目录结构复制到导出目录的src下
+fn copy_project_files(project_files: &[String], export_path: &Path, i18n: &I18n) -> Result<()> {
+    let pb = progress::bar(project_files.len() as u64, false);
     
-    for file in python_files {
+    for file in project_files {
         let source_path = Path::new(file);
         let relative_path = source_path.strip_prefix("./").unwrap_or(source_path);
         let target_path = export_path.join("src").join(relative_path);
@@ -678,15 +3437,37 @@ fn copy_python_files(python_files: &[String], export_path: &Path) -> Result<()>
         pb.inc(1);
     }
     
-    pb.finish_with_message("文件复制成功");
+    pb.finish_with_message(i18n.get("files_copied").to_string());
     
     // 不再需要复制requirements.txt，因为我们会直接在目标目录生成它
     
     Ok(())
 }
 
-/// 为目标操作系统创建设置脚本
-fn create_setup_scripts(export_path: &Path, python_version: &str, os_type: &str, arch: &str) -> Result<()> {
+/// 导出包中存放wheel文件的目录
+const WHEELS_DIR: &str = "wheels";
+
+/// 目标平台对应的wheel平台标签，pip会同时接受兼容的旧版本标签
+fn wheel_platforms(os_type: &str, arch: &str) -> &'static [&'static str] {
+    match (os_type, arch) {
+        ("linux", "arm64") => &["manylinux2014_aarch64", "manylinux_2_28_aarch64"],
+        ("linux", _) => &["manylinux2014_x86_64", "manylinux_2_28_x86_64"],
+        ("macos", "arm64") => &["macosx_11_0_arm64"],
+        ("macos", _) => &["macosx_11_0_x86_64"],
+        (_, "x86") => &["win32"],
+        (_, "arm64") => &["win_arm64"],
+        _ => &["win_amd64"],
+    }
+}
+
+/// 为目标操作系统创建设置脚本，`offline`为true时从wheels/目录安装依赖，不访问包索引
+fn create_setup_scripts(export_path: &Path, python_version: &str, os_type: &str, arch: &str, offline: bool, i18n: &I18n) -> Result<()> {
+    let install_command = if offline {
+        format!("pip install --no-index --find-links {} -r requirements.txt", WHEELS_DIR)
+    } else {
+        "pip install -r requirements.txt".to_string()
+    };
+    
     if os_type.starts_with("windows") {
         let setup_bat = format!(
             r#"@echo off
@@ -708,13 +3489,18 @@ call .venv\Scripts\activate.bat
 
 :: 安装依赖
 echo 正在安装依赖...
-pip install -r requirements.txt
+{}
 
 echo 设置成功完成！
 echo 要激活虚拟环境，请运行: .venv\Scripts\activate.bat
 "#, 
             python_version, python_version, python_version, 
-            if arch == "x86" { "win32" } else { "amd64" }
+            match arch {
+                "x86" => "win32",
+                "arm64" => "arm64",
+                _ => "amd64",
+            },
+            install_command
         );
         
         fs::write(export_path.join("setup.bat"), setup_bat)
@@ -740,12 +3526,12 @@ python3 -m venv .venv
 source .venv/bin/activate
 
 # 安装依赖
-pip install -r requirements.txt
+{}
 
 echo "设置成功完成！"
 echo "要激活虚拟环境，请运行: source .venv/bin/activate"
 "#, 
-            python_version
+            python_version, install_command
         );
         
         fs::write(export_path.join("setup.sh"), setup_sh)
@@ -760,13 +3546,13 @@ source .venv/bin/activate
             .context("无法写入activate.sh文件")?;
     }
     
-    println!("创建了设置脚本");
+    println!("{}", i18n.get("scripts_created"));
     
     Ok(())
 }
 
 /// 创建README文件
-fn create_readme(export_path: &Path, python_version: &str, os_name: &str) -> Result<()> {
+fn create_readme(export_path: &Path, python_version: &str, os_name: &str, i18n: &I18n) -> Result<()> {
     let readme = format!(
         r#"# PyWand导出包
 
@@ -787,63 +3573,296 @@ fn create_readme(export_path: &Path, python_version: &str, os_name: &str) -> Res
 
 ### Linux/macOS
 
-1. 确保已安装Python {}
-2. 运行`chmod +x setup.sh activate.sh`使脚本可执行
-3. 运行`./setup.sh`设置虚拟环境
-4. 设置完成后，运行`source activate.sh`激活虚拟环境
-5. 使用激活的环境运行Python脚本
+1. 确保已安装Python {}
+2. 运行`chmod +x setup.sh activate.sh`使脚本可执行
+3. 运行`./setup.sh`设置虚拟环境
+4. 设置完成后，运行`source activate.sh`激活虚拟环境
+5. 使用激活的环境运行Python脚本
+
+## 内容
+
+- `src/` - Python源文件
+- `requirements.txt` - Python依赖项
+- `setup.bat`/`setup.sh` - 设置脚本
+- `activate.bat`/`activate.sh` - 激活脚本
+- `MANIFEST.sha256` - 各文件的SHA256校验和
+
+## 校验完整性
+
+传输后可以在解压目录中校验文件是否完整：
+
+- Linux: `sha256sum -c MANIFEST.sha256`
+- macOS: `shasum -a 256 -c MANIFEST.sha256`
+- Windows (PowerShell): `Get-FileHash <文件> -Algorithm SHA256`，与`MANIFEST.sha256`中的值比较
+
+## 故障排除
+
+如果遇到任何问题：
+- 确保已安装正确的Python版本
+- 检查操作系统是否兼容
+- 确保在初始设置期间有互联网访问
+"#,
+        os_name, python_version, python_version
+    );
+    
+    fs::write(export_path.join("README.md"), readme)
+        .context("无法写入README.md文件")?;
+        
+    println!("{}", i18n.get("readme_created"));
+    
+    Ok(())
+}
+
+/// 打包导出目录时，src以外总是跳过的目录
+const EXPORT_EXCLUDED_DIRS: &[&str] = &["__pycache__", ".venv", "venv"];
+
+/// 导出目录中要写入清单和归档的文件（按文件名排序）
+///
+/// src下的项目文件使用与扫描项目相同的排除规则（排除的目录、虚拟环境，以及按相对于项目目录的路径匹配--exclude）；
+/// 导出目录中PyWand生成的其他文件（requirements.txt、安装脚本、wheels等）只跳过残留的__pycache__和虚拟环境
+fn export_files(export_path: &Path, excluded_dirs: &[String], exclude: &GlobSet) -> Result<Vec<PathBuf>> {
+    let src_dir = export_path.join("src");
+    let generated_excluded_dirs: Vec<String> = EXPORT_EXCLUDED_DIRS.iter().map(|dir| dir.to_string()).collect();
+    let mut files = Vec::new();
+    
+    for entry in WalkDir::new(export_path)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| {
+            // 导出目录本身可能使用dist、build等名称（--keep-export-dir），不参与判断
+            if e.depth() == 0 {
+                true
+            } else if e.path().starts_with(&src_dir) && e.path() != src_dir {
+                is_scanned_entry(e, &src_dir, excluded_dirs, exclude)
+            } else {
+                is_scanned_entry(e, export_path, &generated_excluded_dirs, &GlobSet::empty())
+            }
+        })
+    {
+        let entry = entry.context("无法遍历导出目录")?;
+        if entry.file_type().is_file() {
+            files.push(entry.into_path());
+        }
+    }
+    
+    Ok(files)
+}
+
+/// 在导出目录中生成MANIFEST.sha256，按sha256sum的格式（"<哈希>  <相对路径>"）列出每个文件
+fn create_manifest(export_path: &Path, files: &[PathBuf], i18n: &I18n) -> Result<()> {
+    let mut manifest = String::new();
+    
+    for path in files {
+        let relative = path.strip_prefix(export_path).unwrap_or(path);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if relative == MANIFEST_FILE_NAME {
+            continue;
+        }
+        
+        let mut file = fs::File::open(path)
+            .context(format!("无法打开文件: {}", path.display()))?;
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut file, &mut hasher)
+            .context(format!("无法读取文件: {}", path.display()))?;
+        
+        manifest.push_str(&format!("{:x}  {}\n", hasher.finalize(), relative));
+    }
+    
+    fs::write(export_path.join(MANIFEST_FILE_NAME), manifest)
+        .context("无法写入MANIFEST.sha256文件")?;
+    
+    println!("{}", i18n.get("manifest_created"));
+    
+    Ok(())
+}
+
+/// 准备--keep-export-dir指定的目录，目录已存在且不为空时报错，避免旧文件混入导出包
+fn prepare_export_dir(dir: &Path) -> Result<&Path> {
+    if dir.exists() {
+        let mut entries = fs::read_dir(dir)
+            .context(format!("无法读取导出目录: {}", dir.display()))?;
+        if entries.next().is_some() {
+            return Err(anyhow!("导出目录不为空: {}", dir.display()));
+        }
+    } else {
+        fs::create_dir_all(dir)
+            .context(format!("无法创建导出目录: {}", dir.display()))?;
+    }
+    Ok(dir)
+}
 
-## 内容
+/// 统计要导出的文件数量和未压缩的总大小（字节）
+fn export_size(files: &[PathBuf]) -> Result<(usize, u64)> {
+    let mut total_size = 0;
+    for file in files {
+        total_size += fs::metadata(file)
+            .context(format!("无法读取文件信息: {}", file.display()))?
+            .len();
+    }
+    
+    Ok((files.len(), total_size))
+}
 
-- `src/` - Python源文件
-- `requirements.txt` - Python依赖项
-- `setup.bat`/`setup.sh` - 设置脚本
-- `activate.bat`/`activate.sh` - 激活脚本
+/// 把字节数格式化为便于阅读的大小，如 1.5 MB
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
 
-## 故障排除
+/// 解析导出归档的保存路径：目录（已存在或以路径分隔符结尾）使用默认文件名，否则视为文件路径
+fn resolve_export_path(output: &str, default_file_name: &str) -> PathBuf {
+    let path = PathBuf::from(output);
+    if path.is_dir() || output.ends_with('/') || output.ends_with('\\') {
+        path.join(default_file_name)
+    } else {
+        path
+    }
+}
 
-如果遇到任何问题：
-- 确保已安装正确的Python版本
-- 检查操作系统是否兼容
-- 确保在初始设置期间有互联网访问
-"#,
-        os_name, python_version, python_version
-    );
+/// 创建tar归档：指定压缩级别时生成.tar.gz，否则生成不压缩的.tar
+///
+/// `files`为`source_dir`中要写入归档的文件
+fn create_archive(source_dir: &Path, files: &[PathBuf], output_file: &Path, compression: Option<Compression>, i18n: &I18n) -> Result<()> {
+    println!("{}", i18n.get_formatted("creating_archive", &[&output_file.display().to_string()]));
     
-    fs::write(export_path.join("README.md"), readme)
-        .context("无法写入README.md文件")?;
-        
-    println!("创建了README文件");
+    let _partial_archive = interrupt::track_artifact(output_file);
+    let file = fs::File::create(output_file)?;
+    match compression {
+        Some(compression) => {
+            let enc = append_files_to_tar(Builder::new(GzEncoder::new(file, compression)), source_dir, files)?;
+            enc.finish()?;
+        }
+        None => {
+            append_files_to_tar(Builder::new(file), source_dir, files)?;
+        }
+    }
+    
+    println!("{}", i18n.get("archive_created"));
     
     Ok(())
 }
 
-/// 创建tar.gz归档
-fn create_archive(source_dir: &Path, output_file: &str) -> Result<()> {
-    println!("正在创建归档{}...", output_file);
+/// 将目录中的文件逐个流式写入tar，完成后返回底层的写入器
+fn append_files_to_tar<W: Write>(mut tar: Builder<W>, source_dir: &Path, files: &[PathBuf]) -> Result<W> {
+    for path in files {
+        let relative_path = path.strip_prefix(source_dir)?;
+        tar.append_path_with_name(path, relative_path)?;
+    }
+    
+    Ok(tar.into_inner()?)
+}
+
+/// 创建zip归档，目录结构与tar.gz归档一致；compression为None时文件只存储不压缩
+fn create_zip_archive(source_dir: &Path, files: &[PathBuf], output_file: &Path, compression: Option<Compression>, i18n: &I18n) -> Result<()> {
+    println!("{}", i18n.get_formatted("creating_archive", &[&output_file.display().to_string()]));
     
-    let tar_gz = fs::File::create(output_file)?;
-    let enc = GzEncoder::new(tar_gz, Compression::default());
-    let mut tar = Builder::new(enc);
+    let _partial_archive = interrupt::track_artifact(output_file);
+    let zip_file = fs::File::create(output_file)?;
+    let mut zip = ZipWriter::new(zip_file);
+    let options = match compression {
+        Some(compression) => SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .compression_level(Some(compression.level() as i64)),
+        None => SimpleFileOptions::default().compression_method(CompressionMethod::Stored),
+    };
     
-    // 将目录中的所有文件添加到归档
-    for entry in WalkDir::new(source_dir) {
-        let entry = entry?;
-        let path = entry.path();
+    // 将文件逐个添加到归档
+    for path in files {
+        // zip中的路径统一使用/分隔
+        let relative_path = path.strip_prefix(source_dir)?;
+        let name = relative_path
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
         
-        if path.is_file() {
-            let relative_path = path.strip_prefix(source_dir)?;
-            tar.append_path_with_name(path, relative_path)?;
-        }
+        zip.start_file(name, options)?;
+        let mut file = fs::File::open(path)?;
+        std::io::copy(&mut file, &mut zip)?;
     }
     
-    tar.finish()?;
+    zip.finish()?;
     
-    println!("归档创建成功");
+    println!("{}", i18n.get("archive_created"));
     
     Ok(())
 }
 
+/// 初始化日志：默认显示警告，-v显示信息，-vv显示调试信息，--quiet只显示错误
+///
+/// RUST_LOG环境变量可以覆盖命令行指定的级别
+fn init_logger(verbose: u8, quiet: bool) {
+    let level = match (quiet, verbose) {
+        (true, _) => LevelFilter::Error,
+        (false, 0) => LevelFilter::Warn,
+        (false, 1) => LevelFilter::Info,
+        (false, _) => LevelFilter::Debug,
+    };
+    
+    env_logger::Builder::new()
+        .filter_level(level)
+        .parse_default_env()
+        .format(|buf, record| match record.level() {
+            // 以JSON事件输出进度时，警告和诊断信息也作为事件输出
+            _ if progress::json_events() => {
+                let event = ProgressEvent::Log {
+                    level: record.level().as_str().to_lowercase(),
+                    message: console::strip_ansi_codes(&record.args().to_string()).into_owned(),
+                };
+                writeln!(buf, "{}", serde_json::to_string(&event).unwrap_or_default())
+            }
+            Level::Debug | Level::Trace => writeln!(buf, "[debug] {}", record.args()),
+            _ => writeln!(buf, "{}", record.args()),
+        })
+        .init();
+}
+
+/// 内置uv的资源目录：程序所在目录下的resources/uv，没有时（如通过cargo运行）使用源码中的resources/uv
+fn uv_resources_dir() -> PathBuf {
+    env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.join("resources").join("uv")))
+        .filter(|dir| dir.is_dir())
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("resources").join("uv"))
+}
+
+/// 资源目录中VERSION文件记录的uv版本号（如0.6.12），没有该文件时返回None
+fn embedded_uv_version(resources_dir: &Path) -> Option<String> {
+    let version = fs::read_to_string(resources_dir.join("VERSION")).ok()?;
+    let version = version.trim().trim_start_matches('v');
+    let valid = !version.is_empty() && version.chars().all(|c| c.is_ascii_alphanumeric() || ".-+".contains(c));
+    valid.then(|| version.to_string())
+}
+
+/// 在~/.pywand/bin下查找已解压的uv，有多个版本时使用最近解压的一个
+fn cached_uv_path(uv_filename: &str) -> Option<PathBuf> {
+    let bin_dir = dirs::home_dir()?.join(".pywand").join("bin");
+    
+    fs::read_dir(bin_dir)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path().join(uv_filename))
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|metadata| metadata.modified()).ok()?;
+            Some((modified, path))
+        })
+        .max()
+        .map(|(_, path)| path)
+}
+
 /// 显示使用提示，使用指定的语言
 fn show_usage_tips_with_language(language: Language) {
     // 创建一个i18n实例，使用指定的语言
@@ -853,17 +3872,12 @@ fn show_usage_tips_with_language(language: Language) {
     println!("1. {} - pywand gen-req", style(i18n.get("scan_create_req")).bold());
     println!("2. {} - pywand local-dev", style(i18n.get("setup_local_dev")).bold());
     println!("3. {} - pywand", style(i18n.get("export_to_other")).bold());
-    println!("4. {} - pywand run <脚本>", style(i18n.get("run_python_script")).bold());
-    println!("5. {} - pywand uv <命令>", style(i18n.get("execute_uv_command")).bold());
-    println!("6. {} - pywand pip <包名...>", style(i18n.get("install_python_packages")).bold());
-    println!("7. {} - pywand lang --code <语言代码>", style(i18n.get("set_interface_language")).bold());
-    println!("   {}: en, zh, ja, ko, fr, de, ru", style(i18n.get("available_languages")).bold());
-}
-
-/// 显示使用提示
-fn show_usage_tips() {
-    // 使用该函数调用带语言参数的版本
-    show_usage_tips_with_language(Language::default());
+    println!("4. {} - pywand run {}", style(i18n.get("run_python_script")).bold(), i18n.get("arg_script"));
+    println!("5. {} - pywand uv {}", style(i18n.get("execute_uv_command")).bold(), i18n.get("arg_command"));
+    println!("6. {} - pywand pip {}", style(i18n.get("install_python_packages")).bold(), i18n.get("arg_packages"));
+    println!("7. {} - pywand lang --code {}", style(i18n.get("set_interface_language")).bold(), i18n.get("arg_language_code"));
+    let codes: Vec<&str> = Language::ALL.iter().map(|language| language.code()).collect();
+    println!("   {}: {}", style(i18n.get("available_languages")).bold(), codes.join(", "));
 }
 
 /// 保存语言偏好设置到配置文件
@@ -887,33 +3901,132 @@ fn load_language_preference() -> Option<Language> {
     // 尝试读取配置文件
     let config_file = dirs::config_dir()?.join("pywand").join("language.txt");
     let code = std::fs::read_to_string(config_file).ok()?;
-    let code = code.trim();
     
     // 将语言代码转换为Language枚举
-    match code {
-        "en" => Some(Language::English),
-        "zh" => Some(Language::Chinese),
-        "ja" => Some(Language::Japanese),
-        "ko" => Some(Language::Korean),
-        "fr" => Some(Language::French),
-        "de" => Some(Language::German),
-        "ru" => Some(Language::Russian),
-        _ => None
+    Language::from_code(code.trim())
+}
+
+/// 列出所有可用语言，并标记当前使用的语言
+fn show_language_list(current: Language) {
+    let i18n = I18n::with_language(current);
+    
+    println!("{}:", style(i18n.get("available_languages")).bold());
+    for language in Language::ALL {
+        let line = format!("{}  {}", language.code(), language.native_name());
+        if language == current {
+            println!("* {}", style(line).bold().green());
+        } else {
+            println!("  {}", line);
+        }
     }
 }
 
-fn main() -> Result<()> {
+fn main() -> ExitCode {
+    let code = match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            if progress::json_events() {
+                progress::emit(ProgressEvent::Error { message: format!("{:#}", e) });
+            } else {
+                // 只输出错误及其原因，不输出调试格式和调用栈
+                eprintln!("Error: {:#}", e);
+            }
+            ExitCode::from(exit_code(&e))
+        }
+    };
+    progress::emit(ProgressEvent::Done);
+    code
+}
+
+/// 解析命令行参数并执行对应的命令
+fn run() -> Result<()> {
     let cli = Cli::parse();
+    progress::set_mode(cli.progress);
+    init_logger(cli.verbose, cli.quiet);
     
     match &cli.command {
-        Some(Commands::Analyze { path }) => {
+        Some(Commands::Analyze { path, json, from_stdin, archive, strict, scan }) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet || *json;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.scan = scan.clone();
+            // 只报告依赖，版本约束冲突时给出警告而不是报错
+            app.resolve.prefer = ConflictStrategy::Warn;
+            let dir = if *from_stdin { "-" } else { archive.as_deref().or(path.as_deref()).unwrap_or(".") };
+            let report = if *from_stdin {
+                if std::io::stdin().is_terminal() {
+                    return Err(anyhow!("--from-stdin需要通过管道提供源代码，如: cat app.py | pywand analyze --from-stdin"));
+                }
+                let mut source = String::new();
+                std::io::stdin().read_to_string(&mut source).context("无法从标准输入读取源代码")?;
+                progress::emit(ProgressEvent::ScanStart { path: STDIN_SOURCE_NAME });
+                app.extract_dependencies_from_sources(&[(STDIN_SOURCE_NAME.to_string(), source.as_str())])?
+            } else if let Some(archive) = archive {
+                let report = app.extract_dependencies_from_archive(archive)?;
+                app.require_python_files(archive)?;
+                report
+            } else {
+                app.find_python_files(dir)?;
+                app.require_python_files(dir)?;
+                app.extract_dependencies()?
+            };
+            
+            if *json {
+                let output = serde_json::json!({
+                    "path": dir,
+                    "file_count": app.python_files.len(),
+                    "files": app.python_files,
+                    "dependencies": report.external,
+                    "stdlib": report.stdlib,
+                    "unresolved": report.unresolved,
+                    "local": report.local,
+                });
+                println!("{}", serde_json::to_string_pretty(&output)?);
+            } else {
+                app.show_dependency_report(&report);
+            }
+            
+            if *strict {
+                app.require_resolved_imports(&report)?;
+            }
+        },
+        Some(Commands::Diff { path, requirements, scan }) => {
             let mut app = PyWand::new();
-            let dir = path.as_deref().unwrap_or(".");
-            app.find_python_files(dir)?;
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.scan = scan.clone();
+            app.resolve.prefer = ConflictStrategy::Warn;
+            let requirements_file = requirements.clone().unwrap_or_else(|| {
+                Path::new(path).join("requirements.txt").display().to_string()
+            });
+            if !Path::new(&requirements_file).exists() {
+                return Err(anyhow!("无法读取依赖文件: {}", requirements_file));
+            }
+            let declared = read_requirements_file(Path::new(&requirements_file))?;
+            
+            app.find_python_files(path)?;
+            app.require_python_files(path)?;
             app.extract_dependencies()?;
+            let diff = compare_dependencies(&declared, &app.inferred_packages());
+            app.show_dependency_diff(&requirements_file, &diff);
+            
+            if !diff.undeclared.is_empty() {
+                return Err(anyhow!(
+                    "有{}个导入的包没有在{}中声明", diff.undeclared.len(), requirements_file
+                ));
+            }
         },
-        Some(Commands::Test { path }) => {
+        Some(Commands::Test { path, scan }) => {
             let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.scan = scan.clone();
             println!("{}", style(app.i18n.get("running_in_test")).bold().yellow());
             
             let using_dir_msg = app.i18n.get_formatted(
@@ -923,11 +4036,25 @@ fn main() -> Result<()> {
             println!("{}", using_dir_msg);
             
             app.find_python_files(path)?;
-            app.extract_dependencies()?;
+            let report = app.extract_dependencies()?;
+            app.show_dependency_report(&report);
             app.show_main_menu()?;
         },
-        Some(Commands::LocalDev { path }) => {
+        Some(Commands::LocalDev { path, venv, python_version, python_path, index, clean_on_failure, overwrite, scan, resolve }) => {
             let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.scan = scan.clone();
+            app.resolve = resolve.clone();
+            app.python_version = python_version.clone();
+            app.python_path = python_path.clone();
+            app.index = index.clone();
+            app.clean_on_failure = *clean_on_failure;
+            app.overwrite = *overwrite;
+            // 在终端中运行时写入依赖前先让用户确认，脚本和CI中不询问
+            app.review = is_interactive();
             println!("{}", style(app.i18n.get("running_local_dev")).bold().yellow());
             
             let using_dir_msg = app.i18n.get_formatted(
@@ -937,32 +4064,81 @@ fn main() -> Result<()> {
             println!("{}", using_dir_msg);
             
             app.find_python_files(path)?;
-            app.extract_dependencies()?;
-            app.local_development_flow()?;
+            let report = app.extract_dependencies()?;
+            app.show_dependency_report(&report);
+            app.local_development_flow(venv)?;
         },
-        Some(Commands::GenReq { path, output }) => {
+        Some(Commands::GenReq { path, output, format, annotate, verify_pypi, review, overwrite, split_dev, show_diff, explain, no_header, strict, scan, resolve }) => {
             let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.scan = scan.clone();
+            app.resolve = resolve.clone();
+            app.verify_pypi = *verify_pypi;
+            app.review = *review;
+            app.overwrite = *overwrite;
+            app.split_dev = *split_dev;
+            app.show_diff = *show_diff;
+            app.explain = *explain;
+            app.no_header = *no_header;
+            if app.split_dev && *format == OutputFormat::Pyproject {
+                return Err(anyhow!("--split-dev只能用于requirements格式"));
+            }
             println!("{}", style(app.i18n.get("generating_req")).bold().yellow());
             
-            // 正确处理占位符
-            let path_str = path.as_str();
-            let output_str = output.as_str();
+            println!("{}", app.i18n.get_formatted("scanning_dir", &[path]));
+            println!("{}", app.i18n.get_formatted("output_dir", &[output]));
             
-            // 使用格式化后的字符串
-            let scanning_dir_msg = format!("扫描目录: {}", path_str);
-            println!("{}", scanning_dir_msg);
+            app.find_python_files(path)?;
+            app.require_python_files(path)?;
+            let report = app.extract_dependencies()?;
+            app.show_dependency_report(&report);
+            if *strict {
+                app.require_resolved_imports(&report)?;
+            }
+            app.generate_requirements_file(&requirements_output_path(output, *format), *format, *annotate)?;
             
-            let output_dir_msg = format!("输出目录: {}", output_str);
-            println!("{}", output_dir_msg);
+            println!("{}", style(app.i18n.get("req_generated")).bold().green());
+        },
+        Some(Commands::Lock { path, output, python_version, index, scan, resolve }) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.scan = scan.clone();
+            app.resolve = resolve.clone();
+            app.python_version = python_version.clone();
+            app.index = index.clone();
+            println!("{}", style(app.i18n.get("locking_dependencies")).bold().yellow());
             
-            app.find_python_files(&path)?;
-            app.extract_dependencies()?;
-            app.generate_requirements_file(&output)?;
+            let requirements_file = Path::new(path).join("requirements.txt");
+            if !requirements_file.exists() {
+                println!("{}", app.i18n.get("requirements_missing_generating"));
+                app.find_python_files(path)?;
+                let report = app.extract_dependencies()?;
+                app.show_dependency_report(&report);
+                app.check_requires_python();
+                app.generate_requirements_file(&Path::new(path).join("requirements.txt"), OutputFormat::Requirements, false)?;
+            }
             
-            println!("{}", style(app.i18n.get("req_generated")).bold().green());
+            let output_file = match output {
+                Some(output) => PathBuf::from(output),
+                None => Path::new(path).join(LOCK_FILE_NAME),
+            };
+            app.lock_requirements(&requirements_file.to_string_lossy(), &output_file.to_string_lossy())?;
         },
-        Some(Commands::Run { script, args }) => {
+        Some(Commands::Run { venv, python_version, python_path, index, script, args }) => {
             let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.python_version = python_version.clone();
+            app.python_path = python_path.clone();
+            app.index = index.clone();
             println!("{}", style(app.i18n.get("running_script")).bold().yellow());
             
             let script_msg = app.i18n.get_formatted(
@@ -972,57 +4148,104 @@ fn main() -> Result<()> {
             println!("{}", script_msg);
             
             // 确保存在虚拟环境
-            let venv_dir = ".venv";
-            if !Path::new(venv_dir).exists() {
-                println!("未检测到虚拟环境，正在创建...");
-                let python_version = app.select_python_version()?;
-                app.uv_manager.create_venv(venv_dir, &python_version)?;
-                
-                // 如果当前目录存在requirements.txt，则安装依赖
-                if Path::new("requirements.txt").exists() {
-                    println!("检测到requirements.txt，正在安装依赖...");
-                    app.uv_manager.install_dependencies("requirements.txt", venv_dir)?;
-                } else {
-                    // 扫描并生成requirements.txt
-                    println!("未检测到requirements.txt，正在扫描并生成...");
-                    app.find_python_files(".")?;
-                    app.extract_dependencies()?;
-                    if !app.dependencies.is_empty() {
-                        app.generate_requirements_file(".")?;
-                        app.uv_manager.install_dependencies("requirements.txt", venv_dir)?;
-                    }
-                }
-            }
+            let venv_dir = venv.as_str();
+            app.ensure_project_venv(venv_dir)?;
             
             // 使用内置的uv运行脚本
-            println!("{}", style("正在运行脚本...").bold().green());
             let uv_cmd = match app.get_internal_uv_path() {
                 Some(path) => path.to_path_buf(),
                 None => PathBuf::from(if cfg!(windows) { "uv.exe" } else { "uv" }),
             };
             
+            // 指定虚拟环境中的Python，使自定义目录的虚拟环境也能生效
+            let python_path = venv_python_path(venv_dir);
+            let mut run_args = vec!["run", "--python", python_path.as_str(), script.as_str()];
+            run_args.extend(args.iter().map(String::as_str));
+            
+            let command = command_line(&uv_cmd, &run_args);
+            if app.dry_run {
+                println!("{}", app.i18n.get_formatted("dry_run_command", &[&command]));
+                return Ok(());
+            }
+            
+            debug!("{}", command);
+            println!("{}", style(app.i18n.get("script_running")).bold().green());
             let status = Command::new(uv_cmd)
-                .args(["run", script])
-                .args(args)
+                .args(&run_args)
                 .status()
                 .context("无法运行脚本")?;
             
             if status.success() {
-                println!("{}", style("脚本执行成功!").bold().green());
+                println!("{}", style(app.i18n.get("script_succeeded")).bold().green());
             } else {
-                println!("{}", style("脚本执行失败!").bold().red());
+                println!("{}", style(app.i18n.get("script_failed")).bold().red());
                 if let Some(code) = status.code() {
-                    println!("退出码: {}", code);
+                    println!("{}", app.i18n.get_formatted("exit_code", &[&code.to_string()]));
                 }
             }
             
             // 显示使用提示
-            show_usage_tips_with_language(app.i18n.language);
+            app.show_usage_tips();
         },
-        Some(Commands::Uv { args }) => {
-            println!("{}", style("执行UV命令").bold().yellow());
+        Some(Commands::Version) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.show_version_info();
+        },
+        Some(Commands::UvVersion) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            
+            let uv_path = app.uv_manager.ensure_available()?;
+            let version = app.uv_manager.version()?;
+            println!("{}", app.i18n.get_formatted("current_uv_version", &[&version, &uv_path.display().to_string()]));
+        },
+        Some(Commands::UpdateUv) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            
+            // 当前没有可用的uv时也允许更新
+            let old_version = app.uv_manager.ensure_available()
+                .and_then(|_| app.uv_manager.version())
+                .unwrap_or_else(|_| app.i18n.get("uv_version_unknown").to_string());
+            println!("{}", app.i18n.get_formatted("current_uv_version_short", &[&old_version]));
             
+            if app.dry_run {
+                println!("{}", app.i18n.get("dry_run_update_uv"));
+                return Ok(());
+            }
+            
+            println!("{}", style(app.i18n.get("updating_uv")).bold().yellow());
+            let uv_path = app.uv_manager.update()?;
+            let new_version = app.uv_manager.version()?;
+            println!("{}", style(app.i18n.get_formatted(
+                "uv_updated",
+                &[&old_version, &new_version, &uv_path.display().to_string()]
+            )).bold().green());
+            
+            if let Some(system_uv) = app.uv_manager.system_uv() {
+                warn!("{}", style(app.i18n.get_formatted(
+                    "system_uv_takes_precedence",
+                    &[&system_uv.display().to_string()]
+                )).yellow());
+            }
+        },
+        Some(Commands::Uv { args }) => {
             let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            println!("{}", style(app.i18n.get("executing_uv")).bold().yellow());
             
             // 使用内置的uv执行命令
             let uv_cmd = match app.get_internal_uv_path() {
@@ -1030,32 +4253,55 @@ fn main() -> Result<()> {
                 None => PathBuf::from(if cfg!(windows) { "uv.exe" } else { "uv" }),
             };
             
+            let uv_args: Vec<&str> = args.iter().map(String::as_str).collect();
+            let command = command_line(&uv_cmd, &uv_args);
+            if app.dry_run {
+                println!("{}", app.i18n.get_formatted("dry_run_command", &[&command]));
+                return Ok(());
+            }
+            
+            debug!("{}", command);
             let status = Command::new(uv_cmd)
                 .args(args)
                 .status()
                 .context("无法执行UV命令")?;
             
             if status.success() {
-                println!("{}", style("UV命令执行成功!").bold().green());
+                println!("{}", style(app.i18n.get("uv_succeeded")).bold().green());
             } else {
-                println!("{}", style("UV命令执行失败!").bold().red());
+                println!("{}", style(app.i18n.get("uv_failed")).bold().red());
                 if let Some(code) = status.code() {
-                    println!("退出码: {}", code);
+                    println!("{}", app.i18n.get_formatted("exit_code", &[&code.to_string()]));
                 }
             }
             
             // 显示使用提示
-            show_usage_tips_with_language(app.i18n.language);
+            app.show_usage_tips();
         },
-        Some(Commands::Pip { packages }) => {
+        Some(Commands::Pip { venv, python_version, python_path, index, requirements, packages }) => {
+            if let Some(requirements) = requirements {
+                if !Path::new(requirements).is_file() {
+                    return Err(anyhow!("找不到依赖文件: {}", requirements));
+                }
+            } else if packages.is_empty() {
+                return Err(anyhow!("请指定要安装的包，或使用--requirements指定依赖文件"));
+            }
+            
             let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.python_version = python_version.clone();
+            app.python_path = python_path.clone();
+            app.index = index.clone();
             println!("{}", style(app.i18n.get("installing_packages")).bold().yellow());
             
             // 检查并确保虚拟环境存在
-            let venv_dir = ".venv";
+            let venv_dir = venv.as_str();
             if !Path::new(venv_dir).exists() {
-                println!("未检测到虚拟环境，正在创建...");
-                let python_version = app.select_python_version()?;
+                println!("{}", app.i18n.get("venv_missing_creating"));
+                let python_version = app.venv_python()?;
                 
                 let creating_venv_msg = app.i18n.get_formatted(
                     "creating_venv", 
@@ -1063,61 +4309,129 @@ fn main() -> Result<()> {
                 );
                 println!("\n{}", creating_venv_msg);
                 
-                // 确保UV可用
-                app.uv_manager.ensure_available()?;
-                
                 // 创建虚拟环境
-                app.uv_manager.create_venv(venv_dir, &python_version)?;
+                app.create_venv(venv_dir, &python_version)?;
                 
                 // 创建激活脚本
-                create_activation_scripts(venv_dir)?;
-                
-                println!("{}", style(app.i18n.get("created_activation_scripts")).bold().green());
+                app.create_activation_scripts(venv_dir)?;
             }
             
-            // 使用内置的uv安装包
-            println!("{}", style(app.i18n.get("installing_dependencies")).bold().green());
-            
-            // 使用venv中的pip来安装包
-            let pip_path = if cfg!(windows) {
-                format!("{}/Scripts/pip.exe", venv_dir)
-            } else {
-                format!("{}/bin/pip", venv_dir)
-            };
+            // 依赖文件和包名一起安装
+            let mut install_args: Vec<String> = Vec::new();
+            if let Some(requirements) = requirements {
+                install_args.push("-r".to_string());
+                install_args.push(requirements.clone());
+            }
+            install_args.extend(packages.iter().cloned());
             
-            // 使用venv的pip安装包
-            let mut command = Command::new(&pip_path);
-            command.arg("install");
-            command.args(packages);
+            app.show_package_index();
+            if app.dry_run {
+                println!("{}", app.i18n.get_formatted("dry_run_install_packages", &[venv_dir, &install_args.join(" ")]));
+                return Ok(());
+            }
             
-            let status = command
-                .status()
-                .context(format!("无法安装包，pip路径：{}", pip_path))?;
+            // 使用uv的pip接口安装到虚拟环境，uv创建的虚拟环境默认不包含pip
+            println!("{}", style(app.i18n.get("installing_dependencies")).bold().green());
+            app.uv_manager.ensure_available()?;
             
-            if status.success() {
-                println!("{}", style(app.i18n.get("packages_installed")).bold().green());
-            } else {
+            let mut pip_args = app.index.args();
+            pip_args.extend(install_args);
+            if let Err(e) = app.uv_manager.install_packages(venv_dir, &pip_args) {
                 println!("{}", style(app.i18n.get("packages_install_failed")).bold().red());
-                if let Some(code) = status.code() {
-                    println!("退出码: {}", code);
-                }
+                return Err(e.context(PyWandError::InstallFailed));
             }
+            println!("{}", style(app.i18n.get("packages_installed")).bold().green());
             
             // 显示使用提示
-            show_usage_tips_with_language(app.i18n.language);
+            app.show_usage_tips();
         },
-        Some(Commands::Lang { code }) => {
-            let app = PyWand::new();
-            
-            let language = match code.as_str() {
-                "en" => Language::English,
-                "zh" => Language::Chinese,
-                "ja" => Language::Japanese,
-                "ko" => Language::Korean,
-                "fr" => Language::French,
-                "de" => Language::German,
-                "ru" => Language::Russian,
-                _ => {
+        Some(Commands::Add { venv, python_version, python_path, index, requirements, packages }) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.python_version = python_version.clone();
+            app.python_path = python_path.clone();
+            app.index = index.clone();
+            println!("{}", style(app.i18n.get("installing_packages")).bold().yellow());
+            
+            app.add_packages(venv, requirements, packages)?;
+            app.show_usage_tips();
+        },
+        Some(Commands::Remove { path, venv, requirements, packages, scan }) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.scan = scan.clone();
+            app.resolve.prefer = ConflictStrategy::Warn;
+            println!("{}", style(app.i18n.get("removing_packages")).bold().yellow());
+            
+            app.remove_packages(path, venv, requirements, packages)?;
+            app.show_usage_tips();
+        },
+        Some(Commands::Export { path, output, force, archive_format, compression, no_compression, os, arch, python_version, target, all_platforms, yes, include_data, export_conda, bundle_wheels, keep_export_dir, scan, resolve }) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            app.scan = scan.clone();
+            app.resolve = resolve.clone();
+            
+            app.find_python_files(path)?;
+            let report = app.extract_dependencies()?;
+            app.show_dependency_report(&report);
+            
+            let options = ExportOptions {
+                output: output.clone(),
+                force: *force,
+                archive_format: *archive_format,
+                compression_level: *compression,
+                no_compression: *no_compression,
+                target: os.as_ref().map(|os| (os.clone(), arch.clone())),
+                python_version: python_version.clone(),
+                targets: target.clone(),
+                all_platforms: *all_platforms,
+                yes: *yes,
+                include_data: include_data.clone(),
+                conda: *export_conda,
+                bundle_wheels: *bundle_wheels,
+                keep_export_dir: keep_export_dir.clone(),
+            };
+            app.export_development_flow(&options)?;
+        },
+        Some(Commands::Init { path, python_version, lang, sample, force }) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            println!("{}", style(app.i18n.get("initializing_project")).bold().yellow());
+            
+            app.init_project(Path::new(path), python_version.as_deref(), lang.as_deref(), *sample, *force)?;
+            
+            println!("{}", style(app.i18n.get("project_initialized")).bold().green());
+            app.show_usage_tips();
+        },
+        Some(Commands::Lang { list: true, .. }) => {
+            // 当前语言：已保存的设置，否则为系统语言
+            let current = load_language_preference().unwrap_or_default();
+            show_language_list(current);
+        },
+        Some(Commands::Lang { code, .. }) => {
+            let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
+            let code = code.as_deref().unwrap_or_default();
+            
+            let language = match Language::from_code(code) {
+                Some(language) => language,
+                None => {
                     let unsupported_msg = app.i18n.get_formatted(
                         "unsupported_language",
                         &[code]
@@ -1132,24 +4446,400 @@ fn main() -> Result<()> {
             println!("{}", style(i18n.get("language_changed")).bold().green());
             
             // 保存语言设置到配置文件
-            if let Err(e) = save_language_preference(&code) {
+            if app.dry_run {
+                println!("{}", i18n.get_formatted("dry_run_save_language", &[code]));
+                return Ok(());
+            }
+            if let Err(e) = save_language_preference(code) {
                 println!("Warning: Could not save language preference: {}", e);
             }
             
             // 显示使用提示，使用指定的语言
-            show_usage_tips_with_language(language);
+            if !app.quiet && !app.no_tips {
+                show_usage_tips_with_language(language);
+            }
         },
         None => {
             let mut app = PyWand::new();
+            app.dry_run = cli.dry_run;
+            app.quiet = cli.quiet;
+            app.no_tips = cli.no_tips;
+            app.uv_manager.set_source(cli.uv_source);
             println!("{}", style(app.i18n.get("no_command")).bold().yellow());
             println!("{}", app.i18n.get("scanning_current"));
             
             // 默认在当前目录查找Python文件
             app.find_python_files(".")?;
-            app.extract_dependencies()?;
+            let report = app.extract_dependencies()?;
+            app.show_dependency_report(&report);
             app.show_main_menu()?;
         }
     }
     
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// 在临时目录中创建文件，路径中的目录会自动创建
+    fn write_file(root: &Path, relative: &str, content: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    
+    fn declared(requirement: &str, source: &'static str) -> DeclaredRequirement {
+        DeclaredRequirement { requirement: requirement.to_string(), source }
+    }
+    
+    #[test]
+    fn conflicting_exact_pin_fails_with_prefer_error() {
+        let entries = vec![
+            declared("flask==2.0", "requirements.txt"),
+            declared("flask>=2.0.1", "pyproject.toml"),
+        ];
+        assert!(!constraints_compatible(&entries));
+        
+        let i18n = I18n::with_fallbacks(Language::English, Vec::new());
+        let error = resolve_declared_dependencies(entries.clone(), ConflictStrategy::Error, &i18n).unwrap_err();
+        assert!(error.to_string().contains("flask==2.0 (requirements.txt), flask>=2.0.1 (pyproject.toml)"));
+        
+        // 只报告依赖的子命令保留最先声明的约束
+        let resolved = resolve_declared_dependencies(entries, ConflictStrategy::Warn, &i18n).unwrap();
+        assert_eq!(resolved, vec!["flask==2.0"]);
+    }
+    
+    #[test]
+    fn overlapping_ranges_are_compatible() {
+        let compatible = |first: &str, second: &str| {
+            constraints_compatible(&[declared(first, "requirements.txt"), declared(second, "pyproject.toml")])
+        };
+        assert!(compatible("flask<2", "flask<1"));
+        assert!(compatible("flask>=2", "flask<3"));
+        assert!(compatible("flask<=2.0", "flask!=2.0"));
+        assert!(compatible("flask~=1.4", "flask<1.5"));
+        assert!(!compatible("flask==1.1", "flask>=2.0"));
+        assert!(!compatible("flask<1", "flask>=1"));
+        
+        assert_eq!(version_just_below("2.0").as_deref(), Some("1.999999"));
+        assert_eq!(version_just_below("1.1").as_deref(), Some("1.0.999999"));
+        assert_eq!(version_just_below("0.0"), None);
+    }
+    
+    #[test]
+    fn compatible_constraints_are_merged() {
+        let entries = vec![
+            declared("flask>=2.0", "requirements.txt"),
+            declared("Flask<3", "pyproject.toml"),
+        ];
+        let i18n = I18n::with_fallbacks(Language::English, Vec::new());
+        let resolved = resolve_declared_dependencies(entries, ConflictStrategy::Error, &i18n).unwrap();
+        assert_eq!(resolved, vec!["flask>=2.0,<3"]);
+    }
+    
+    #[test]
+    fn requirements_with_the_same_canonical_name_are_deduplicated() {
+        assert_eq!(requirement_name("Flask"), requirement_name("flask"));
+        assert_eq!(requirement_name("Flask-SQLAlchemy"), requirement_name("flask_sqlalchemy"));
+        
+        let requirements = vec!["Flask", "flask_sqlalchemy", "flask>=2.0", "Flask-SQLAlchemy", "requests"]
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        // 同名时保留带版本约束的声明
+        assert_eq!(sort_requirements(requirements), vec!["flask>=2.0", "Flask-SQLAlchemy", "requests"]);
+    }
+    
+    #[test]
+    fn mapped_extras_are_kept_in_requirements() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(MAPPINGS_FILE_NAME);
+        write_file(dir.path(), MAPPINGS_FILE_NAME, r#"{"uvicorn": "uvicorn[ standard ]"}"#);
+        
+        let i18n = I18n::with_fallbacks(Language::English, Vec::new());
+        let mappings = read_mappings_file(&path, &i18n).unwrap();
+        assert_eq!(normalize_package_name("uvicorn", None, &mappings).as_deref(), Some("uvicorn[standard]"));
+        
+        for invalid in ["uvicorn[standard", "uvicorn[]", "uvicorn>=0.30"] {
+            write_file(dir.path(), MAPPINGS_FILE_NAME, &format!(r#"{{"uvicorn": "{}"}}"#, invalid));
+            let error = read_mappings_file(&path, &i18n).unwrap_err();
+            assert!(error.to_string().contains(invalid), "{}", error);
+        }
+    }
+    
+    #[test]
+    fn dependency_diff_reports_unused_and_undeclared() {
+        let declared = vec!["Flask>=2.0".to_string(), "pyyaml==6.0.1".to_string()];
+        let inferred = vec!["flask".to_string(), "requests".to_string()];
+        
+        let diff = compare_dependencies(&declared, &inferred);
+        assert_eq!(diff.matching, vec!["Flask>=2.0"]);
+        assert_eq!(diff.unused, vec!["pyyaml==6.0.1"]);
+        assert_eq!(diff.undeclared, vec!["requests"]);
+    }
+    
+    #[test]
+    fn added_requirement_keeps_file_order() {
+        let pinned = format!("{}=={}", requirement_with_extras("requests[socks]>=2"), "2.32.3");
+        assert_eq!(pinned, "requests[socks]==2.32.3");
+        
+        // 已按包名排序时插入到对应位置
+        let sorted = format!("{}\nflask==3.0.3\n# HTTP\nurllib3==2.2.2\n", GENERATED_MARKER);
+        assert_eq!(
+            insert_requirement(&sorted, &pinned),
+            format!("{}\nflask==3.0.3\n# HTTP\nrequests[socks]==2.32.3\nurllib3==2.2.2\n", GENERATED_MARKER),
+        );
+        
+        // 未排序时追加到最后一个依赖之后
+        let unsorted = "urllib3==2.2.2\nflask==3.0.3\n\n# 结束\n";
+        assert_eq!(
+            insert_requirement(unsorted, "requests==2.32.3"),
+            "urllib3==2.2.2\nflask==3.0.3\nrequests==2.32.3\n\n# 结束\n",
+        );
+        
+        assert_eq!(insert_requirement("", "requests==2.32.3"), "requests==2.32.3\n");
+    }
+    
+    #[test]
+    fn export_archive_skips_pycache_and_applies_exclude_only_to_src() {
+        let dir = tempfile::tempdir().unwrap();
+        let export_path = dir.path().join("export");
+        for file in [
+            "requirements.txt",
+            "setup.sh",
+            "__pycache__/setup.cpython-311.pyc",
+            "wheels/requests-2.32.3-py3-none-any.whl",
+            "wheels/__pycache__/stale.pyc",
+            "src/app.py",
+            "src/notes.txt",
+            "src/__pycache__/app.cpython-311.pyc",
+        ] {
+            write_file(&export_path, file, "");
+        }
+        
+        let exclude = build_glob_set(&["*.txt".to_string(), "setup*".to_string()]).unwrap();
+        let excluded_dirs: Vec<String> = DEFAULT_EXCLUDED_DIRS.iter().map(|dir| dir.to_string()).collect();
+        let files = export_files(&export_path, &excluded_dirs, &exclude).unwrap();
+        
+        let output = dir.path().join("export.tar.gz");
+        let i18n = I18n::with_fallbacks(Language::English, Vec::new());
+        create_archive(&export_path, &files, &output, Some(Compression::default()), &i18n).unwrap();
+        
+        let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(fs::File::open(&output).unwrap()));
+        let entries: Vec<String> = archive
+            .entries()
+            .unwrap()
+            .map(|entry| entry.unwrap().path().unwrap().to_string_lossy().replace('\\', "/"))
+            .collect();
+        assert_eq!(entries, vec!["requirements.txt", "setup.sh", "src/app.py", "wheels/requests-2.32.3-py3-none-any.whl"]);
+    }
+    
+    #[test]
+    fn dependency_report_lists_pypi_package_names() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "app.py", "import os\nimport yaml\nimport requests\nfrom sklearn import svm\nfrom google.cloud import storage\nimport helpers\n");
+        write_file(dir.path(), "helpers.py", "import json\n");
+        
+        let mut app = PyWand::new();
+        app.quiet = true;
+        app.find_python_files(&dir.path().display().to_string()).unwrap();
+        let report = app.extract_dependencies().unwrap();
+        
+        assert_eq!(report.external, vec!["google-cloud-storage", "PyYAML", "requests", "scikit-learn"]);
+        assert_eq!(report.external, app.requirements());
+        assert_eq!(report.local, vec!["helpers"]);
+        assert!(report.stdlib.contains(&"os".to_string()) && report.stdlib.contains(&"json".to_string()));
+        assert!(report.unresolved.is_empty());
+    }
+    
+    #[test]
+    fn huge_size_limits_do_not_overflow() {
+        let mut app = PyWand::new();
+        app.scan.max_file_size = 99999999999999999;
+        app.scan.oversized_head = Some(u64::MAX);
+        let config = app.scan_config();
+        assert_eq!(config.max_file_size, u64::MAX);
+        assert_eq!(config.oversized_head, u64::MAX);
+    }
+    
+    #[test]
+    fn annotated_requirements_list_importing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("requirements.txt");
+        let files = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+        let sources = HashMap::from([
+            ("requests".to_string(), files(&["api/client.py", "app.py"])),
+            ("pyyaml".to_string(), files(&["a.py", "b.py", "c.py", "d.py", "e.py"])),
+        ]);
+        let requirements = vec!["PyYAML".to_string(), "requests>=2".to_string(), "toml".to_string()];
+        
+        let i18n = I18n::with_fallbacks(Language::English, Vec::new());
+        write_requirements_txt(&path, &requirements, Some(&sources), false, &i18n).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!(
+                "{}\nPyYAML  # used in: a.py, b.py, c.py and 2 more\nrequests>=2  # used in: api/client.py, app.py\ntoml\n",
+                GENERATED_MARKER
+            )
+        );
+        
+        let i18n = I18n::with_fallbacks(Language::German, Vec::new());
+        assert_eq!(format_sources(&sources["pyyaml"], &i18n), "a.py, b.py, c.py und 2 weitere");
+    }
+    
+    #[test]
+    fn date_from_unix_days_known_dates() {
+        assert_eq!(date_from_unix_days(0), "1970-01-01");
+        assert_eq!(date_from_unix_days(-1), "1969-12-31");
+        // 2000年是闰年（能被400整除），2100年不是
+        assert_eq!(date_from_unix_days(11016), "2000-02-29");
+        assert_eq!(date_from_unix_days(11017), "2000-03-01");
+        assert_eq!(date_from_unix_days(19782), "2024-02-29");
+        assert_eq!(date_from_unix_days(47540), "2100-02-28");
+        assert_eq!(date_from_unix_days(47541), "2100-03-01");
+        // 跨年
+        assert_eq!(date_from_unix_days(20088), "2024-12-31");
+        assert_eq!(date_from_unix_days(20089), "2025-01-01");
+    }
+    
+    #[test]
+    fn requirements_and_pyproject_formats_list_the_same_dependencies() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path().join("project");
+        let output = dir.path().join("output");
+        write_file(&project, "app.py", "import requests\nimport yaml\n");
+        write_file(&output, "existing/pyproject.toml", "[project]\nname = \"demo\"\ndependencies = [\"Requests>=2.31\"]\n\n[tool.black]\nline-length = 100\n");
+        
+        let mut app = PyWand::new();
+        app.quiet = true;
+        app.find_python_files(&project.display().to_string()).unwrap();
+        app.extract_dependencies().unwrap();
+        
+        let requirements_txt = output.join("requirements.txt");
+        app.generate_requirements_file(&requirements_txt, OutputFormat::Requirements, false).unwrap();
+        let content = fs::read_to_string(&requirements_txt).unwrap();
+        let lines: Vec<&str> = content.lines().filter(|line| !line.starts_with('#')).collect();
+        assert_eq!(lines, vec!["PyYAML", "requests"]);
+        
+        let dependencies = |path: &Path| -> (DocumentMut, Vec<String>) {
+            let document: DocumentMut = fs::read_to_string(path).unwrap().parse().unwrap();
+            let array = document["project"]["dependencies"].as_array().unwrap();
+            let dependencies = array.iter().map(|dep| dep.as_str().unwrap().to_string()).collect();
+            (document, dependencies)
+        };
+        
+        let pyproject = output.join("pyproject.toml");
+        app.generate_requirements_file(&pyproject, OutputFormat::Pyproject, false).unwrap();
+        assert_eq!(dependencies(&pyproject).1, lines);
+        
+        // 合并到已有的pyproject.toml：保留已声明的约束和其他表，只追加新的包
+        let existing = output.join("existing/pyproject.toml");
+        app.generate_requirements_file(&existing, OutputFormat::Pyproject, false).unwrap();
+        let (document, merged) = dependencies(&existing);
+        assert_eq!(merged, vec!["Requests>=2.31", "PyYAML"]);
+        assert_eq!(document["project"]["name"].as_str(), Some("demo"));
+        assert_eq!(document["tool"]["black"]["line-length"].as_integer(), Some(100));
+    }
+    
+    #[test]
+    fn windows_export_zip_contains_requirements_and_setup_script() {
+        let dir = tempfile::tempdir().unwrap();
+        let export_path = dir.path().join("export");
+        write_file(&export_path, "requirements.txt", "requests\n");
+        write_file(&export_path, "src/app.py", "import requests\n");
+        write_file(&export_path, "src/pkg/client.py", "");
+        let i18n = I18n::with_fallbacks(Language::English, Vec::new());
+        create_setup_scripts(&export_path, "3.11.7", "windows10", "x64", false, &i18n).unwrap();
+        
+        let archive_format = ArchiveFormat::default_for("windows10");
+        assert!(archive_format == ArchiveFormat::Zip);
+        let excluded_dirs: Vec<String> = DEFAULT_EXCLUDED_DIRS.iter().map(|dir| dir.to_string()).collect();
+        let files = export_files(&export_path, &excluded_dirs, &build_glob_set(&[]).unwrap()).unwrap();
+        let output = dir.path().join(format!("export.{}", archive_format.extension()));
+        create_zip_archive(&export_path, &files, &output, Some(Compression::default()), &i18n).unwrap();
+        
+        let mut archive = zip::ZipArchive::new(fs::File::open(&output).unwrap()).unwrap();
+        let mut names: Vec<&str> = archive.file_names().collect();
+        names.sort();
+        assert_eq!(names, vec!["activate.bat", "requirements.txt", "setup.bat", "src/app.py", "src/pkg/client.py"]);
+        
+        let mut requirements = String::new();
+        archive.by_name("requirements.txt").unwrap().read_to_string(&mut requirements).unwrap();
+        assert_eq!(requirements, "requests\n");
+    }
+    
+    #[test]
+    fn requirements_output_does_not_depend_on_scan_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = "import requests\nimport yaml\nimport Babel\n";
+        let second = "import attrs\nimport requests\n";
+        
+        // 两个项目中的导入相同，但出现在不同的文件中，扫描顺序不同
+        let mut outputs = Vec::new();
+        for (project, contents) in [("one", [first, second]), ("two", [second, first])] {
+            let project = dir.path().join(project);
+            write_file(&project, "a.py", contents[0]);
+            write_file(&project, "b.py", contents[1]);
+            
+            let mut app = PyWand::new();
+            app.quiet = true;
+            app.no_header = true;
+            app.find_python_files(&project.display().to_string()).unwrap();
+            app.extract_dependencies().unwrap();
+            let output = project.join("requirements.txt");
+            app.generate_requirements_file(&output, OutputFormat::Requirements, false).unwrap();
+            outputs.push(fs::read(&output).unwrap());
+        }
+        
+        assert_eq!(outputs[0], outputs[1]);
+        assert_eq!(String::from_utf8_lossy(&outputs[0]), format!("{}\nattrs\nBabel\nPyYAML\nrequests\n", GENERATED_MARKER));
+    }
+    
+    #[test]
+    fn config_can_remove_build_from_excluded_dirs() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "app.py", "import requests\n");
+        write_file(dir.path(), "build/make_release.py", "import twine\n");
+        write_file(dir.path(), "examples/demo.py", "import matplotlib\n");
+        let config_path = dir.path().join(CONFIG_FILE_PATH);
+        let i18n = I18n::with_fallbacks(Language::English, Vec::new());
+        
+        let scanned_dependencies = |excluded_dirs: Vec<String>| {
+            let mut app = PyWand::new();
+            app.quiet = true;
+            app.excluded_dirs = excluded_dirs;
+            app.find_python_files(&dir.path().display().to_string()).unwrap();
+            app.extract_dependencies().unwrap().external
+        };
+        
+        // 默认追加到内置的排除目录，build/仍然被排除
+        write_file(dir.path(), CONFIG_FILE_PATH, "exclude_dirs = [\"examples\"]\n");
+        let config = read_config_file(&config_path, &i18n).unwrap();
+        assert_eq!(scanned_dependencies(config.excluded_dirs), vec!["requests"]);
+        
+        write_file(dir.path(), CONFIG_FILE_PATH, "exclude_dirs_mode = \"override\"\nexclude_dirs = [\".git\", \"examples\"]\nexclude = []\n");
+        let config = read_config_file(&config_path, &i18n).unwrap();
+        assert_eq!(config.unknown_keys, vec!["exclude"]);
+        assert_eq!(scanned_dependencies(config.excluded_dirs), vec!["requests", "twine"]);
+    }
+    
+    #[test]
+    fn removed_stdlib_import_warns_for_target_version() {
+        let dir = tempfile::tempdir().unwrap();
+        write_file(dir.path(), "legacy_build.py", "from distutils.core import setup\nimport requests\n");
+        
+        let mut app = PyWand::new();
+        app.quiet = true;
+        app.i18n = I18n::with_fallbacks(Language::English, Vec::new());
+        app.find_python_files(&dir.path().display().to_string()).unwrap();
+        app.extract_dependencies().unwrap();
+        // 目标版本未知时不检查
+        assert!(app.stdlib_warnings.is_empty());
+        
+        app.set_python_version("3.12");
+        let warnings: Vec<&String> = app.stdlib_warnings.iter().collect();
+        assert_eq!(warnings, vec!["Warning: the standard library module distutils was removed in Python 3.12; use setuptools, packaging instead"]);
+    }
+}
@@ -0,0 +1,87 @@
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use clap::ValueEnum;
+use indicatif::{ProgressBar, ProgressStyle};
+use serde::Serialize;
+
+/// 进度的显示方式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ProgressMode {
+    /// 在终端中显示进度条
+    Bar,
+    /// 不显示进度条，向stderr逐行输出JSON事件，便于编辑器等工具集成
+    Json,
+}
+
+/// 是否以JSON事件输出进度，整个进程共用
+static JSON_EVENTS: AtomicBool = AtomicBool::new(false);
+
+/// 设置本次运行的进度显示方式
+pub fn set_mode(mode: ProgressMode) {
+    JSON_EVENTS.store(mode == ProgressMode::Json, Ordering::Relaxed);
+}
+
+/// 是否以JSON事件输出进度
+pub fn json_events() -> bool {
+    JSON_EVENTS.load(Ordering::Relaxed)
+}
+
+/// 进度事件，输出为一行JSON，event字段为事件名（如 {"event":"file","path":"app.py"}）
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ProgressEvent<'a> {
+    /// 开始扫描目录、归档或标准输入
+    ScanStart { path: &'a str },
+    /// 扫描到一个Python文件
+    File { path: &'a str },
+    /// 推断出一个依赖，name为规范化的包名，requirement为写入依赖文件的条目（可能带版本约束）
+    DepFound { name: &'a str, requirement: &'a str },
+    /// 日志输出的警告或诊断信息，level为warn、info、debug等
+    Log { level: String, message: String },
+    /// 命令失败
+    Error { message: String },
+    /// 命令结束，总是最后一个事件
+    Done,
+}
+
+/// 以JSON事件模式运行时向stderr输出一个事件，否则什么也不做
+pub fn emit(event: ProgressEvent) {
+    if !json_events() {
+        return;
+    }
+    if let Ok(line) = serde_json::to_string(&event) {
+        // 加锁写入整行，避免与其他线程的输出交错
+        let mut stderr = io::stderr().lock();
+        let _ = writeln!(stderr, "{}", line);
+    }
+}
+
+/// 创建计数的进度条，hidden为true或以JSON事件输出进度时不显示
+pub fn bar(len: u64, hidden: bool) -> ProgressBar {
+    if hidden || json_events() {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new(len);
+    pb.set_style(ProgressStyle::default_bar()
+        .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
+        .expect("进度条模板无效")
+        .progress_chars("#>-"));
+    pb
+}
+
+/// 创建显示当前操作的旋转指示，hidden为true或以JSON事件输出进度时不显示
+pub fn spinner(message: &str, hidden: bool) -> ProgressBar {
+    if hidden || json_events() {
+        return ProgressBar::hidden();
+    }
+    let pb = ProgressBar::new_spinner();
+    pb.set_style(ProgressStyle::default_spinner()
+        .tick_chars("⠁⠂⠄⡀⢀⠠⠐⠈ ")
+        .template("{spinner:.green} {msg}")
+        .expect("进度条模板无效"));
+    pb.set_message(message.to_string());
+    pb.enable_steady_tick(Duration::from_millis(100));
+    pb
+}
@@ -0,0 +1,265 @@
+use std::collections::HashMap;
+use std::env;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::version::version_satisfies;
+
+/// PyPI JSON API的默认地址，可以通过PYWAND_PYPI_URL环境变量替换为镜像
+const PYPI_JSON_URL: &str = "https://pypi.org/pypi";
+
+/// 查询请求的超时时间
+const PYPI_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 每个包最多查询的次数，请求失败时按指数退避重试
+const PYPI_ATTEMPTS: u32 = 3;
+
+/// 第一次重试前的等待时间，之后每次翻倍
+const PYPI_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// 包索引中查询到的包信息
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PackageInfo {
+    /// 包最新版本声明的Python版本要求，如">=3.8"
+    #[serde(default)]
+    pub requires_python: Option<String>,
+}
+
+/// PyPI JSON API响应中用到的部分
+#[derive(Deserialize)]
+struct PypiResponse {
+    info: PackageInfo,
+}
+
+/// 包索引查询接口，包不存在时返回None；网络错误时返回Err
+pub trait PackageIndex {
+    fn lookup(&self, name: &str) -> Result<Option<PackageInfo>>;
+}
+
+/// 通过PyPI JSON API查询包是否存在，所有查询共用同一个客户端以复用连接
+pub struct PypiIndex {
+    client: reqwest::blocking::Client,
+    base_url: String,
+}
+
+impl PypiIndex {
+    pub fn new() -> Result<Self> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(PYPI_TIMEOUT)
+            .build()
+            .context("无法创建HTTP客户端")?;
+        
+        let base_url = env::var("PYWAND_PYPI_URL")
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .unwrap_or_else(|| PYPI_JSON_URL.to_string());
+        
+        Ok(PypiIndex { client, base_url: base_url.trim_end_matches('/').to_string() })
+    }
+}
+
+impl PackageIndex for PypiIndex {
+    fn lookup(&self, name: &str) -> Result<Option<PackageInfo>> {
+        let url = format!("{}/{}/json", self.base_url, name);
+        let response = self.client
+            .get(&url)
+            .send()
+            .with_context(|| format!("无法访问PyPI: {}", url))?;
+        
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response: PypiResponse = response.error_for_status()
+            .with_context(|| format!("PyPI返回错误: {}", url))?
+            .json()
+            .with_context(|| format!("无法解析PyPI的响应: {}", url))?;
+        Ok(Some(response.info))
+    }
+}
+
+/// 包的校验结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum PackageStatus {
+    Exists,
+    Missing,
+    /// 无法确认（离线或请求失败），保留该包
+    Unknown,
+}
+
+/// 带缓存的包校验器，同一次运行中每个包名只查询一次
+pub struct PackageVerifier<I: PackageIndex> {
+    index: I,
+    cache: HashMap<String, PackageStatus>,
+    /// 已查询到的包的Python版本要求
+    requires_python: HashMap<String, String>,
+    /// 第一次网络错误，出现后不再继续请求
+    offline_error: Option<String>,
+    /// 第一次重试前的等待时间
+    retry_delay: Duration,
+}
+
+impl<I: PackageIndex> PackageVerifier<I> {
+    pub fn new(index: I) -> Self {
+        PackageVerifier {
+            index,
+            cache: HashMap::new(),
+            requires_python: HashMap::new(),
+            offline_error: None,
+            retry_delay: PYPI_RETRY_DELAY,
+        }
+    }
+    
+    /// 查询包名是否存在于索引中，包名不区分大小写
+    pub fn check(&mut self, name: &str) -> PackageStatus {
+        let key = name.to_lowercase();
+        if let Some(status) = self.cache.get(&key) {
+            return status.clone();
+        }
+        if self.offline_error.is_some() {
+            return PackageStatus::Unknown;
+        }
+        
+        let status = match self.lookup_with_retry(&key) {
+            Ok(Some(info)) => {
+                if let Some(requires_python) = info.requires_python.filter(|spec| !spec.trim().is_empty()) {
+                    self.requires_python.insert(key.clone(), requires_python);
+                }
+                PackageStatus::Exists
+            }
+            Ok(None) => PackageStatus::Missing,
+            Err(e) => {
+                self.offline_error = Some(format!("{}: {}", e, e.root_cause()));
+                PackageStatus::Unknown
+            }
+        };
+        self.cache.insert(key, status.clone());
+        status
+    }
+    
+    /// 包声明的Python版本要求，包不存在、未声明或无法查询时返回None
+    pub fn requires_python(&mut self, name: &str) -> Option<String> {
+        self.check(name);
+        self.requires_python.get(&name.to_lowercase()).cloned()
+    }
+    
+    /// 声明的requires_python不包含`python_version`的包及其版本要求；无法查询或无法解析的约束视为兼容
+    pub fn incompatible_packages(&mut self, names: &[String], python_version: &str) -> Vec<(String, String)> {
+        names
+            .iter()
+            .filter_map(|name| {
+                let requires_python = self.requires_python(name)?;
+                (version_satisfies(python_version, &requires_python) == Some(false))
+                    .then(|| (name.clone(), requires_python))
+            })
+            .collect()
+    }
+    
+    /// 查询包的信息，请求失败时按指数退避重试，只有明确的404才算不存在；所有尝试都失败时返回最后一次的错误
+    fn lookup_with_retry(&self, name: &str) -> Result<Option<PackageInfo>> {
+        let mut delay = self.retry_delay;
+        let mut attempt = 1;
+        loop {
+            match self.index.lookup(name) {
+                Err(_) if attempt < PYPI_ATTEMPTS => {
+                    thread::sleep(delay);
+                    delay *= 2;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+    
+    /// 导致校验中断的网络错误
+    pub fn offline_error(&self) -> Option<&str> {
+        self.offline_error.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    
+    use anyhow::bail;
+    
+    /// 测试用的包索引：packages中的包存在（值为requires_python），其余返回404；前`failures`次查询返回网络错误
+    #[derive(Default)]
+    struct MockIndex {
+        packages: HashMap<&'static str, Option<&'static str>>,
+        failures: Cell<u32>,
+        lookups: Cell<u32>,
+    }
+    
+    impl MockIndex {
+        fn with_packages(packages: &[(&'static str, Option<&'static str>)]) -> Self {
+            MockIndex { packages: packages.iter().copied().collect(), ..MockIndex::default() }
+        }
+    }
+    
+    impl PackageIndex for MockIndex {
+        fn lookup(&self, name: &str) -> Result<Option<PackageInfo>> {
+            self.lookups.set(self.lookups.get() + 1);
+            if self.failures.get() > 0 {
+                self.failures.set(self.failures.get() - 1);
+                bail!("连接超时");
+            }
+            Ok(self.packages.get(name).map(|requires_python| PackageInfo {
+                requires_python: requires_python.map(str::to_string),
+            }))
+        }
+    }
+    
+    #[test]
+    fn missing_package_is_flagged() {
+        let mut verifier = PackageVerifier::new(MockIndex::with_packages(&[("requests", None)]));
+        
+        assert_eq!(verifier.check("requests"), PackageStatus::Exists);
+        assert_eq!(verifier.check("reqeusts"), PackageStatus::Missing);
+        // 同一次运行中每个包名只查询一次，不区分大小写
+        assert_eq!(verifier.check("Requests"), PackageStatus::Exists);
+        assert_eq!(verifier.index.lookups.get(), 2);
+        assert_eq!(verifier.offline_error(), None);
+    }
+    
+    #[test]
+    fn transient_errors_are_retried() {
+        let index = MockIndex::with_packages(&[("requests", None)]);
+        index.failures.set(2);
+        let mut verifier = PackageVerifier::new(index);
+        verifier.retry_delay = Duration::ZERO;
+        
+        assert_eq!(verifier.check("requests"), PackageStatus::Exists);
+        assert_eq!(verifier.index.lookups.get(), 3);
+        assert_eq!(verifier.offline_error(), None);
+    }
+    
+    #[test]
+    fn repeated_errors_keep_the_package() {
+        let index = MockIndex::with_packages(&[("requests", None)]);
+        index.failures.set(PYPI_ATTEMPTS);
+        let mut verifier = PackageVerifier::new(index);
+        verifier.retry_delay = Duration::ZERO;
+        
+        assert_eq!(verifier.check("requests"), PackageStatus::Unknown);
+        assert_eq!(verifier.index.lookups.get(), PYPI_ATTEMPTS);
+        assert!(verifier.offline_error().is_some_and(|error| error.contains("连接超时")));
+        // 离线后不再继续请求
+        assert_eq!(verifier.check("flask"), PackageStatus::Unknown);
+        assert_eq!(verifier.index.lookups.get(), PYPI_ATTEMPTS);
+    }
+    
+    #[test]
+    fn requires_python_mismatch_is_reported() {
+        let index = MockIndex::with_packages(&[("httpx", Some(">=3.11")), ("requests", Some(">=3.8")), ("six", None)]);
+        let mut verifier = PackageVerifier::new(index);
+        let names: Vec<String> = ["httpx", "requests", "six", "missing"].iter().map(|name| name.to_string()).collect();
+        
+        assert_eq!(verifier.incompatible_packages(&names, "3.8.10"), vec![("httpx".to_string(), ">=3.11".to_string())]);
+        assert!(verifier.incompatible_packages(&names, "3.11.7").is_empty());
+    }
+}
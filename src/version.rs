@@ -0,0 +1,119 @@
+use std::cmp::Ordering;
+
+/// 判断版本是否满足用逗号分隔的所有约束（如">=3.8, !=3.9.*"），按PEP 440的发布版本规则比较
+///
+/// ==和!=只有写作==3.9.*时才按前缀匹配，==2.0不匹配2.0.1；不带运算符的声明（如.python-version中的3.11）按前缀匹配。
+/// 版本号或约束无法解析（如预发布版本）时返回None，由调用方决定如何处理
+pub fn version_satisfies(version: &str, spec: &str) -> Option<bool> {
+    let current = parse_release(version)?;
+    for clause in spec.split(',').map(str::trim).filter(|clause| !clause.is_empty()) {
+        let index = clause.find(|c: char| c.is_ascii_digit())?;
+        let (op, target) = (clause[..index].trim(), clause[index..].trim());
+        let wildcard = target.ends_with(".*");
+        let target_parts = parse_release(target.trim_end_matches(".*"))?;
+        
+        // 只比较给出的位数，3.11匹配3.11.7
+        let prefix_matches = current.starts_with(&target_parts);
+        // 比较大小时缺少的部分视为0，3.9与3.9.0相等
+        let ordering = compare_version_parts(&current, &target_parts);
+        let satisfied = match op {
+            "" => prefix_matches,
+            "==" if wildcard => prefix_matches,
+            "!=" if wildcard => !prefix_matches,
+            _ if wildcard => return None,
+            "==" => ordering.is_eq(),
+            "!=" => ordering.is_ne(),
+            "===" => version.trim() == target,
+            ">=" => ordering.is_ge(),
+            ">" => ordering.is_gt(),
+            "<=" => ordering.is_le(),
+            "<" => ordering.is_lt(),
+            // ~=3.8表示>=3.8且==3.*
+            "~=" => {
+                ordering.is_ge()
+                    && current.starts_with(&target_parts[..target_parts.len().saturating_sub(1)])
+            }
+            _ => return None,
+        };
+        if !satisfied {
+            return Some(false);
+        }
+    }
+    Some(true)
+}
+
+/// 将版本号拆分为数字列表，用于比较；遇到非数字的部分时停止，如"2.0rc1" -> [2]
+pub fn version_parts(version: &str) -> Vec<u32> {
+    version.split('.').map_while(|part| part.trim().parse().ok()).collect()
+}
+
+/// 解析版本号的数字部分，如"3.11.7" -> [3, 11, 7]，包含非数字的部分时返回None
+fn parse_release(version: &str) -> Option<Vec<u32>> {
+    version.trim().split('.').map(|part| part.trim().parse().ok()).collect()
+}
+
+/// 按数字逐段比较版本号，缺少的部分视为0
+fn compare_version_parts(left: &[u32], right: &[u32]) -> Ordering {
+    let len = left.len().max(right.len());
+    let padded = |parts: &[u32]| -> Vec<u32> {
+        parts.iter().copied().chain(std::iter::repeat(0)).take(len).collect()
+    };
+    padded(left).cmp(&padded(right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn matches(version: &str, spec: &str) -> bool {
+        version_satisfies(version, spec) == Some(true)
+    }
+    
+    #[test]
+    fn comparison_operators() {
+        assert!(matches("3.9.1", ">3.9"));
+        assert!(!matches("3.9.0", ">3.9"));
+        assert!(!matches("3.9", ">3.9"));
+        assert!(matches("3.11", "<=3.11"));
+        assert!(matches("3.11.0", "<=3.11"));
+        assert!(!matches("3.11.7", "<=3.11"));
+        assert!(matches("3.10.11", "<=3.11"));
+    }
+    
+    #[test]
+    fn compatible_release() {
+        assert!(matches("3.8.10", "~=3.8"));
+        assert!(matches("3.12.1", "~=3.8"));
+        assert!(!matches("3.7.9", "~=3.8"));
+        assert!(matches("3.8.10", "~=3.8.1"));
+        assert!(!matches("3.9.13", "~=3.8.1"));
+    }
+    
+    #[test]
+    fn exclusions() {
+        assert!(!matches("3.9.13", "!=3.9.*"));
+        assert!(matches("3.10.11", "!=3.9.*"));
+        assert!(matches("3.11.7", ">=3.8,!=3.9.*,<3.12"));
+        assert!(!matches("3.12.1", ">=3.8,!=3.9.*,<3.12"));
+        assert!(matches("3.11.7", ">=3.8, !=3.9.*"));
+    }
+    
+    #[test]
+    fn exact_release() {
+        assert!(matches("2.0", "==2.0"));
+        assert!(matches("2.0.0", "==2.0"));
+        assert!(!matches("2.0.1", "==2.0"));
+        assert!(matches("2.0.1", "==2.0.*"));
+        assert!(matches("2.0.1", "!=2.0"));
+        assert!(!matches("2.0.1", "!=2.0.*"));
+        // 不带运算符的Python版本声明按前缀匹配
+        assert!(matches("3.11.7", "3.11"));
+    }
+    
+    #[test]
+    fn unparseable_spec() {
+        assert_eq!(version_satisfies("3.11.7", ">=3.8rc1"), None);
+        assert_eq!(version_satisfies("3.11.7", ">=3.*"), None);
+        assert_eq!(version_satisfies("3.11.7", "latest"), None);
+    }
+}
@@ -0,0 +1,7 @@
+import requests
+
+from pkg.client import fetch
+
+
+def main():
+    print(fetch(requests.Session(), "https://example.com"))
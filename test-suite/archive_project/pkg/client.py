@@ -0,0 +1,2 @@
+def fetch(session, url):
+    return session.get(url, timeout=10).status_code
@@ -0,0 +1,11 @@
+import requests
+import torch
+
+
+def main():
+    response = requests.get("https://example.com")
+    print(torch.tensor([len(response.content)]))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,5 @@
+"""
+requirements.txt and pyproject.toml declare incompatible flask constraints.
+"""
+import flask
+import requests
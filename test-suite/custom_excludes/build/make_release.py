@@ -0,0 +1,2 @@
+"""Build helper that lives in build/, scanned because the project config stops excluding it."""
+import build_only_pkg
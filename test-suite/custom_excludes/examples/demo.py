@@ -0,0 +1 @@
+import example_only_pkg
@@ -0,0 +1,11 @@
+"""Reads config.json at runtime, so the export must include it via --include-data."""
+import json
+from pathlib import Path
+
+import requests
+
+CONFIG = json.loads((Path(__file__).parent / "config.json").read_text())
+
+
+def fetch():
+    return requests.get(CONFIG["endpoint"], timeout=CONFIG["timeout"])
@@ -0,0 +1,7 @@
+"""
+requirements.txt is out of sync with the imports below:
+flask is declared but unused, numpy is imported but undeclared.
+"""
+import numpy
+import requests
+import yaml
@@ -0,0 +1,15 @@
+import cgi
+import requests
+from distutils.version import LooseVersion
+
+
+def needs_upgrade(installed, latest):
+    return LooseVersion(installed) < LooseVersion(latest)
+
+
+def read_form():
+    return cgi.FieldStorage()
+
+
+def latest_version(package):
+    return requests.get(f"https://pypi.org/pypi/{package}/json").json()["info"]["version"]
@@ -0,0 +1,5 @@
+import requests
+
+
+def fetch_status(url):
+    return requests.get(url).status_code
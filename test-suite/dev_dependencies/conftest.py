@@ -0,0 +1,5 @@
+import responses
+
+
+def pytest_configure(config):
+    responses.start()
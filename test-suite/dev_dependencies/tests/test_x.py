@@ -0,0 +1,10 @@
+import pytest
+import requests
+
+from app import fetch_status
+
+
+@pytest.mark.parametrize("url", ["https://example.com"])
+def test_fetch_status(url, monkeypatch):
+    monkeypatch.setattr(requests, "get", lambda url: type("Response", (), {"status_code": 200}))
+    assert fetch_status(url) == 200
@@ -0,0 +1,9 @@
+import requests
+
+
+def main():
+    print(requests.__version__)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,12 @@
+import requests
+import internal_tracing  # pywand: ignore
+
+try:
+    import ujson as json  # pywand: ignore
+except ImportError:
+    import json
+
+
+def fetch(url):
+    internal_tracing.record(url)
+    return json.loads(requests.get(url).text)
@@ -0,0 +1,8 @@
+#!/usr/bin/env python
+# pywand: ignore-file
+# Legacy script, its dependencies are provided by the deployment image
+import legacy_sdk
+
+
+def run():
+    legacy_sdk.start()
@@ -0,0 +1,8 @@
+"""
+Local helper module imported by main.py; it must not be reported as a PyPI dependency.
+"""
+import requests
+
+
+def fetch_status(url):
+    return requests.get(url, timeout=5).status_code
@@ -0,0 +1,17 @@
+#!/usr/bin/env python3
+"""
+Entry point importing local modules next to third-party ones.
+"""
+import helpers
+from settings import DEFAULT_URL
+
+import requests
+
+
+def main():
+    print(helpers.fetch_status(DEFAULT_URL))
+    print(requests.__version__)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,10 @@
+"""
+Local package; `import settings` must not be reported as a PyPI dependency.
+Relative imports below always refer to local modules and are skipped.
+"""
+from . import defaults
+from .defaults import (
+    TIMEOUT,
+)
+
+DEFAULT_URL = "https://example.com"
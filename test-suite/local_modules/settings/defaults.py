@@ -0,0 +1,4 @@
+"""
+Default values for the local settings package.
+"""
+TIMEOUT = 5
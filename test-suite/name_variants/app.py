@@ -0,0 +1,6 @@
+"""
+requirements.txt spells the packages as Flask and Flask-SQLAlchemy while the imports
+use flask and flask_sqlalchemy; each should appear only once in the generated file.
+"""
+import flask
+import flask_sqlalchemy
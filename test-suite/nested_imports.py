@@ -0,0 +1,26 @@
+"""
+Sample module with imports nested inside classes, functions and conditional blocks.
+"""
+from typing import TYPE_CHECKING
+
+if TYPE_CHECKING:
+    from httpx import Client
+
+try: import orjson as fast_json
+except ImportError: fast_json = None
+
+
+class Serializer:
+    def dumps(self, data):
+        try:
+            import ujson as json
+        except ImportError:
+            import json
+        return json.dumps(data)
+
+    def load_table(self, path):
+        if path.endswith(".xlsx"):
+            if path.startswith("remote://"):
+                from openpyxl import load_workbook
+                return load_workbook(path)
+        return None
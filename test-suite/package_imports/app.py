@@ -0,0 +1,14 @@
+import requests
+
+from mypkg import thing
+from mypkg.sub import helper
+from tools import cleanup
+
+
+def main():
+    thing.run(helper.load(requests.get("https://example.com").text))
+    cleanup.finish()
+
+
+if __name__ == "__main__":
+    main()
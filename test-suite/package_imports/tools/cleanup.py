@@ -0,0 +1,2 @@
+def finish():
+    print("done")
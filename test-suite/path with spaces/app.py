@@ -0,0 +1,2 @@
+"""Lives in a directory whose name contains spaces; gen-req -p/-o should write requirements.txt here."""
+import requests
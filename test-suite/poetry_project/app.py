@@ -0,0 +1,3 @@
+import httpx
+import pendulum
+import requests
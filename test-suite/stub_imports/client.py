@@ -0,0 +1,4 @@
+def make_client(base_url):
+    from . import _backend
+
+    return _backend.connect(base_url)
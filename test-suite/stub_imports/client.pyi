@@ -0,0 +1,3 @@
+from httpx import Client
+
+def make_client(base_url: str) -> Client: ...
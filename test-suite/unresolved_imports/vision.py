@@ -0,0 +1,7 @@
+import cv2
+import numpy as np
+
+
+def load_gray(path):
+    image = cv2.imread(path)
+    return np.asarray(cv2.cvtColor(image, cv2.COLOR_BGR2GRAY))
@@ -0,0 +1,234 @@
+//! 命令行的端到端测试：在临时目录中运行编译好的pywand
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// 在临时目录中创建文件，路径中的目录会自动创建
+fn write_file(root: &Path, relative: &str, content: &str) {
+    let path = root.join(relative);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// 在`dir`中运行pywand的命令，用户目录指向`dir`下的home，不读取本机的语言设置和映射文件
+fn pywand(dir: &Path, args: &[&str]) -> Command {
+    let home = dir.join("home");
+    let mut command = Command::new(env!("CARGO_BIN_EXE_pywand"));
+    command
+        .args(args)
+        .current_dir(dir)
+        .env("HOME", &home)
+        .env("XDG_CONFIG_HOME", home.join(".config"))
+        .env("PYWAND_NO_TIPS", "1")
+        .env("LANG", "en_US.UTF-8")
+        .env_remove("LC_ALL")
+        .env_remove("LANGUAGE")
+        .env_remove("RUST_BACKTRACE");
+    command
+}
+
+#[test]
+fn analyze_json_lists_pypi_package_names() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "project/app.py", "import os\nimport yaml\nimport requests\nfrom sklearn import svm\nfrom google.cloud import storage\n");
+    
+    let output = pywand(dir.path(), &["analyze", "--json", "--path", "project"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["dependencies"], serde_json::json!(["google-cloud-storage", "PyYAML", "requests", "scikit-learn"]));
+    assert_eq!(report["stdlib"], serde_json::json!(["os"]));
+    assert_eq!(report["file_count"], 1);
+}
+
+#[test]
+fn analyze_empty_directory_exits_with_code_2() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("empty")).unwrap();
+    
+    let output = pywand(dir.path(), &["analyze", "--path", "empty"])
+        .env("RUST_BACKTRACE", "1")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.starts_with("Error: "), "{}", stderr);
+    assert!(stderr.contains("empty"), "{}", stderr);
+    // 普通的用户错误不输出调用栈
+    assert!(!stderr.contains("backtrace"), "{}", stderr);
+    assert_eq!(stderr.lines().count(), 1, "{}", stderr);
+}
+
+#[test]
+fn strict_analyze_fails_on_unmappable_import() {
+    let dir = tempfile::tempdir().unwrap();
+    // cv2对应多个包（opencv-python、opencv-contrib-python等），没有映射时无法确定
+    write_file(dir.path(), "project/app.py", "import requests\nimport cv2\n");
+    
+    let output = pywand(dir.path(), &["analyze", "--strict", "--path", "project"]).output().unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("could not be mapped to a PyPI package: cv2"), "{}", stderr);
+    
+    let output = pywand(dir.path(), &["analyze", "--path", "project"]).output().unwrap();
+    assert!(output.status.success());
+}
+
+#[test]
+fn embedded_uv_is_shared_across_working_directories() {
+    let dir = tempfile::tempdir().unwrap();
+    let home = dir.path().join("home");
+    let version = fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/uv/VERSION")).unwrap();
+    let uv_name = if cfg!(windows) { "uv.exe" } else { "uv" };
+    let shared_uv = home.join(".pywand").join("bin").join(version.trim()).join(uv_name);
+    
+    let mut modified = Vec::new();
+    for project in ["first", "second"] {
+        let project_dir = dir.path().join(project);
+        fs::create_dir(&project_dir).unwrap();
+        let output = pywand(&project_dir, &["uv", "--", "--version"]).env("HOME", &home).output().unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        assert!(String::from_utf8_lossy(&output.stdout).contains(version.trim()));
+        // 不在项目目录中复制uv
+        assert!(!project_dir.join(".pywand").exists());
+        modified.push(fs::metadata(&shared_uv).unwrap().modified().unwrap());
+    }
+    // 第二次运行直接使用已解压的uv，没有重新复制
+    assert_eq!(modified[0], modified[1]);
+    assert_eq!(fs::read_dir(home.join(".pywand").join("bin")).unwrap().count(), 1);
+}
+
+/// 输出中的中日文字符
+fn cjk_chars(output: &[u8]) -> String {
+    String::from_utf8_lossy(output)
+        .chars()
+        .filter(|c| matches!(c, '\u{3040}'..='\u{30ff}' | '\u{3400}'..='\u{9fff}' | '\u{ff00}'..='\u{ffef}'))
+        .collect()
+}
+
+#[test]
+fn english_export_output_has_no_cjk() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "app.py", "import requests\n");
+    // 无效的配置项只给出警告
+    write_file(dir.path(), ".pywand/config.toml", "exclude_dirs_mode = \"replace\"\n");
+    
+    let args = ["export", "--os", "linux", "--arch", "x64", "--python-version", "3.11.7"];
+    let mut dry_run_args = vec!["--dry-run"];
+    dry_run_args.extend(args);
+    let output = pywand(dir.path(), &dry_run_args).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(cjk_chars(&output.stdout), "", "{}", String::from_utf8_lossy(&output.stdout));
+    assert_eq!(cjk_chars(&output.stderr), "", "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("exclude_dirs_mode must be"));
+    
+    // 归档已存在时的错误
+    write_file(dir.path(), "pywand_export_linux_x64_3_11_7.tar.gz", "");
+    let output = pywand(dir.path(), &args).output().unwrap();
+    assert!(!output.status.success());
+    assert_eq!(cjk_chars(&output.stdout), "", "{}", String::from_utf8_lossy(&output.stdout));
+    assert_eq!(cjk_chars(&output.stderr), "", "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Use --force to overwrite"));
+}
+
+#[test]
+fn mappings_file_in_working_directory_maps_import_names() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "project/app.py", "import cv2\nimport requests\n");
+    write_file(dir.path(), "pywand-mappings.json", r#"{"cv2": "opencv-python"}"#);
+    
+    let output = pywand(dir.path(), &["gen-req", "--path", "project", "--output", "project", "--no-header"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let requirements = fs::read_to_string(dir.path().join("project/requirements.txt")).unwrap();
+    assert!(requirements.ends_with("\nopencv-python\nrequests\n"), "{}", requirements);
+}
+
+#[test]
+fn split_dev_writes_test_only_packages_to_dev_requirements() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "app.py", "import requests\n");
+    write_file(dir.path(), "conftest.py", "import pytest\nimport requests\n");
+    write_file(dir.path(), "tests/test_x.py", "import pytest\nimport responses\n");
+    
+    let output = pywand(dir.path(), &["gen-req", "--split-dev", "--no-header"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    
+    let requirements = fs::read_to_string(dir.path().join("requirements.txt")).unwrap();
+    let dev_requirements = fs::read_to_string(dir.path().join("requirements-dev.txt")).unwrap();
+    let packages = |content: &str| content.lines().filter(|line| !line.starts_with('#')).map(str::to_string).collect::<Vec<_>>();
+    assert_eq!(packages(&requirements), vec!["requests"]);
+    assert_eq!(packages(&dev_requirements), vec!["pytest", "responses"]);
+}
+
+#[test]
+fn unmapped_imports_are_reported_as_unresolved() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "project/vision.py", "import cv2\nimport numpy\n");
+    
+    let output = pywand(dir.path(), &["analyze", "--path", "project"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Imports not mapped to any package:\n  - cv2 (provided by several packages: opencv-python"), "{}", stdout);
+    assert!(stdout.contains("Add an entry to pywand-mappings.json"), "{}", stdout);
+    
+    let output = pywand(dir.path(), &["analyze", "--json", "--path", "project"]).output().unwrap();
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["dependencies"], serde_json::json!(["numpy"]));
+    assert_eq!(report["unresolved"], serde_json::json!(["cv2"]));
+}
+
+#[test]
+fn remove_deletes_requirement_and_warns_if_still_imported() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "app.py", "from flask import Flask\nimport requests\n");
+    write_file(dir.path(), "requirements.txt", "# web\nFlask>=2.0\nrequests==2.32.3\nPyYAML\n");
+    
+    // 没有虚拟环境时跳过卸载，只更新requirements.txt
+    let output = pywand(dir.path(), &["remove", "flask", "pyyaml"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(fs::read_to_string(dir.path().join("requirements.txt")).unwrap(), "# web\nrequests==2.32.3\n");
+    
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Warning: flask is still imported in: app.py"), "{}", stderr);
+    assert!(!stderr.contains("pyyaml"), "{}", stderr);
+}
+
+#[test]
+fn explain_lists_inferred_and_declared_provenance() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "app.py", "import yaml\nfrom flask import Flask\n");
+    write_file(dir.path(), "pyproject.toml", "[project]\nname = \"demo\"\ndependencies = [\"flask>=2.0\"]\n");
+    
+    let output = pywand(dir.path(), &["--dry-run", "gen-req", "--explain"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("  flask>=2.0  [inferred, declared:pyproject.toml]\n"), "{}", stdout);
+    assert!(stdout.contains("  PyYAML      [mapping]\n"), "{}", stdout);
+    assert!(!dir.path().join("requirements.txt").exists());
+}
+
+#[test]
+fn analyze_warns_on_conflicting_constraints_while_gen_req_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    write_file(dir.path(), "app.py", "from flask import Flask\n");
+    write_file(dir.path(), "requirements.txt", "flask==1.1\n");
+    write_file(dir.path(), "pyproject.toml", "[project]\nname = \"demo\"\ndependencies = [\"flask>=2.0\"]\n");
+    
+    let output = pywand(dir.path(), &["analyze"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("conflicting constraints for flask: flask==1.1 (requirements.txt), flask>=2.0 (pyproject.toml)"), "{}", stderr);
+    
+    // --prefer和--check-requires-python只属于生成依赖文件的子命令
+    let output = pywand(dir.path(), &["analyze", "--prefer", "highest"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    
+    let output = pywand(dir.path(), &["--dry-run", "gen-req"]).output().unwrap();
+    assert!(!output.status.success());
+    
+    let output = pywand(dir.path(), &["--dry-run", "gen-req", "--prefer", "highest"]).output().unwrap();
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("  flask>=2.0\n"));
+}